@@ -153,6 +153,13 @@ class RustGenerator(BaseGenerator):
                 return "{}".format(enum_node.type.name)
             return "{}".format(field.type.name)
 
+        def _is_byte_aligned(field: Field) -> bool:
+            # byte-aligned fields of native width can be loaded without bit-twiddling
+            primitive_type = _primitive_type(field)
+            assert field.type is not None
+            return (primitive_type != "bool" and field.offset % 8 == 0
+                    and field.type.width == int(primitive_type[1:]))
+
         def _fully_qualified_name(current: Node, node: Node) -> str:
             return "::".join((current.path_depth() - 1) * ["super"]) + str(node.path_with("::"))
 
@@ -160,6 +167,7 @@ class RustGenerator(BaseGenerator):
         env.filters["escape_rust_keywords"] = _escape_rust_keywords
         env.filters["field_type"] = _field_type
         env.filters["primitive_type"] = _primitive_type
+        env.filters["is_byte_aligned"] = _is_byte_aligned
         env.filters['structure_references'] = lambda ls: [
             x for x in ls if (isinstance(x.node, Structure)
                               and "_builtin.multivector" not in SyntaxTree.namespace_path(x.node))]
@@ -0,0 +1,270 @@
+//! Printing of differences between archives.
+
+use crate::inspect::{resolve, Result};
+
+use flatdata::{
+    diff_archives, diff_resource, ArchiveDiff, DynArchive, ResourceDiff, ResourceDiffKind,
+};
+
+use std::io;
+
+/// Compares two archives, or a single resource given by its `/` separated
+/// path, and prints the differences.
+///
+/// In summary mode, only the changed resources are printed together with
+/// their length changes and the number of changes.
+pub fn diff(
+    out: &mut dyn io::Write,
+    old: &DynArchive,
+    new: &DynArchive,
+    resource: Option<&str>,
+    summary: bool,
+) -> Result<()> {
+    match resource {
+        Some(path) => {
+            let (old, name) = resolve(old, path)?;
+            let (new, _) = resolve(new, path)?;
+            if old.definition().resource(name).is_none()
+                && new.definition().resource(name).is_none()
+            {
+                return Err(format!("{}: no such resource", path).into());
+            }
+            match diff_resource(&old, &new, name)? {
+                Some(diff) => print_resource(out, &diff, summary, 0)?,
+                None => writeln!(out, "{}: equal", path)?,
+            }
+        }
+        None => {
+            let diff = diff_archives(old, new)?;
+            if diff.is_empty() {
+                writeln!(out, "archives are equal")?;
+            } else {
+                print_archive(out, &diff, summary, 0)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_archive(
+    out: &mut dyn io::Write,
+    diff: &ArchiveDiff,
+    summary: bool,
+    depth: usize,
+) -> Result<()> {
+    writeln!(out, "{}archive {}", "  ".repeat(depth), diff.name)?;
+    for resource in &diff.resources {
+        print_resource(out, resource, summary, depth + 1)?;
+    }
+    Ok(())
+}
+
+fn lengths(old: usize, new: usize) -> String {
+    if old == new {
+        old.to_string()
+    } else {
+        format!("{} -> {}", old, new)
+    }
+}
+
+fn value(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("-")
+}
+
+fn print_resource(
+    out: &mut dyn io::Write,
+    diff: &ResourceDiff,
+    summary: bool,
+    depth: usize,
+) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    let changes = diff.num_changes();
+    match &diff.kind {
+        ResourceDiffKind::Added => writeln!(out, "{}{}: added", indent, diff.name)?,
+        ResourceDiffKind::Removed => writeln!(out, "{}{}: removed", indent, diff.name)?,
+        ResourceDiffKind::TypeChanged { old, new } => writeln!(
+            out,
+            "{}{}: type changed from {} to {}",
+            indent, diff.name, old, new
+        )?,
+        ResourceDiffKind::Instance(fields) => {
+            writeln!(out, "{}{}: {} fields changed", indent, diff.name, changes)?;
+            if !summary {
+                for field in fields {
+                    writeln!(
+                        out,
+                        "{}  {}: {} -> {}",
+                        indent,
+                        field.name,
+                        value(&field.old),
+                        value(&field.new)
+                    )?;
+                }
+            }
+        }
+        ResourceDiffKind::Vector {
+            old_len,
+            new_len,
+            elements,
+        } => {
+            writeln!(
+                out,
+                "{}{}: {} elements, {} changed",
+                indent,
+                diff.name,
+                lengths(*old_len, *new_len),
+                changes
+            )?;
+            if !summary {
+                for element in elements {
+                    let fields: Vec<_> = element
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            format!(
+                                "{}: {} -> {}",
+                                field.name,
+                                value(&field.old),
+                                value(&field.new)
+                            )
+                        })
+                        .collect();
+                    writeln!(out, "{}  [{}] {}", indent, element.index, fields.join(", "))?;
+                }
+            }
+        }
+        ResourceDiffKind::MultiVector {
+            old_len,
+            new_len,
+            items,
+        } => {
+            writeln!(
+                out,
+                "{}{}: {} items, {} changed",
+                indent,
+                diff.name,
+                lengths(*old_len, *new_len),
+                changes
+            )?;
+            if !summary {
+                for item in items {
+                    writeln!(out, "{}  [{}]", indent, item.index)?;
+                    for element in &item.old {
+                        writeln!(out, "{}    - {}", indent, element)?;
+                    }
+                    for element in &item.new {
+                        writeln!(out, "{}    + {}", indent, element)?;
+                    }
+                }
+            }
+        }
+        ResourceDiffKind::RawData {
+            old_len,
+            new_len,
+            ranges,
+        } => {
+            writeln!(
+                out,
+                "{}{}: {} bytes, {} ranges differ",
+                indent,
+                diff.name,
+                lengths(*old_len, *new_len),
+                changes
+            )?;
+            if !summary {
+                for range in ranges {
+                    writeln!(out, "{}  [{}..{}]", indent, range.start, range.end)?;
+                }
+            }
+        }
+        ResourceDiffKind::Archive(archive) => {
+            writeln!(out, "{}{}: {} changes", indent, diff.name, changes)?;
+            for resource in &archive.resources {
+                print_resource(out, resource, summary, depth + 1)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::inspect::open_archive;
+
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).expect("failed to create directory");
+        for entry in fs::read_dir(from).expect("failed to read directory") {
+            let entry = entry.expect("failed to read entry");
+            let target = to.join(entry.file_name());
+            if entry.path().is_dir() {
+                copy_dir(&entry.path(), &target);
+            } else {
+                fs::copy(entry.path(), target).expect("failed to copy file");
+            }
+        }
+    }
+
+    fn output(f: impl FnOnce(&mut dyn io::Write) -> Result<()>) -> String {
+        let mut out = Vec::new();
+        f(&mut out).expect("diff failed");
+        String::from_utf8(out).expect("invalid utf-8 output")
+    }
+
+    #[test]
+    fn test_diff() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/coappearances/assets");
+        let old = open_archive(&assets.join("karenina.archive"), None).expect("failed to open");
+        let equal = open_archive(&assets.join("karenina.tar"), None).expect("failed to open");
+        assert_eq!(
+            output(|out| diff(out, &old, &equal, None, false)),
+            "archives are equal\n"
+        );
+
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("flatdata-inspect-test-diff-{}", std::process::id()));
+        copy_dir(&assets.join("karenina.archive"), &path);
+        let mut strings = fs::read(path.join("strings")).expect("failed to read strings");
+        // size of the resource is stored in the first 8 bytes
+        strings[8] = b'X';
+        strings[9] = b'Y';
+        fs::write(path.join("strings"), strings).expect("failed to write strings");
+        let mut degrees = fs::read(path.join("statistics/vertex_degrees")).unwrap();
+        degrees[8] ^= 1;
+        fs::write(path.join("statistics/vertex_degrees"), degrees).unwrap();
+        let new = open_archive(&path, None).expect("failed to open");
+
+        let report = output(|out| diff(out, &old, &new, None, false));
+        let summary = output(|out| diff(out, &old, &new, None, true));
+        let strings = output(|out| diff(out, &old, &new, Some("strings"), false));
+        let degrees = output(|out| diff(out, &old, &new, Some("statistics/vertex_degrees"), true));
+        let edges = output(|out| diff(out, &old, &new, Some("edges"), false));
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+
+        assert_eq!(
+            report,
+            "archive .coappearances.Graph\n\
+             \x20 strings: 3910 bytes, 1 ranges differ\n\
+             \x20   [0..2]\n\
+             \x20 statistics: 1 changes\n\
+             \x20   vertex_degrees: 138 elements, 1 changed\n\
+             \x20     [0] value: 4 -> 5\n"
+        );
+        assert_eq!(
+            summary,
+            "archive .coappearances.Graph\n\
+             \x20 strings: 3910 bytes, 1 ranges differ\n\
+             \x20 statistics: 1 changes\n\
+             \x20   vertex_degrees: 138 elements, 1 changed\n"
+        );
+        assert_eq!(strings, "strings: 3910 bytes, 1 ranges differ\n  [0..2]\n");
+        assert_eq!(degrees, "vertex_degrees: 138 elements, 1 changed\n");
+        assert_eq!(edges, "edges: equal\n");
+        assert!(diff(&mut Vec::new(), &old, &new, Some("nope"), false).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+//! Export of resources to CSV and Parquet files.
+
+use crate::inspect::{resolve, Result};
+
+use flatdata::{export_resource, DynArchive, ExportOptions};
+
+use std::{fs, io, path::Path};
+
+/// Exports the resource given by its `/` separated path to files in
+/// `directory`, and prints the paths of the written files.
+pub fn export(
+    out: &mut dyn io::Write,
+    archive: &DynArchive,
+    path: &str,
+    directory: &Path,
+    options: &ExportOptions,
+) -> Result<()> {
+    let (archive, name) = resolve(archive, path)?;
+    if archive.definition().resource(name).is_none() {
+        return Err(format!("{}: no such resource", path).into());
+    }
+    fs::create_dir_all(directory)?;
+    for file in export_resource(&archive, name, directory, options)? {
+        writeln!(out, "{}", file.display())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::inspect::open_archive;
+
+    use flatdata::ExportFormat;
+
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_export() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/coappearances/assets");
+        let archive = open_archive(&assets.join("karenina.tar"), None).expect("failed to open");
+        let directory: PathBuf = std::env::temp_dir().join(format!(
+            "flatdata-inspect-test-export-{}",
+            std::process::id()
+        ));
+        let options = ExportOptions {
+            format: ExportFormat::Csv,
+            resolve_references: true,
+        };
+
+        let mut out = Vec::new();
+        export(
+            &mut out,
+            &archive,
+            "statistics/vertex_degrees",
+            &directory,
+            &options,
+        )
+        .expect("failed to export");
+        let degrees = fs::read_to_string(directory.join("vertex_degrees.csv")).unwrap();
+        let mut listing = Vec::new();
+        export(
+            &mut listing,
+            &archive,
+            "vertices_data",
+            &directory,
+            &options,
+        )
+        .expect("failed to export");
+        let unsupported = export(&mut Vec::new(), &archive, "strings", &directory, &options);
+        let missing = export(&mut Vec::new(), &archive, "nope", &directory, &options);
+        fs::remove_dir_all(&directory).expect("failed to remove directory");
+
+        let path = directory.join("vertex_degrees.csv");
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("{}\n", path.display())
+        );
+        assert!(degrees.starts_with("value\n4\n"));
+        let listing = String::from_utf8(listing).unwrap();
+        assert_eq!(listing.lines().count(), 4);
+        assert!(listing.contains("vertices_data.Nickname.csv\n"));
+        assert!(unsupported.is_err());
+        assert!(missing.is_err());
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_export_parquet() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/coappearances/assets");
+        let archive = open_archive(&assets.join("karenina.tar"), None).expect("failed to open");
+        let directory: PathBuf = std::env::temp_dir().join(format!(
+            "flatdata-inspect-test-export-parquet-{}",
+            std::process::id()
+        ));
+        let options = ExportOptions {
+            format: ExportFormat::Parquet,
+            resolve_references: true,
+        };
+
+        export(&mut Vec::new(), &archive, "edges", &directory, &options).expect("failed to export");
+        let is_parquet = fs::metadata(directory.join("edges.parquet")).is_ok();
+        fs::remove_dir_all(&directory).expect("failed to remove directory");
+
+        assert!(is_parquet);
+    }
+}
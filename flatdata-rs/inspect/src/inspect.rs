@@ -0,0 +1,389 @@
+//! Listing and printing of archives decoded via their stored schema.
+
+use flatdata::{
+    DynArchive, DynResource, FileResourceStorage, RawData, StorageHandle, TarArchiveResourceStorage,
+};
+
+use std::{error::Error, fs, io, ops::Range, path::Path};
+
+/// Number of elements printed when no selection is given.
+const DEFAULT_LIMIT: usize = 10;
+
+pub(crate) type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Opens the archive stored in the directory or `.tar` file at `path`.
+///
+/// If `name` is not given, the archive is detected by looking for the
+/// `*.archive` signature file; this fails if there is none or more than one.
+pub fn open_archive(path: &Path, name: Option<&str>) -> Result<DynArchive> {
+    let is_tar = path.is_file();
+    let storage: StorageHandle = if is_tar {
+        TarArchiveResourceStorage::new(path)?
+    } else if path.is_dir() {
+        FileResourceStorage::new(path)
+    } else {
+        return Err(format!("{}: no such archive directory or tar file", path.display()).into());
+    };
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => {
+            let names = if is_tar {
+                tar_entry_names(path)?
+            } else {
+                dir_entry_names(path)?
+            };
+            detect_archive_name(path, names)?
+        }
+    };
+    Ok(DynArchive::open(storage, &name)?)
+}
+
+fn dir_entry_names(path: &Path) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+fn tar_entry_names(path: &Path) -> Result<Vec<String>> {
+    let mut archive = tar::Archive::new(fs::File::open(path)?);
+    let mut names = Vec::new();
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let entry_path = entry.path()?;
+        let entry_path = entry_path.strip_prefix(".").unwrap_or(&entry_path);
+        if entry_path.components().count() == 1 {
+            names.push(entry_path.to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+fn detect_archive_name(path: &Path, names: Vec<String>) -> Result<String> {
+    let mut candidates: Vec<_> = names
+        .into_iter()
+        .filter_map(|name| name.strip_suffix(".archive").map(String::from))
+        .collect();
+    candidates.sort();
+    match candidates.len() {
+        0 => Err(format!("{}: no archive found", path.display()).into()),
+        1 => Ok(candidates.remove(0)),
+        _ => Err(format!(
+            "{}: found multiple archives ({}), use --archive to choose one",
+            path.display(),
+            candidates.join(", ")
+        )
+        .into()),
+    }
+}
+
+/// Lists all resources of the archive and its subarchives together with
+/// their element counts and sizes.
+pub fn list(out: &mut dyn io::Write, archive: &DynArchive) -> Result<()> {
+    writeln!(out, "archive {}", archive.name())?;
+    list_resources(out, archive, 1)
+}
+
+fn list_resources(out: &mut dyn io::Write, archive: &DynArchive, depth: usize) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    for resource in &archive.definition().resources {
+        let type_name = &resource.resource_type;
+        let value = match archive.resource(&resource.name)? {
+            None => {
+                writeln!(out, "{}{}: {} (missing)", indent, resource.name, type_name)?;
+                continue;
+            }
+            Some(value) => value,
+        };
+        let (count, size) = match &value {
+            DynResource::Instance(x) => (1, x.as_bytes().len()),
+            DynResource::Vector(x) => (x.len(), x.as_bytes().len()),
+            DynResource::MultiVector(x) => {
+                (x.len(), x.index_as_bytes().len() + x.data_as_bytes().len())
+            }
+            DynResource::RawData(x) => (x.as_bytes().len(), x.as_bytes().len()),
+            DynResource::Archive(_) => {
+                writeln!(out, "{}{}: {}", indent, resource.name, type_name)?;
+                if let DynResource::Archive(subarchive) = value {
+                    list_resources(out, &subarchive, depth + 1)?;
+                }
+                continue;
+            }
+        };
+        writeln!(
+            out,
+            "{}{}: {}, {} elements, {} bytes",
+            indent, resource.name, type_name, count, size
+        )?;
+    }
+    Ok(())
+}
+
+/// Walks the subarchives along a `/` separated path.
+///
+/// Returns the archive containing the resource and the resource name.
+pub(crate) fn resolve<'p>(archive: &DynArchive, path: &'p str) -> Result<(DynArchive, &'p str)> {
+    let mut archive = archive.clone();
+    let mut components = path.split('/').peekable();
+    while let Some(name) = components.next() {
+        if components.peek().is_none() {
+            return Ok((archive, name));
+        }
+        archive = match archive.resource(name)? {
+            Some(DynResource::Archive(subarchive)) => subarchive,
+            Some(_) => return Err(format!("{}: not an archive", name).into()),
+            None => return Err(format!("{}: missing optional archive", name).into()),
+        };
+    }
+    Err("empty resource path".into())
+}
+
+/// Prints the schema of the archive, or of a single resource if given.
+pub fn print_schema(
+    out: &mut dyn io::Write,
+    archive: &DynArchive,
+    resource: Option<&str>,
+) -> Result<()> {
+    let (archive, resource_name) = match resource {
+        Some(path) => {
+            let (archive, name) = resolve(archive, path)?;
+            if archive.definition().resource(name).is_none() {
+                return Err(format!("{}: no such resource", path).into());
+            }
+            (archive, name.to_string())
+        }
+        None => {
+            let name = archive.name().rsplit('.').next().unwrap_or_default();
+            (archive.clone(), format!("{}.archive", name))
+        }
+    };
+    let schema = archive
+        .storage()
+        .read_resource(&format!("{}.schema", resource_name))?;
+    out.write_all(schema)?;
+    if !schema.ends_with(b"\n") {
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Elements of a resource to print.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    /// A single element.
+    Index(usize),
+    /// A range of elements; the end is clamped to the size of the resource.
+    Range(Range<usize>),
+    /// The first few elements.
+    Default,
+}
+
+impl Selection {
+    /// Parses `N`, `A..B` or `A..`; ranges must not be reversed.
+    pub fn parse(s: &str) -> Result<Self> {
+        let parse = |x: &str| {
+            x.parse::<usize>()
+                .map_err(|_| format!("invalid index '{}'", x))
+        };
+        Ok(match s.split_once("..") {
+            Some((start, "")) => Selection::Range(parse(start)?..usize::MAX),
+            Some((start, end)) => {
+                let range = parse(start)?..parse(end)?;
+                if range.start > range.end {
+                    return Err(format!("reversed range '{}'", s).into());
+                }
+                Selection::Range(range)
+            }
+            None => Selection::Index(parse(s)?),
+        })
+    }
+
+    fn range(&self, len: usize) -> Result<Range<usize>> {
+        match self {
+            Selection::Index(index) if *index < len => Ok(*index..*index + 1),
+            Selection::Index(index) => {
+                Err(format!("index {} out of bounds (size {})", index, len).into())
+            }
+            Selection::Range(range) => Ok(range.start.min(len)..range.end.min(len)),
+            Selection::Default => Ok(0..len.min(DEFAULT_LIMIT)),
+        }
+    }
+}
+
+/// Prints the selected elements of a resource given by its `/` separated path.
+pub fn show(
+    out: &mut dyn io::Write,
+    archive: &DynArchive,
+    path: &str,
+    selection: &Selection,
+) -> Result<()> {
+    let (archive, name) = resolve(archive, path)?;
+    let resource = match archive.resource(name)? {
+        Some(resource) => resource,
+        None => {
+            writeln!(out, "{}: missing", path)?;
+            return Ok(());
+        }
+    };
+    let (len, range) = match &resource {
+        DynResource::Instance(x) => {
+            writeln!(out, "{:?}", x)?;
+            return Ok(());
+        }
+        DynResource::Vector(x) => {
+            let range = selection.range(x.len())?;
+            for index in range.clone() {
+                writeln!(out, "[{}] {:?}", index, x.at(index))?;
+            }
+            (x.len(), range)
+        }
+        DynResource::MultiVector(x) => {
+            let range = selection.range(x.len())?;
+            for index in range.clone() {
+                writeln!(out, "[{}]", index)?;
+                for element in x.at(index) {
+                    writeln!(out, "  {:?}", element)?;
+                }
+            }
+            (x.len(), range)
+        }
+        DynResource::RawData(x) => {
+            show_raw_data(out, x, selection)?;
+            return Ok(());
+        }
+        DynResource::Archive(x) => return list(out, x),
+    };
+    if range.end < len && *selection == Selection::Default {
+        writeln!(out, "... {} more", len - range.end)?;
+    }
+    Ok(())
+}
+
+fn show_raw_data(out: &mut dyn io::Write, data: &RawData, selection: &Selection) -> Result<()> {
+    let len = data.as_bytes().len();
+    match selection {
+        Selection::Index(offset) => {
+            selection.range(len)?;
+            writeln!(out, "[{}] {:?}", offset, data.substring_lossy(*offset))?;
+        }
+        _ => {
+            let range = selection.range(len)?;
+            let bytes = &data.as_bytes()[range.clone()];
+            writeln!(
+                out,
+                "[{}..{}] {:?}",
+                range.start,
+                range.end,
+                String::from_utf8_lossy(bytes)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    fn assets() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/coappearances/assets")
+    }
+
+    fn output(f: impl FnOnce(&mut dyn io::Write) -> Result<()>) -> String {
+        let mut out = Vec::new();
+        f(&mut out).expect("inspection failed");
+        String::from_utf8(out).expect("invalid utf-8 output")
+    }
+
+    #[test]
+    fn test_selection() {
+        assert_eq!(Selection::parse("3").unwrap(), Selection::Index(3));
+        assert_eq!(Selection::parse("3..5").unwrap(), Selection::Range(3..5));
+        assert_eq!(
+            Selection::parse("3..").unwrap(),
+            Selection::Range(3..usize::MAX)
+        );
+        assert!(Selection::parse("x").is_err());
+        assert!(Selection::parse("1..x").is_err());
+        assert_eq!(Selection::parse("2..2").unwrap(), Selection::Range(2..2));
+        assert!(Selection::parse("5..2").is_err());
+    }
+
+    #[test]
+    fn test_list() {
+        for path in &["karenina.archive", "karenina.tar"] {
+            let archive = open_archive(&assets().join(path), None).expect("failed to open");
+            let listing = output(|out| list(out, &archive));
+            assert!(listing.starts_with("archive .coappearances.Graph\n"));
+            assert!(listing.contains("  vertices: vector<.coappearances.Character>, 138 elements"));
+            assert!(listing.contains("  vertices_data: multivector<32, "));
+            assert!(listing.contains("  strings: raw_data, "));
+            assert!(listing.contains("  statistics: archive .coappearances.Statistics\n"));
+            assert!(listing.contains("    invariants: .coappearances.Invariants, 1 elements"));
+        }
+    }
+
+    #[test]
+    fn test_detect_archive() {
+        assert!(open_archive(&assets().join("karenina.archive"), Some("Graph")).is_ok());
+        assert!(open_archive(&assets().join("karenina.archive"), Some("Nope")).is_err());
+        assert!(open_archive(&assets().join("does_not_exist"), None).is_err());
+        assert!(open_archive(&assets(), None).is_err());
+    }
+
+    #[test]
+    fn test_show() {
+        let archive =
+            open_archive(&assets().join("karenina.archive"), None).expect("failed to open");
+
+        let vertices = output(|out| show(out, &archive, "vertices", &Selection::Default));
+        assert_eq!(vertices.lines().count(), DEFAULT_LIMIT + 1);
+        assert!(vertices.starts_with("[0] Character { "));
+        assert!(vertices.ends_with("... 128 more\n"));
+
+        let vertex = output(|out| show(out, &archive, "vertices", &Selection::Index(1)));
+        assert!(vertex.starts_with("[1] Character { name_ref: "));
+        assert!(show(
+            &mut Vec::new(),
+            &archive,
+            "vertices",
+            &Selection::Index(138)
+        )
+        .is_err());
+
+        let edges = output(|out| show(out, &archive, "edges", &Selection::Range(2..4)));
+        assert_eq!(edges.lines().count(), 2);
+        assert!(edges.starts_with("[2] Coappearance { "));
+
+        let data = output(|out| show(out, &archive, "vertices_data", &Selection::Range(0..2)));
+        assert!(data.starts_with("[0]\n"));
+        assert!(data.contains("\n[1]\n"));
+
+        let string = output(|out| show(out, &archive, "strings", &Selection::Index(0)));
+        assert!(string.starts_with("[0] \""));
+
+        let invariants =
+            output(|out| show(out, &archive, "statistics/invariants", &Selection::Default));
+        assert!(invariants.starts_with("Invariants { "));
+
+        assert!(show(&mut Vec::new(), &archive, "nope", &Selection::Default).is_err());
+        assert!(show(&mut Vec::new(), &archive, "vertices/x", &Selection::Default).is_err());
+    }
+
+    #[test]
+    fn test_print_schema() {
+        let archive =
+            open_archive(&assets().join("karenina.archive"), None).expect("failed to open");
+        let schema = output(|out| print_schema(out, &archive, None));
+        assert!(schema.contains("archive Graph"));
+        let schema = output(|out| print_schema(out, &archive, Some("statistics/invariants")));
+        assert!(schema.contains("struct Invariants"));
+        assert!(print_schema(&mut Vec::new(), &archive, Some("nope")).is_err());
+    }
+}
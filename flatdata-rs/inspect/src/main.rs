@@ -0,0 +1,148 @@
+//! Command-line tool for inspecting flatdata archives.
+//!
+//! The archive is decoded using the schema stored alongside its resources,
+//! so no generated code is needed and any archive can be inspected.
+
+#![deny(missing_docs, missing_debug_implementations, warnings)]
+
+mod diff;
+mod export;
+mod inspect;
+mod stats;
+
+use crate::inspect::Selection;
+
+use flatdata::{ExportFormat, ExportOptions};
+
+use std::{
+    env,
+    error::Error,
+    fmt, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+const USAGE: &str = "\
+Usage: flatdata-inspect [--archive NAME] PATH COMMAND
+
+PATH is an archive directory or a .tar file containing an archive.
+
+Options:
+    --archive NAME              name of the archive, if PATH contains several
+
+Commands:
+    list                        list resources with element counts and sizes
+    schema [RESOURCE]           print the schema of the archive or a resource
+    show RESOURCE [SELECTION]   print elements of a resource
+    stats                       print value statistics and bit usage of fields
+    diff OTHER [RESOURCE] [--summary]
+                                print differences to the archive at OTHER,
+                                optionally restricted to a single resource
+    export RESOURCE DIRECTORY [--format csv|parquet] [--resolve-references]
+                                write the elements of a resource to files in
+                                DIRECTORY, one per variant of a multivector;
+                                references to raw data are optionally replaced
+                                by the referenced strings; parquet requires the
+                                `parquet` feature
+
+RESOURCE is a resource name; resources of subarchives are addressed as
+`subarchive/resource`. SELECTION is an index `N` or a range `A..B` or `A..`;
+for raw data, an index prints the zero-terminated string at that offset.";
+
+/// Invalid command-line arguments, reported together with the usage.
+#[derive(Debug)]
+struct UsageError(String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for UsageError {}
+
+impl From<&str> for UsageError {
+    fn from(message: &str) -> Self {
+        UsageError(message.into())
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), Box<dyn Error>> {
+    let mut args = args.into_iter().peekable();
+    let mut name = None;
+    if args.peek().map(String::as_str) == Some("--archive") {
+        args.next();
+        name = Some(
+            args.next()
+                .ok_or(UsageError::from("missing archive name"))?,
+        );
+    }
+    let path = PathBuf::from(
+        args.next()
+            .ok_or(UsageError::from("missing archive path"))?,
+    );
+    let command = args.next().ok_or(UsageError::from("missing command"))?;
+    let mut rest: Vec<_> = args.collect();
+    let summary = rest.iter().any(|arg| arg == "--summary");
+    rest.retain(|arg| arg != "--summary");
+    let mut options = ExportOptions {
+        format: ExportFormat::Csv,
+        resolve_references: rest.iter().any(|arg| arg == "--resolve-references"),
+    };
+    rest.retain(|arg| arg != "--resolve-references");
+    if let Some(pos) = rest.iter().position(|arg| arg == "--format") {
+        options.format = match rest.get(pos + 1).map(String::as_str) {
+            Some("csv") => ExportFormat::Csv,
+            #[cfg(feature = "parquet")]
+            Some("parquet") => ExportFormat::Parquet,
+            #[cfg(not(feature = "parquet"))]
+            Some("parquet") => {
+                return Err(
+                    UsageError::from("parquet support requires the `parquet` feature").into(),
+                )
+            }
+            _ => return Err(UsageError::from("invalid or missing format").into()),
+        };
+        rest.drain(pos..pos + 2);
+    }
+
+    let archive = inspect::open_archive(&path, name.as_deref())?;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    match (command.as_str(), &rest[..]) {
+        ("list", []) => inspect::list(&mut out, &archive),
+        ("schema", []) => inspect::print_schema(&mut out, &archive, None),
+        ("schema", [resource]) => inspect::print_schema(&mut out, &archive, Some(resource)),
+        ("stats", []) => stats::stats(&mut out, &archive),
+        ("show", [resource]) => inspect::show(&mut out, &archive, resource, &Selection::Default),
+        ("show", [resource, selection]) => {
+            let selection = Selection::parse(selection).map_err(|e| UsageError(e.to_string()))?;
+            inspect::show(&mut out, &archive, resource, &selection)
+        }
+        ("diff", [other, resource @ ..]) if resource.len() <= 1 => {
+            let other = inspect::open_archive(Path::new(other), name.as_deref())?;
+            let resource = resource.first().map(String::as_str);
+            diff::diff(&mut out, &archive, &other, resource, summary)
+        }
+        ("export", [resource, directory]) => {
+            export::export(&mut out, &archive, resource, Path::new(directory), &options)
+        }
+        _ => Err(UsageError(format!("invalid command '{}'", command)).into()),
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+    if let Err(e) = run(args) {
+        eprintln!("error: {}", e);
+        if e.is::<UsageError>() {
+            eprintln!();
+            eprintln!("{}", USAGE);
+        }
+        process::exit(1);
+    }
+}
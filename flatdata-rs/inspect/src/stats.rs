@@ -0,0 +1,136 @@
+//! Printing of archive statistics.
+
+use crate::inspect::Result;
+
+use flatdata::{
+    archive_statistics, ArchiveStatistics, DynArchive, FieldStatistics, ResourceStatisticsKind,
+};
+
+use std::io;
+
+/// Prints sizes, element counts and value statistics of all resources.
+///
+/// For every field, the number of bits needed for its values is shown next to
+/// its declared width; fields using all of their bits are marked.
+pub fn stats(out: &mut dyn io::Write, archive: &DynArchive) -> Result<()> {
+    let statistics = archive_statistics(archive)?;
+    print_archive(out, &statistics, 0)
+}
+
+fn print_archive(
+    out: &mut dyn io::Write,
+    statistics: &ArchiveStatistics,
+    depth: usize,
+) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    writeln!(
+        out,
+        "{}archive {}: {} bytes",
+        indent,
+        statistics.name,
+        statistics.size_in_bytes()
+    )?;
+    for resource in &statistics.resources {
+        let name = &resource.name;
+        let size = resource.size_in_bytes;
+        match &resource.kind {
+            ResourceStatisticsKind::Missing => writeln!(out, "{}  {}: missing", indent, name)?,
+            ResourceStatisticsKind::Instance { fields } => {
+                writeln!(out, "{}  {}: {} bytes", indent, name, size)?;
+                print_fields(out, fields, depth + 2)?;
+            }
+            ResourceStatisticsKind::Vector { len, fields } => {
+                writeln!(
+                    out,
+                    "{}  {}: {} elements, {} bytes",
+                    indent, name, len, size
+                )?;
+                print_fields(out, fields, depth + 2)?;
+            }
+            ResourceStatisticsKind::MultiVector {
+                len,
+                variants,
+                bucket_sizes,
+            } => {
+                writeln!(out, "{}  {}: {} items, {} bytes", indent, name, len, size)?;
+                let histogram: Vec<_> = bucket_sizes
+                    .iter()
+                    .map(|(size, count)| format!("{}: {}", size, count))
+                    .collect();
+                writeln!(out, "{}    item sizes: {}", indent, histogram.join(", "))?;
+                for variant in variants {
+                    writeln!(
+                        out,
+                        "{}    {}: {} elements",
+                        indent, variant.name, variant.count
+                    )?;
+                    print_fields(out, &variant.fields, depth + 3)?;
+                }
+            }
+            ResourceStatisticsKind::RawData => {
+                writeln!(out, "{}  {}: {} bytes", indent, name, size)?
+            }
+            ResourceStatisticsKind::Archive(statistics) => {
+                writeln!(out, "{}  {}:", indent, name)?;
+                print_archive(out, statistics, depth + 2)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_fields(out: &mut dyn io::Write, fields: &[FieldStatistics], depth: usize) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    for field in fields {
+        let (min, max) = match (field.min, field.max) {
+            (Some(min), Some(max)) => (min.to_string(), max.to_string()),
+            _ => ("-".into(), "-".into()),
+        };
+        write!(
+            out,
+            "{}{}: min {}, max {}, {} distinct, {}/{} bits used",
+            indent,
+            field.name,
+            min,
+            max,
+            field.distinct_estimate,
+            field.used_bits(),
+            field.width
+        )?;
+        if field.num_missing != 0 {
+            write!(out, ", {} missing", field.num_missing)?;
+        }
+        if field.min.is_some() && field.unused_bits() == 0 {
+            write!(out, " (full)")?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::inspect::open_archive;
+
+    use std::path::Path;
+
+    #[test]
+    fn test_stats() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("../tests/coappearances/assets");
+        let archive = open_archive(&assets.join("karenina.archive"), None).expect("failed to open");
+        let mut out = Vec::new();
+        stats(&mut out, &archive).expect("failed to compute statistics");
+        let out = String::from_utf8(out).expect("invalid utf-8 output");
+
+        assert!(out.starts_with("archive .coappearances.Graph: "));
+        assert!(out.contains("\n  vertices: 138 elements, 552 bytes\n"));
+        assert!(out.contains("\n  vertices_data: 138 items, 1462 bytes\n    item sizes: "));
+        assert!(out.contains("\n    .coappearances.Nickname: "));
+        assert!(out.contains("\n  strings: 3910 bytes\n"));
+        assert!(out.contains("\n  statistics:\n    archive .coappearances.Statistics: 286 bytes\n"));
+        assert!(
+            out.contains("\n      vertex_degrees: 138 elements, 276 bytes\n        value: min ")
+        );
+    }
+}
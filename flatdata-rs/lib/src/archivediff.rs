@@ -0,0 +1,510 @@
+use crate::{
+    dynamic::{DynArchive, DynMultiVector, DynResource, DynStruct, DynVector},
+    error::ResourceStorageError,
+    rawdata::RawData,
+};
+
+use std::ops::Range;
+
+/// Differences between two archives computed by [`diff_archives`].
+///
+/// Only resources which differ are contained.
+///
+/// [`diff_archives`]: fn.diff_archives.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveDiff {
+    /// Fully qualified name of the archive.
+    pub name: String,
+    /// Differing resources in the order of the schema.
+    pub resources: Vec<ResourceDiff>,
+}
+
+impl ArchiveDiff {
+    /// Returns `true` if the archives are equal.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    /// Returns the differences of the resource with the given name, or `None`
+    /// if it does not differ.
+    pub fn resource(&self, name: &str) -> Option<&ResourceDiff> {
+        self.resources.iter().find(|resource| resource.name == name)
+    }
+}
+
+/// Differences of a single resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceDiff {
+    /// Name of the resource.
+    pub name: String,
+    /// Kind of the differences.
+    pub kind: ResourceDiffKind,
+}
+
+impl ResourceDiff {
+    /// Number of differences, e.g. changed elements or byte ranges, not
+    /// counting length changes.
+    ///
+    /// Differences of subarchives are counted recursively.
+    pub fn num_changes(&self) -> usize {
+        match &self.kind {
+            ResourceDiffKind::Added | ResourceDiffKind::Removed => 1,
+            ResourceDiffKind::TypeChanged { .. } => 1,
+            ResourceDiffKind::Instance(fields) => fields.len(),
+            ResourceDiffKind::Vector { elements, .. } => elements.len(),
+            ResourceDiffKind::MultiVector { items, .. } => items.len(),
+            ResourceDiffKind::RawData { ranges, .. } => ranges.len(),
+            ResourceDiffKind::Archive(diff) => {
+                diff.resources.iter().map(ResourceDiff::num_changes).sum()
+            }
+        }
+    }
+}
+
+/// Kind of differences of a resource.
+///
+/// Elements are compared by the values of their fields, matched by name, so
+/// that archives with structurally compatible schemas can be compared.
+/// Elements beyond the length of the shorter resource are not reported
+/// individually.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceDiffKind {
+    /// The resource is only present in the new archive.
+    Added,
+    /// The resource is only present in the old archive.
+    Removed,
+    /// The type of the resource in the schema differs.
+    TypeChanged {
+        /// Type of the resource in the old archive.
+        old: String,
+        /// Type of the resource in the new archive.
+        new: String,
+    },
+    /// Changed fields of an instance.
+    Instance(Vec<FieldDiff>),
+    /// Changes of a vector.
+    Vector {
+        /// Length of the old vector.
+        old_len: usize,
+        /// Length of the new vector.
+        new_len: usize,
+        /// Changed elements in ascending order of their index.
+        elements: Vec<ElementDiff>,
+    },
+    /// Changes of a multivector.
+    MultiVector {
+        /// Number of items of the old multivector.
+        old_len: usize,
+        /// Number of items of the new multivector.
+        new_len: usize,
+        /// Changed items in ascending order of their index.
+        items: Vec<ItemDiff>,
+    },
+    /// Changes of raw data.
+    RawData {
+        /// Size of the old data in bytes.
+        old_len: usize,
+        /// Size of the new data in bytes.
+        new_len: usize,
+        /// Maximal ranges of differing bytes in the common prefix of both.
+        ranges: Vec<Range<usize>>,
+    },
+    /// Changes of a subarchive.
+    Archive(ArchiveDiff),
+}
+
+/// Changed element of a vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementDiff {
+    /// Index of the element.
+    pub index: usize,
+    /// Changed fields.
+    pub fields: Vec<FieldDiff>,
+}
+
+/// Changed field of a struct.
+///
+/// Values are formatted like the values of [`DynStruct`].
+///
+/// [`DynStruct`]: struct.DynStruct.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Name of the field.
+    pub name: String,
+    /// Old value, or `None` if the field does not exist in the old schema.
+    pub old: Option<String>,
+    /// New value, or `None` if the field does not exist in the new schema.
+    pub new: Option<String>,
+}
+
+/// Changed item of a multivector.
+///
+/// Elements are formatted like the `Debug` output of [`DynStruct`].
+///
+/// [`DynStruct`]: struct.DynStruct.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemDiff {
+    /// Index of the item.
+    pub index: usize,
+    /// Elements of the old item.
+    pub old: Vec<String>,
+    /// Elements of the new item.
+    pub new: Vec<String>,
+}
+
+/// Compares two archives resource by resource.
+///
+/// The archives should have the same or structurally compatible schemas:
+/// resources are matched by name, and fields of structs by name. Resources
+/// missing in one of the archives are reported as added or removed.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{diff_archives, DynArchive, MemoryResourceStorage, ResourceDiffKind};
+/// # use flatdata::test::XBuilder;
+///
+/// let open = |path, values: &[u32]| {
+///     let storage = MemoryResourceStorage::new(path);
+/// #   let builder = XBuilder::new(storage.clone()).unwrap();
+/// #   let mut data = builder.start_data().unwrap();
+/// #   for &x in values {
+/// #       data.grow().unwrap().set_x(x);
+/// #   }
+/// #   data.close().unwrap();
+///     // storage contains an archive `X` with a vector resource `data`
+///     DynArchive::open(storage, "X").expect("failed to open archive")
+/// };
+/// let diff = diff_archives(&open("/old", &[1, 2, 3]), &open("/new", &[1, 5]))
+///     .expect("failed to compare archives");
+/// match &diff.resource("data").expect("data is equal").kind {
+///     ResourceDiffKind::Vector { old_len, new_len, elements } => {
+///         assert_eq!((*old_len, *new_len), (3, 2));
+///         assert_eq!(elements[0].index, 1);
+///         assert_eq!(elements[0].fields[0].new.as_deref(), Some("5"));
+///     }
+///     _ => panic!("unexpected diff"),
+/// }
+/// ```
+pub fn diff_archives(
+    old: &DynArchive,
+    new: &DynArchive,
+) -> Result<ArchiveDiff, ResourceStorageError> {
+    let mut names: Vec<&str> = old
+        .definition()
+        .resources
+        .iter()
+        .map(|resource| &resource.name[..])
+        .collect();
+    for resource in &new.definition().resources {
+        if !names.contains(&&resource.name[..]) {
+            names.push(&resource.name);
+        }
+    }
+
+    let mut resources = Vec::new();
+    for name in names {
+        if let Some(diff) = diff_resource(old, new, name)? {
+            resources.push(diff);
+        }
+    }
+    Ok(ArchiveDiff {
+        name: new.name().into(),
+        resources,
+    })
+}
+
+/// Reads a resource which might be missing in the schema.
+fn read_resource<'a>(
+    archive: &'a DynArchive,
+    name: &str,
+) -> Result<Option<DynResource<'a>>, ResourceStorageError> {
+    if archive.definition().resource(name).is_none() {
+        return Ok(None);
+    }
+    archive.resource(name)
+}
+
+/// Compares the resource with the given name of two archives.
+///
+/// Returns `None` if the resource does not differ, see [`diff_archives`] for
+/// details.
+///
+/// [`diff_archives`]: fn.diff_archives.html
+pub fn diff_resource(
+    old: &DynArchive,
+    new: &DynArchive,
+    name: &str,
+) -> Result<Option<ResourceDiff>, ResourceStorageError> {
+    let kind = diff_resource_kind(old, new, name)?;
+    Ok(kind.map(|kind| ResourceDiff {
+        name: name.into(),
+        kind,
+    }))
+}
+
+fn diff_resource_kind(
+    old: &DynArchive,
+    new: &DynArchive,
+    name: &str,
+) -> Result<Option<ResourceDiffKind>, ResourceStorageError> {
+    let old_resource = read_resource(old, name)?;
+    let new_resource = read_resource(new, name)?;
+    Ok(match (old_resource, new_resource) {
+        (None, None) => None,
+        (None, Some(_)) => Some(ResourceDiffKind::Added),
+        (Some(_), None) => Some(ResourceDiffKind::Removed),
+        (Some(DynResource::Instance(old)), Some(DynResource::Instance(new))) => {
+            Some(diff_structs(&old, &new))
+                .filter(|fields| !fields.is_empty())
+                .map(ResourceDiffKind::Instance)
+        }
+        (Some(DynResource::Vector(old)), Some(DynResource::Vector(new))) => {
+            diff_vectors(&old, &new)
+        }
+        (Some(DynResource::MultiVector(old)), Some(DynResource::MultiVector(new))) => {
+            diff_multivectors(&old, &new)
+        }
+        (Some(DynResource::RawData(old)), Some(DynResource::RawData(new))) => {
+            diff_raw_data(&old, &new)
+        }
+        (Some(DynResource::Archive(old)), Some(DynResource::Archive(new))) => {
+            Some(diff_archives(&old, &new)?)
+                .filter(|diff| !diff.is_empty())
+                .map(ResourceDiffKind::Archive)
+        }
+        (Some(_), Some(_)) => {
+            let type_name = |archive: &DynArchive| {
+                let resource = archive
+                    .definition()
+                    .resource(name)
+                    .expect("missing resource");
+                resource.resource_type.to_string()
+            };
+            Some(ResourceDiffKind::TypeChanged {
+                old: type_name(old),
+                new: type_name(new),
+            })
+        }
+    })
+}
+
+fn diff_structs(old: &DynStruct, new: &DynStruct) -> Vec<FieldDiff> {
+    let format = |x: &DynStruct, name: &str| x.get(name).map(|value| value.to_string());
+    let mut result = Vec::new();
+    let mut push = |name: &str| {
+        let (old_value, new_value) = (old.get(name), new.get(name));
+        if old_value != new_value {
+            result.push(FieldDiff {
+                name: name.into(),
+                old: format(old, name),
+                new: format(new, name),
+            });
+        }
+    };
+    for field in &old.definition().fields {
+        push(&field.name);
+    }
+    for field in &new.definition().fields {
+        if old.definition().field(&field.name).is_none() {
+            push(&field.name);
+        }
+    }
+    result
+}
+
+fn diff_vectors(old: &DynVector, new: &DynVector) -> Option<ResourceDiffKind> {
+    let elements: Vec<_> = old
+        .iter()
+        .zip(new.iter())
+        .enumerate()
+        .filter_map(|(index, (old, new))| {
+            let fields = diff_structs(&old, &new);
+            if fields.is_empty() {
+                None
+            } else {
+                Some(ElementDiff { index, fields })
+            }
+        })
+        .collect();
+    if elements.is_empty() && old.len() == new.len() {
+        return None;
+    }
+    Some(ResourceDiffKind::Vector {
+        old_len: old.len(),
+        new_len: new.len(),
+        elements,
+    })
+}
+
+fn diff_multivectors(old: &DynMultiVector, new: &DynMultiVector) -> Option<ResourceDiffKind> {
+    let format = |item: &mut dyn Iterator<Item = DynStruct>| -> Vec<String> {
+        item.map(|x| format!("{:?}", x)).collect()
+    };
+    let items: Vec<_> = old
+        .iter()
+        .zip(new.iter())
+        .enumerate()
+        .filter_map(|(index, (mut old, mut new))| {
+            let (old, new) = (format(&mut old), format(&mut new));
+            if old == new {
+                None
+            } else {
+                Some(ItemDiff { index, old, new })
+            }
+        })
+        .collect();
+    if items.is_empty() && old.len() == new.len() {
+        return None;
+    }
+    Some(ResourceDiffKind::MultiVector {
+        old_len: old.len(),
+        new_len: new.len(),
+        items,
+    })
+}
+
+fn diff_raw_data(old: &RawData, new: &RawData) -> Option<ResourceDiffKind> {
+    let (old, new) = (old.as_bytes(), new.as_bytes());
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (index, _) in old
+        .iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+    {
+        match ranges.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    if ranges.is_empty() && old.len() == new.len() {
+        return None;
+    }
+    Some(ResourceDiffKind::RawData {
+        old_len: old.len(),
+        new_len: new.len(),
+        ranges,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, MemoryResourceStorage, StorageHandle};
+
+    fn create_y(path: &str, values: &[(u32, u32)]) -> StorageHandle {
+        let storage = MemoryResourceStorage::new(path);
+        let builder = YBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        for &(first_x, y) in values {
+            let r = data.grow().expect("grow failed");
+            r.set_first_x(first_x);
+            r.set_y(y);
+        }
+        data.close().expect("failed to close data");
+        storage
+    }
+
+    #[test]
+    fn test_equal() {
+        let old = DynArchive::open(create_y("/old", &[(1, 10), (3, 20)]), "Y").unwrap();
+        let new = DynArchive::open(create_y("/new", &[(1, 10), (3, 20)]), "Y").unwrap();
+        let diff = diff_archives(&old, &new).expect("diff failed");
+        assert_eq!(diff.name, ".test.Y");
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_vector() {
+        // the last element is the sentinel of the range
+        let old = create_y("/old", &[(1, 10), (3, 20), (7, 30), (8, 0)]);
+        let new = create_y("/new", &[(1, 10), (4, 20), (9, 0)]);
+        let (old, new) = (
+            DynArchive::open(old, "Y").unwrap(),
+            DynArchive::open(new, "Y").unwrap(),
+        );
+        let diff = diff_archives(&old, &new).expect("diff failed");
+        let data = diff.resource("data").expect("missing data diff");
+        assert_eq!(data.num_changes(), 1);
+        assert_eq!(
+            data.kind,
+            ResourceDiffKind::Vector {
+                old_len: 3,
+                new_len: 2,
+                elements: vec![ElementDiff {
+                    index: 1,
+                    fields: vec![FieldDiff {
+                        name: "first_x".into(),
+                        old: Some("3".into()),
+                        new: Some("4".into()),
+                    }],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_raw_data() {
+        let diff = |old: &[u8], new: &[u8]| diff_raw_data(&RawData::new(old), &RawData::new(new));
+        assert_eq!(diff(b"abc\0", b"abc\0"), None);
+        assert_eq!(
+            diff(b"abcdef", b"axcyyfg"),
+            Some(ResourceDiffKind::RawData {
+                old_len: 6,
+                new_len: 7,
+                ranges: vec![1..2, 3..5],
+            })
+        );
+    }
+
+    #[test]
+    fn test_multivector() {
+        let create = |path, id| {
+            let storage = MemoryResourceStorage::new(path);
+            let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+            let mut ab = builder.start_ab().expect("failed to start ab");
+            ab.grow().expect("grow failed").add_b().set_id(1);
+            ab.grow().expect("grow failed").add_b().set_id(id);
+            ab.close().expect("failed to close ab");
+            DynArchive::open(storage, "Z").expect("failed to open archive")
+        };
+        let diff = diff_archives(&create("/old", 2), &create("/new", 3)).expect("diff failed");
+        assert_eq!(
+            diff.resource("ab").expect("missing ab diff").kind,
+            ResourceDiffKind::MultiVector {
+                old_len: 2,
+                new_len: 2,
+                items: vec![ItemDiff {
+                    index: 1,
+                    old: vec!["B { id: 2 }".into()],
+                    new: vec!["B { id: 3 }".into()],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let old = DynArchive::open(create_y("/old", &[(1, 10)]), "Y").unwrap();
+        let storage = MemoryResourceStorage::new("/new");
+        let builder = WBuilder::new(storage.clone()).expect("failed to create builder");
+        builder.set_blob(b"abc").expect("failed to set blob");
+        let new = DynArchive::open(storage, "W").unwrap();
+
+        let diff = diff_archives(&old, &new).expect("diff failed");
+        assert_eq!(
+            diff.resources,
+            vec![
+                ResourceDiff {
+                    name: "data".into(),
+                    kind: ResourceDiffKind::Removed,
+                },
+                ResourceDiff {
+                    name: "blob".into(),
+                    kind: ResourceDiffKind::Added,
+                }
+            ]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::error::ResourceStorageError;
-use crate::structs::Struct;
+use crate::structs::{NoOverlap, Struct};
 
 /// Enhanced slices of flatdata Structs so that they can be created from bytes / converted to bytes
 /// Note: TryFrom/AsRef cannot be used, since slice is a foreign type
@@ -46,6 +46,354 @@ where
     }
 }
 
+/// Mutable slices of flatdata Structs which can be created from mutable bytes,
+/// e.g. from resources opened with [`FileResourceStorageMut`].
+///
+/// Only implemented for structs which do not overlap with the next element,
+/// since changing the ranges of a vector in place could break them.
+///
+/// [`FileResourceStorageMut`]: struct.FileResourceStorageMut.html
+pub trait SliceExtMut<'a>
+where
+    Self: Sized,
+{
+    /// Create a mutable slice from an array of bytes
+    fn from_bytes_mut(data: &'a mut [u8]) -> Result<Self, ResourceStorageError>;
+}
+
+impl<'a, T> SliceExtMut<'a> for &'a mut [T]
+where
+    T: Struct + NoOverlap,
+{
+    fn from_bytes_mut(data: &'a mut [u8]) -> Result<Self, ResourceStorageError> {
+        let len = <&[T]>::from_bytes(data)?.len();
+        unsafe {
+            Ok(std::slice::from_raw_parts_mut(
+                data.as_mut_ptr() as *mut T,
+                len,
+            ))
+        }
+    }
+}
+
+/// Serializes a slice of structs overlapping with the next one together with
+/// the sentinel element following it.
+///
+/// Used by the generated code, so that the serialized vector can be written
+/// back element by element.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct WithSentinel<'a, T>(pub &'a [T]);
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for WithSentinel<'_, T>
+where
+    T: Struct + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        debug_assert!(T::IS_OVERLAPPING_WITH_NEXT);
+        // Safety: slices of overlapping structs are always followed by a sentinel
+        let data = unsafe { std::slice::from_raw_parts(self.0.as_ptr(), self.0.len() + 1) };
+        serializer.collect_seq(data)
+    }
+}
+
+/// Lookups in slices of flatdata Structs sorted by a key
+///
+/// The key is usually extracted by a generated field getter, e.g.
+/// `view.lower_bound(&42, A::x)`. The slice must be sorted by the key, cf.
+/// [`is_sorted_by_field`] or the generated `is_sorted_by_<field>` functions.
+///
+/// Structs with ranges (cf. [`Overlap`]) can not be sorted in place, but
+/// views of them can be searched: only structs inside the slice are compared,
+/// and the last one reads the end of its ranges from the sentinel following
+/// the slice. For a lower bound by a predicate, the standard
+/// `partition_point` can be used directly.
+///
+/// [`is_sorted_by_field`]: #tymethod.is_sorted_by_field
+/// [`Overlap`]: trait.Overlap.html
+pub trait SortedSliceExt<'a, T> {
+    /// Binary searches the slice for a struct with the given key.
+    ///
+    /// Behaves like the standard `binary_search_by_key`, but takes the key by
+    /// reference, so that it can be used with keys which are not `Copy`.
+    fn binary_search_by_field<K, F>(&self, key: &K, f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns the position of the first struct with a key not less than `key`.
+    fn lower_bound<K, F>(&self, key: &K, f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns the position of the first struct with a key greater than `key`.
+    fn upper_bound<K, F>(&self, key: &K, f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns the positions of all structs with the given key.
+    fn equal_range_by_key<K, F>(&self, key: &K, f: F) -> std::ops::Range<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns the first struct with the given key.
+    fn find_by_key<K, F>(&self, key: &K, f: F) -> Option<&'a T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K;
+
+    /// Returns `true` if the keys of the structs are in non-decreasing order.
+    fn is_sorted_by_field<K, F>(&self, f: F) -> bool
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K;
+}
+
+impl<'a, T> SortedSliceExt<'a, T> for &'a [T]
+where
+    T: Struct,
+{
+    fn binary_search_by_field<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.binary_search_by(|x| f(x).cmp(key))
+    }
+
+    fn lower_bound<K, F>(&self, key: &K, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.partition_point(|x| f(x) < *key)
+    }
+
+    fn upper_bound<K, F>(&self, key: &K, mut f: F) -> usize
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.partition_point(|x| f(x) <= *key)
+    }
+
+    fn equal_range_by_key<K, F>(&self, key: &K, mut f: F) -> std::ops::Range<usize>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let start = self.lower_bound(key, &mut f);
+        let end = start + (&self[start..]).upper_bound(key, &mut f);
+        start..end
+    }
+
+    fn find_by_key<K, F>(&self, key: &K, mut f: F) -> Option<&'a T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let pos = self.lower_bound(key, &mut f);
+        self.get(pos).filter(|x| f(x) == *key)
+    }
+
+    fn is_sorted_by_field<K, F>(&self, mut f: F) -> bool
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K,
+    {
+        let mut keys = self.iter().map(&mut f);
+        let mut previous = match keys.next() {
+            Some(key) => key,
+            None => return true,
+        };
+        for key in keys {
+            if previous > key {
+                return false;
+            }
+            previous = key;
+        }
+        true
+    }
+}
+
+/// Parallel iteration through chunks of slices of flatdata Structs
+#[cfg(feature = "rayon")]
+pub trait ParallelSliceExt<'a, T> {
+    /// Returns a parallel iterator through consecutive chunks of at most
+    /// `chunk_size` structs, together with the position of the first struct
+    /// of each chunk in the slice.
+    ///
+    /// Chunks are never copied. For structs with ranges (cf. [`Overlap`]),
+    /// the last struct of a chunk still reads the end of its ranges from the
+    /// first struct of the next chunk, resp. from the sentinel following the
+    /// slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// [`Overlap`]: trait.Overlap.html
+    fn par_chunks_with_offset(self, chunk_size: usize) -> ParChunksWithOffset<'a, T>;
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> ParallelSliceExt<'a, T> for &'a [T]
+where
+    T: Struct + Sync,
+{
+    fn par_chunks_with_offset(self, chunk_size: usize) -> ParChunksWithOffset<'a, T> {
+        assert!(chunk_size != 0, "chunk_size must not be 0");
+        ParChunksWithOffset {
+            data: self,
+            chunk_size,
+        }
+    }
+}
+
+/// Parallel iterator through chunks of a slice and their positions.
+///
+/// Created by [`ParallelSliceExt::par_chunks_with_offset`].
+///
+/// [`ParallelSliceExt::par_chunks_with_offset`]: trait.ParallelSliceExt.html#tymethod.par_chunks_with_offset
+#[cfg(feature = "rayon")]
+#[derive(Debug, Clone)]
+pub struct ParChunksWithOffset<'a, T> {
+    data: &'a [T],
+    chunk_size: usize,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> rayon::iter::ParallelIterator for ParChunksWithOffset<'a, T>
+where
+    T: Struct + Sync,
+{
+    type Item = (usize, &'a [T]);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(rayon::iter::IndexedParallelIterator::len(self))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> rayon::iter::IndexedParallelIterator for ParChunksWithOffset<'a, T>
+where
+    T: Struct + Sync,
+{
+    fn len(&self) -> usize {
+        self.data.len().div_ceil(self.chunk_size)
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::Consumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: rayon::iter::plumbing::ProducerCallback<Self::Item>,
+    {
+        callback.callback(ChunksWithOffset {
+            data: self.data,
+            chunk_size: self.chunk_size,
+            offset: 0,
+        })
+    }
+}
+
+/// Sequential iterator through chunks of a slice and their positions, which
+/// is also the producer of the parallel iterator.
+#[cfg(feature = "rayon")]
+struct ChunksWithOffset<'a, T> {
+    data: &'a [T],
+    chunk_size: usize,
+    offset: usize,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> Iterator for ChunksWithOffset<'a, T> {
+    type Item = (usize, &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.data.split_at(self.chunk_size.min(self.data.len()));
+        let offset = self.offset;
+        self.data = rest;
+        self.offset += chunk.len();
+        Some((offset, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len().div_ceil(self.chunk_size);
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> DoubleEndedIterator for ChunksWithOffset<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let last_len = match self.data.len() % self.chunk_size {
+            0 => self.chunk_size,
+            remainder => remainder,
+        };
+        let (rest, chunk) = self.data.split_at(self.data.len() - last_len);
+        self.data = rest;
+        Some((self.offset + rest.len(), chunk))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> ExactSizeIterator for ChunksWithOffset<'a, T> {}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> rayon::iter::plumbing::Producer for ChunksWithOffset<'a, T>
+where
+    T: Struct + Sync,
+{
+    type Item = (usize, &'a [T]);
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self
+            .data
+            .split_at((index * self.chunk_size).min(self.data.len()));
+        (
+            Self {
+                data: left,
+                chunk_size: self.chunk_size,
+                offset: self.offset,
+            },
+            Self {
+                data: right,
+                chunk_size: self.chunk_size,
+                offset: self.offset + left.len(),
+            },
+        )
+    }
+}
+
 #[cfg(test)]
 #[allow(dead_code)]
 mod test {
@@ -80,6 +428,85 @@ mod test {
         assert_eq!(vec[0..1][0].x(), 10..20);
     }
 
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_chunks_with_offset() {
+        use rayon::prelude::*;
+
+        let mut vec: Vector<R> = Vector::with_len(101);
+        for i in 0..101 {
+            vec[i].set_first_x(10 * i as u32);
+        }
+        let view = vec.as_view();
+        assert_eq!(view.len(), 100);
+
+        let chunks: Vec<_> = view
+            .par_chunks_with_offset(7)
+            .map(|(offset, chunk)| (offset, chunk.len(), chunk.last().unwrap().x()))
+            .collect();
+        assert_eq!(chunks.len(), 15);
+        for (i, (offset, len, x)) in chunks.into_iter().enumerate() {
+            assert_eq!(offset, i * 7);
+            assert_eq!(len, 7.min(100 - i * 7));
+            let last = (offset + len - 1) as u32;
+            assert_eq!(x, 10 * last..10 * (last + 1));
+        }
+
+        let offsets: Vec<_> = view
+            .par_chunks_with_offset(7)
+            .rev()
+            .map(|(offset, _)| offset)
+            .collect();
+        assert_eq!(offsets, (0..100).step_by(7).rev().collect::<Vec<_>>());
+
+        let total: usize = view
+            .par_chunks_with_offset(3)
+            .with_max_len(1)
+            .map(|(_, chunk)| chunk.iter().map(|r| r.x().len()).sum::<usize>())
+            .sum();
+        assert_eq!(total, 1000);
+        assert_eq!((&view[..0]).par_chunks_with_offset(3).count(), 0);
+    }
+
+    #[test]
+    fn sorted_lookups() {
+        let mut vec: Vector<A> = Vector::new();
+        for &x in &[1, 3, 3, 3, 5, 8] {
+            vec.grow().set_x(x);
+        }
+        let view = vec.as_view();
+        assert!(view.is_sorted_by_field(A::x));
+        assert!(!view.is_sorted_by_field(|a| std::cmp::Reverse(a.x())));
+        assert!((&view[..0]).is_sorted_by_field(A::x));
+
+        assert_eq!(view.lower_bound(&3, A::x), 1);
+        assert_eq!(view.upper_bound(&3, A::x), 4);
+        assert_eq!(view.equal_range_by_key(&3, A::x), 1..4);
+        assert_eq!(view.equal_range_by_key(&4, A::x), 4..4);
+        assert_eq!(view.equal_range_by_key(&9, A::x), 6..6);
+        assert_eq!(view.lower_bound(&0, A::x), 0);
+        assert_eq!(view.binary_search_by_field(&5, A::x), Ok(4));
+        assert_eq!(view.binary_search_by_field(&6, A::x), Err(5));
+        assert_eq!(view.find_by_key(&8, A::x).map(A::x), Some(8));
+        assert!(view.find_by_key(&2, A::x).is_none());
+        assert!(view.find_by_key(&9, A::x).is_none());
+    }
+
+    #[test]
+    fn sorted_lookups_with_ranges() {
+        let mut vec: Vector<R> = Vector::new();
+        for &first_x in &[0, 2, 2, 7, 10] {
+            vec.grow().set_first_x(first_x);
+        }
+        // the last struct is the sentinel
+        let view = vec.as_view();
+        assert_eq!(view.len(), 4);
+        assert!(view.is_sorted_by_field(R::first_x));
+        assert_eq!(view.equal_range_by_key(&2, R::first_x), 1..3);
+        assert_eq!(view.find_by_key(&7, R::first_x).map(R::x), Some(7..10));
+        assert!(view.find_by_key(&10, R::first_x).is_none());
+    }
+
     #[test]
     fn into_iter() {
         for _ in create_values(10).as_view() {}
@@ -0,0 +1,441 @@
+//! Conversion of vectors and multivectors to [Apache Arrow] record batches.
+//!
+//! Every field of a struct becomes a column of the matching Arrow type:
+//! booleans become `Boolean`, integers the integer type of the same
+//! primitive type, and enums become dictionaries of the variant names with
+//! `Int32` keys. Values of enums without a variant are represented by their
+//! decimal value. Missing values of `@optional` fields become nulls.
+//!
+//! Large resources should be converted in chunks with [`record_batches`] and
+//! [`multivector_record_batches`] to bound the memory needed.
+//!
+//! [Apache Arrow]: https://arrow.apache.org
+//! [`record_batches`]: fn.record_batches.html
+//! [`multivector_record_batches`]: fn.multivector_record_batches.html
+
+use crate::{
+    arrayview::SliceExt,
+    dynamic::read_bits,
+    multiarrayview::MultiArrayView,
+    structs::{FieldInfo, IndexStruct, StructReflect, VariadicReflect},
+};
+
+use arrow_array::{
+    types::Int32Type, ArrayRef, BooleanArray, DictionaryArray, Int16Array, Int32Array, Int64Array,
+    Int8Array, RecordBatch, RecordBatchOptions, StringArray, UInt16Array, UInt32Array, UInt64Array,
+    UInt8Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+/// Name of the column containing the index of the item of a multivector.
+pub const BUCKET_COLUMN: &str = "bucket";
+
+fn data_type(field: &FieldInfo) -> DataType {
+    if field.enum_type.is_some() {
+        return DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    }
+    match field.primitive_type {
+        "bool" => DataType::Boolean,
+        "u8" => DataType::UInt8,
+        "i8" => DataType::Int8,
+        "u16" => DataType::UInt16,
+        "i16" => DataType::Int16,
+        "u32" => DataType::UInt32,
+        "i32" => DataType::Int32,
+        "u64" => DataType::UInt64,
+        "i64" => DataType::Int64,
+        x => panic!("unknown primitive type {}", x),
+    }
+}
+
+fn fields(fields: &[FieldInfo]) -> impl Iterator<Item = Field> + '_ {
+    fields
+        .iter()
+        .map(|field| Field::new(field.name, data_type(field), field.invalid_value.is_some()))
+}
+
+/// Returns the Arrow schema of the record batches created from structs of
+/// type `T`.
+pub fn arrow_schema<T: StructReflect>() -> SchemaRef {
+    Arc::new(Schema::new(fields(T::FIELDS).collect::<Vec<_>>()))
+}
+
+/// Returns the Arrow schemas of the record batches created from a
+/// multivector in the order of the type indexes of its variants.
+///
+/// The first column of each schema is the [`BUCKET_COLUMN`] containing the
+/// index of the item the element belongs to.
+///
+/// [`BUCKET_COLUMN`]: constant.BUCKET_COLUMN.html
+pub fn multivector_arrow_schemas<Ts: VariadicReflect>() -> Vec<SchemaRef> {
+    Ts::VARIANTS
+        .iter()
+        .map(|variant| {
+            let bucket = Field::new(BUCKET_COLUMN, DataType::UInt64, false);
+            let columns: Vec<_> = std::iter::once(bucket)
+                .chain(fields(variant.fields))
+                .collect();
+            Arc::new(Schema::new(columns))
+        })
+        .collect()
+}
+
+/// Decodes the values of a field of the structs at the given byte offsets of
+/// `data`.
+fn column(data: &[u8], offsets: &[usize], field: &FieldInfo) -> ArrayRef {
+    let values = offsets.iter().map(|&offset| {
+        let value = read_bits(
+            data,
+            offset * 8 + field.offset,
+            field.width,
+            field.is_signed,
+        );
+        Some(value).filter(|&value| Some(value) != field.invalid_value)
+    });
+    macro_rules! integers {
+        ($array:ident, $T:ty) => {
+            Arc::new(values.map(|x| x.map(|x| x as $T)).collect::<$array>())
+        };
+    }
+    if let Some(enum_type) = field.enum_type {
+        let mut names: Vec<String> = enum_type
+            .variants
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let mut keys: HashMap<i128, i32> = enum_type
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(key, &(_, value))| (value, key as i32))
+            .collect();
+        let keys: Int32Array = values
+            .map(|value| {
+                value.map(|value| {
+                    *keys.entry(value).or_insert_with(|| {
+                        names.push(value.to_string());
+                        names.len() as i32 - 1
+                    })
+                })
+            })
+            .collect();
+        let values = Arc::new(StringArray::from(names));
+        return Arc::new(
+            DictionaryArray::<Int32Type>::try_new(keys, values).expect("invalid dictionary"),
+        );
+    }
+    match field.primitive_type {
+        "bool" => Arc::new(values.map(|x| x.map(|x| x != 0)).collect::<BooleanArray>()),
+        "u8" => integers!(UInt8Array, u8),
+        "i8" => integers!(Int8Array, i8),
+        "u16" => integers!(UInt16Array, u16),
+        "i16" => integers!(Int16Array, i16),
+        "u32" => integers!(UInt32Array, u32),
+        "i32" => integers!(Int32Array, i32),
+        "u64" => integers!(UInt64Array, u64),
+        "i64" => integers!(Int64Array, i64),
+        x => panic!("unknown primitive type {}", x),
+    }
+}
+
+fn record_batch(
+    schema: SchemaRef,
+    columns: Vec<ArrayRef>,
+    num_rows: usize,
+) -> Result<RecordBatch, ArrowError> {
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+/// Converts a slice of structs to a record batch with one column per field.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::arrow::to_record_batch;
+/// # use flatdata::{test::{X, XBuilder}, MemoryResourceStorage};
+///
+/// # let storage = MemoryResourceStorage::new("/root/arrow");
+/// # let builder = XBuilder::new(storage.clone()).unwrap();
+/// # let mut data = builder.start_data().unwrap();
+/// # for &x in &[1, 2, 3] {
+/// #     data.grow().unwrap().set_x(x);
+/// # }
+/// # data.close().unwrap();
+/// # let archive = X::open(storage).unwrap();
+/// // archive contains a vector `data` of structs with fields `x`, `y` and `e`
+/// let batch = to_record_batch(archive.data()).expect("conversion failed");
+/// assert_eq!(batch.num_rows(), 3);
+/// assert_eq!(batch.num_columns(), 3);
+/// ```
+pub fn to_record_batch<T: StructReflect>(data: &[T]) -> Result<RecordBatch, ArrowError> {
+    let bytes = data.as_bytes();
+    let offsets: Vec<_> = (0..data.len()).map(|i| i * T::SIZE_IN_BYTES).collect();
+    let columns = T::FIELDS
+        .iter()
+        .map(|field| column(bytes, &offsets, field))
+        .collect();
+    record_batch(arrow_schema::<T>(), columns, data.len())
+}
+
+/// Returns an iterator converting a slice of structs to record batches of at
+/// most `batch_size` rows each.
+///
+/// # Panics
+///
+/// Panics if `batch_size` is `0`.
+pub fn record_batches<T: StructReflect>(data: &[T], batch_size: usize) -> RecordBatches<'_, T> {
+    assert!(batch_size > 0, "batch size must be positive");
+    RecordBatches { data, batch_size }
+}
+
+/// Iterator returned by [`record_batches`].
+///
+/// [`record_batches`]: fn.record_batches.html
+#[derive(Debug, Clone)]
+pub struct RecordBatches<'a, T> {
+    data: &'a [T],
+    batch_size: usize,
+}
+
+impl<T: StructReflect> Iterator for RecordBatches<'_, T> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let (batch, rest) = self.data.split_at(self.batch_size.min(self.data.len()));
+        self.data = rest;
+        Some(to_record_batch(batch))
+    }
+}
+
+/// Returns an iterator converting a multivector to record batches.
+///
+/// Each step converts at most `batch_size` items and yields one record batch
+/// per variant in the order of the type indexes, cf.
+/// [`multivector_arrow_schemas`]. Elements are matched to their items by the
+/// [`BUCKET_COLUMN`].
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::arrow::multivector_record_batches;
+/// # use flatdata::{test::{Z, ZBuilder}, MemoryResourceStorage};
+///
+/// # let storage = MemoryResourceStorage::new("/root/arrow");
+/// # let builder = ZBuilder::new(storage.clone()).unwrap();
+/// # let mut ab = builder.start_ab().unwrap();
+/// # {
+/// #     let mut item = ab.grow().unwrap();
+/// #     item.add_a().set_x(1);
+/// #     item.add_b().set_id(2);
+/// # }
+/// # ab.close().unwrap();
+/// # let archive = Z::open(storage).unwrap();
+/// // archive contains a multivector `ab` of structs `A` and `B`
+/// for batches in multivector_record_batches(archive.ab(), 1000) {
+///     let batches = batches.expect("conversion failed");
+///     let (a, b) = (&batches[0], &batches[1]);
+///     assert_eq!((a.num_rows(), b.num_rows()), (1, 1));
+/// }
+/// ```
+///
+/// [`multivector_arrow_schemas`]: fn.multivector_arrow_schemas.html
+/// [`BUCKET_COLUMN`]: constant.BUCKET_COLUMN.html
+///
+/// # Panics
+///
+/// Panics if `batch_size` is `0`.
+pub fn multivector_record_batches<'a, Ts: VariadicReflect>(
+    view: &MultiArrayView<'a, Ts>,
+    batch_size: usize,
+) -> MultiVectorRecordBatches<'a, Ts> {
+    assert!(batch_size > 0, "batch size must be positive");
+    MultiVectorRecordBatches {
+        view: view.clone(),
+        schemas: multivector_arrow_schemas::<Ts>(),
+        start: 0,
+        batch_size,
+        _phantom: PhantomData,
+    }
+}
+
+/// Iterator returned by [`multivector_record_batches`].
+///
+/// [`multivector_record_batches`]: fn.multivector_record_batches.html
+pub struct MultiVectorRecordBatches<'a, Ts: VariadicReflect> {
+    view: MultiArrayView<'a, Ts>,
+    schemas: Vec<SchemaRef>,
+    start: usize,
+    batch_size: usize,
+    _phantom: PhantomData<Ts>,
+}
+
+impl<Ts: VariadicReflect> std::fmt::Debug for MultiVectorRecordBatches<'_, Ts> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("MultiVectorRecordBatches")
+            .field("len", &self.view.len())
+            .field("start", &self.start)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl<Ts: VariadicReflect> Iterator for MultiVectorRecordBatches<'_, Ts> {
+    type Item = Result<Vec<RecordBatch>, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.view.len() {
+            return None;
+        }
+        let end = self.view.len().min(self.start + self.batch_size);
+        let data = self.view.data();
+        let mut buckets = vec![Vec::new(); Ts::VARIANTS.len()];
+        let mut offsets = vec![Vec::new(); Ts::VARIANTS.len()];
+        for (bucket, index) in self.view.index()[self.start..end].iter().enumerate() {
+            let range = index.range();
+            let mut offset = range.start;
+            while offset < range.end {
+                let type_index = data[offset] as usize;
+                let variant = Ts::VARIANTS
+                    .get(type_index)
+                    .unwrap_or_else(|| panic!("invalid type index {}", type_index));
+                buckets[type_index].push((self.start + bucket) as u64);
+                offsets[type_index].push(offset + 1);
+                offset += 1 + variant.size_in_bytes;
+            }
+        }
+        self.start = end;
+
+        let batches = Ts::VARIANTS
+            .iter()
+            .zip(&self.schemas)
+            .zip(buckets.into_iter().zip(offsets))
+            .map(|((variant, schema), (buckets, offsets))| {
+                let num_rows = buckets.len();
+                let columns = std::iter::once(Arc::new(UInt64Array::from(buckets)) as ArrayRef)
+                    .chain(
+                        variant
+                            .fields
+                            .iter()
+                            .map(|field| column(data, &offsets, field)),
+                    )
+                    .collect();
+                record_batch(schema.clone(), columns, num_rows)
+            })
+            .collect();
+        Some(batches)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, MemoryResourceStorage};
+
+    use arrow_array::{cast::AsArray, types::UInt32Type, Array};
+
+    #[test]
+    fn test_schema() {
+        let schema = arrow_schema::<A>();
+        assert_eq!(schema.fields().len(), 3);
+        assert_eq!(schema.field(0).name(), "x");
+        assert_eq!(schema.field(0).data_type(), &DataType::UInt32);
+        assert!(!schema.field(0).is_nullable());
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+
+        let schemas = multivector_arrow_schemas::<Ab>();
+        assert_eq!(schemas.len(), 2);
+        assert_eq!(schemas[1].field(0).name(), BUCKET_COLUMN);
+        assert_eq!(schemas[1].field(1).name(), "id");
+    }
+
+    #[test]
+    fn test_vector() {
+        let storage = MemoryResourceStorage::new("/root/arrow");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        for x in 0..10 {
+            let a = data.grow().expect("grow failed");
+            a.set_x(x);
+            a.set_y(0xffff - x);
+            // the enum has a single variant with value 0
+            a.set_e(if x % 2 == 0 {
+                E::Value
+            } else {
+                E::UnknownValue1
+            });
+        }
+        data.close().expect("failed to close data");
+        let archive = X::open(storage).expect("failed to open archive");
+
+        let batches: Vec<_> = record_batches(archive.data(), 4)
+            .collect::<Result<_, _>>()
+            .expect("conversion failed");
+        assert_eq!(
+            batches.iter().map(|x| x.num_rows()).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        let batch = &batches[1];
+        let x = batch.column(0).as_primitive::<UInt32Type>();
+        assert_eq!(x.values().to_vec(), vec![4, 5, 6, 7]);
+        let y = batch.column(1).as_primitive::<UInt32Type>();
+        assert_eq!(y.value(0), 0xffff - 4);
+        let e = batch.column(2).as_dictionary::<Int32Type>();
+        let names = e.values().as_string::<i32>();
+        let e: Vec<_> = e
+            .keys()
+            .iter()
+            .map(|key| names.value(key.unwrap() as usize))
+            .collect();
+        assert_eq!(e, vec!["Value", "1", "Value", "1"]);
+    }
+
+    #[test]
+    fn test_multivector() {
+        let storage = MemoryResourceStorage::new("/root/arrow");
+        let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut ab = builder.start_ab().expect("failed to start ab");
+        {
+            let mut item = ab.grow().expect("grow failed");
+            item.add_a().set_x(1);
+            item.add_b().set_id(2);
+            item.add_b().set_id(3);
+        }
+        ab.grow().expect("grow failed");
+        ab.grow().expect("grow failed").add_b().set_id(4);
+        ab.close().expect("failed to close ab");
+        let archive = Z::open(storage).expect("failed to open archive");
+
+        let batches: Vec<_> = multivector_record_batches(archive.ab(), 2)
+            .collect::<Result<_, _>>()
+            .expect("conversion failed");
+        assert_eq!(batches.len(), 2);
+        let (a, b) = (&batches[0][0], &batches[0][1]);
+        assert_eq!(a.num_rows(), 1);
+        assert_eq!(
+            a.column(0)
+                .as_primitive::<arrow_array::types::UInt64Type>()
+                .value(0),
+            0
+        );
+        assert_eq!(a.column(1).as_primitive::<UInt32Type>().value(0), 1);
+        let buckets = b.column(0).as_primitive::<arrow_array::types::UInt64Type>();
+        assert_eq!(buckets.values().to_vec(), vec![0, 0]);
+        let ids = b.column(1).as_primitive::<UInt32Type>();
+        assert_eq!(ids.values().to_vec(), vec![2, 3]);
+
+        let (a, b) = (&batches[1][0], &batches[1][1]);
+        assert_eq!(a.num_rows(), 0);
+        let buckets = b.column(0).as_primitive::<arrow_array::types::UInt64Type>();
+        assert_eq!(buckets.values().to_vec(), vec![2]);
+        assert_eq!(b.column(1).len(), 1);
+    }
+}
@@ -0,0 +1,317 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+};
+
+/// Amount of memory reserved from a [`MemoryBudget`] at once, so that writers
+/// do not need to touch the shared counter for every single element.
+///
+/// [`MemoryBudget`]: struct.MemoryBudget.html
+const RESERVATION_STEP: usize = 64 * 1024;
+
+/// Size of the buffer each writer can use without reserving memory from its
+/// [`MemoryBudget`], so that writers do not flush every single element when
+/// the budget is exhausted.
+///
+/// [`MemoryBudget`]: struct.MemoryBudget.html
+pub(crate) const MINIMUM_BUFFER_SIZE: usize = RESERVATION_STEP;
+
+static DEFAULT_BUFFERING: RwLock<Option<WriteBuffering>> = RwLock::new(None);
+
+/// Configures how much data [`ExternalVector`] and [`MultiVector`] buffer in
+/// memory before flushing it to storage.
+///
+/// Each writer flushes its buffer as soon as it would grow beyond
+/// [`buffer_size`]. Additionally, writers can share a [`MemoryBudget`]: a
+/// writer flushes early when the budget is exhausted by all writers together.
+///
+/// Writers created without explicit buffering use the process-wide default
+/// at the time they are created, cf. [`set_default`]. This includes the
+/// writers started from archive builders without explicit buffering.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{MemoryBudget, MemoryResourceStorage, WriteBuffering};
+/// use flatdata::test::XBuilder;
+///
+/// let storage = MemoryResourceStorage::new("/root/buffering");
+/// let mut builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+///
+/// // all resources of the archive share at most 256 MiB of buffers
+/// let budget = MemoryBudget::new(256 * 1024 * 1024);
+/// builder.set_buffering(WriteBuffering::new(8 * 1024 * 1024).with_budget(budget.clone()));
+///
+/// let mut v = builder.start_data().expect("failed to start");
+/// for _ in 0..100_000 {
+///     v.grow().expect("grow failed").set_x(1);
+/// }
+/// assert!(budget.used() > 0);
+/// v.close().expect("close failed");
+/// assert_eq!(budget.used(), 0);
+/// ```
+///
+/// [`ExternalVector`]: struct.ExternalVector.html
+/// [`MultiVector`]: struct.MultiVector.html
+/// [`MemoryBudget`]: struct.MemoryBudget.html
+/// [`buffer_size`]: #method.buffer_size
+/// [`set_default`]: #method.set_default
+#[derive(Debug, Clone)]
+pub struct WriteBuffering {
+    buffer_size: usize,
+    budget: Option<MemoryBudget>,
+}
+
+impl WriteBuffering {
+    /// Buffer size used if no other default was set.
+    pub const DEFAULT_BUFFER_SIZE: usize = 32 * 1024 * 1024;
+
+    /// Creates a configuration flushing each writer's buffer when it would
+    /// exceed `buffer_size` bytes.
+    pub fn new(buffer_size: usize) -> Self {
+        Self {
+            buffer_size,
+            budget: None,
+        }
+    }
+
+    /// Additionally limits the buffers of all writers using this
+    /// configuration by the given shared budget.
+    pub fn with_budget(mut self, budget: MemoryBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Maximum size in bytes of the buffer of a single writer.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Memory budget shared between writers, if any.
+    pub fn budget(&self) -> Option<&MemoryBudget> {
+        self.budget.as_ref()
+    }
+
+    /// Sets the process-wide default used by writers created afterwards.
+    ///
+    /// Writers which already exist keep their configuration. Archive builders
+    /// without explicit buffering use the new default for the writers they
+    /// start afterwards.
+    pub fn set_default(buffering: WriteBuffering) {
+        *DEFAULT_BUFFERING
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(buffering);
+    }
+}
+
+impl Default for WriteBuffering {
+    /// Returns the process-wide default, cf. [`set_default`].
+    ///
+    /// [`set_default`]: #method.set_default
+    fn default() -> Self {
+        DEFAULT_BUFFERING
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .unwrap_or_else(|| Self::new(Self::DEFAULT_BUFFER_SIZE))
+    }
+}
+
+/// Total amount of memory which can be used by the buffers of several writers.
+///
+/// Cloning a budget does not create a new budget, but another handle to the
+/// same one.
+///
+/// The first 64 KiB of the buffer of each writer are not taken from the
+/// budget, so that a writer does not flush every single element when the
+/// budget is exhausted. Beyond that, writers reserve memory from the budget in
+/// steps of 64 KiB and release it when flushing, closing or being dropped. A
+/// writer always buffers at least the element it is currently writing.
+#[derive(Clone)]
+pub struct MemoryBudget {
+    inner: Arc<BudgetInner>,
+}
+
+struct BudgetInner {
+    limit: usize,
+    used: AtomicUsize,
+}
+
+impl MemoryBudget {
+    /// Creates a budget of `limit` bytes.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Arc::new(BudgetInner {
+                limit,
+                used: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Size of the budget in bytes.
+    pub fn limit(&self) -> usize {
+        self.inner.limit
+    }
+
+    /// Number of bytes currently reserved by writers.
+    pub fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Relaxed)
+    }
+
+    fn try_reserve(&self, bytes: usize) -> bool {
+        self.inner
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(bytes)
+                    .filter(|&total| total <= self.inner.limit)
+            })
+            .is_ok()
+    }
+
+    fn release(&self, bytes: usize) {
+        self.inner.used.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Debug for MemoryBudget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "MemoryBudget {{ limit: {}, used: {} }}",
+            self.limit(),
+            self.used()
+        )
+    }
+}
+
+/// Memory reserved by a single writer from an optional budget.
+///
+/// The reservation is returned to the budget on drop.
+#[derive(Debug, Default)]
+pub(crate) struct Reservation {
+    budget: Option<MemoryBudget>,
+    bytes: usize,
+}
+
+impl Reservation {
+    pub(crate) fn new(budget: Option<MemoryBudget>) -> Self {
+        Self { budget, bytes: 0 }
+    }
+
+    /// Makes sure that a buffer of `bytes` is covered by the reservation.
+    ///
+    /// The first `MINIMUM_BUFFER_SIZE` bytes are always covered. Returns
+    /// `false` if the budget is exhausted. Without a budget, this always
+    /// succeeds.
+    pub(crate) fn reserve(&mut self, bytes: usize) -> bool {
+        let budget = match &self.budget {
+            Some(budget) => budget,
+            None => return true,
+        };
+        let bytes = bytes.saturating_sub(MINIMUM_BUFFER_SIZE);
+        if bytes <= self.bytes {
+            return true;
+        }
+        let missing = bytes - self.bytes;
+        let step = missing.max(RESERVATION_STEP);
+        if budget.try_reserve(step) {
+            self.bytes += step;
+            true
+        } else if step > missing && budget.try_reserve(missing) {
+            self.bytes += missing;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the reserved memory to the budget.
+    pub(crate) fn release(&mut self) {
+        if let Some(budget) = &self.budget {
+            budget.release(self.bytes);
+        }
+        self.bytes = 0;
+    }
+
+    /// Size of the buffer covered by the reservation, or `None` if there is
+    /// no budget.
+    pub(crate) fn reserved(&self) -> Option<usize> {
+        self.budget
+            .as_ref()
+            .map(|_| self.bytes + MINIMUM_BUFFER_SIZE)
+    }
+
+    /// Whether memory is tracked by a budget.
+    pub(crate) fn is_budgeted(&self) -> bool {
+        self.budget.is_some()
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reservation_without_budget() {
+        let mut reservation = Reservation::new(None);
+        assert!(reservation.reserve(usize::MAX));
+        assert!(!reservation.is_budgeted());
+    }
+
+    #[test]
+    fn reservation_is_shared_and_released() {
+        let budget = MemoryBudget::new(100 * 1024);
+        let mut first = Reservation::new(Some(budget.clone()));
+        let mut second = Reservation::new(Some(budget.clone()));
+
+        // covered by the minimum buffer
+        assert!(first.reserve(MINIMUM_BUFFER_SIZE));
+        assert_eq!(first.reserved(), Some(MINIMUM_BUFFER_SIZE));
+        assert_eq!(budget.used(), 0);
+
+        assert!(first.reserve(MINIMUM_BUFFER_SIZE + 10));
+        assert_eq!(budget.used(), RESERVATION_STEP);
+        // already covered by the first step
+        assert!(first.reserve(MINIMUM_BUFFER_SIZE + RESERVATION_STEP));
+        assert_eq!(budget.used(), RESERVATION_STEP);
+
+        // a full step does not fit anymore, but the exact amount does
+        assert!(second.reserve(MINIMUM_BUFFER_SIZE + 1024));
+        assert_eq!(budget.used(), RESERVATION_STEP + 1024);
+        assert!(!second.reserve(MINIMUM_BUFFER_SIZE + 100 * 1024));
+        assert_eq!(second.reserved(), Some(MINIMUM_BUFFER_SIZE + 1024));
+
+        first.release();
+        assert_eq!(budget.used(), 1024);
+        drop(second);
+        assert_eq!(budget.used(), 0);
+    }
+
+    #[test]
+    fn reservation_from_exhausted_budget() {
+        let budget = MemoryBudget::new(0);
+        let mut reservation = Reservation::new(Some(budget.clone()));
+        assert!(reservation.reserve(MINIMUM_BUFFER_SIZE));
+        assert!(!reservation.reserve(MINIMUM_BUFFER_SIZE + 1));
+        assert_eq!(budget.used(), 0);
+    }
+
+    #[test]
+    fn default_buffering() {
+        let buffering = WriteBuffering::new(1024);
+        assert_eq!(buffering.buffer_size(), 1024);
+        assert!(buffering.budget().is_none());
+        let budget = MemoryBudget::new(2048);
+        let buffering = buffering.with_budget(budget);
+        assert_eq!(buffering.budget().map(MemoryBudget::limit), Some(2048));
+    }
+}
@@ -0,0 +1,592 @@
+//! Module which contains helpers for building archives from a `serde::Deserializer`.
+//!
+//! The generated code implements the traits of this module for structs,
+//! variadic builders and archive builders. The accepted input mirrors the
+//! output of the `serde::Serialize` implementations:
+//!
+//! * a struct is a map from field names to values (or a sequence of values in
+//!   the order of the schema),
+//! * an enum value is the name of its variant,
+//! * an instance resource is a struct,
+//! * a vector resource is a sequence of structs; for structs with a range the
+//!   last element is the sentinel closing the last range,
+//! * a multivector resource is a sequence of items, each item being a
+//!   sequence of externally tagged variants, e.g. `{"A": {"x": 1}}`,
+//! * a raw data resource is a byte sequence or a string,
+//! * an archive (and a subarchive resource) is a map from resource names to
+//!   resources; a `null` resource is not written.
+
+use crate::{
+    multivector::MultiVector,
+    rawdata::RawDataWriter,
+    structs::{Struct, VariadicRefFactory, VariadicStruct},
+    vector::ExternalVector,
+};
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::{fmt, io::Write, str};
+
+/// Implemented by generated structs to deserialize their fields in place.
+pub trait DeserializeFields {
+    /// Name of the struct.
+    const NAME: &'static str;
+    /// Names of the fields in the order of the schema.
+    const FIELDS: &'static [&'static str];
+
+    /// Deserializes the field with the given index into `self`.
+    fn deserialize_field<'de, D: Deserializer<'de>>(
+        &mut self,
+        index: usize,
+        deserializer: D,
+    ) -> Result<(), D::Error>;
+}
+
+/// Implemented by generated builders of multivector items to add a variant
+/// deserialized in place.
+pub trait DeserializeVariant {
+    /// Name of the variadic type.
+    const NAME: &'static str;
+    /// Names of the variants in the order of the schema.
+    const VARIANTS: &'static [&'static str];
+
+    /// Adds the variant with the given index and deserializes its content.
+    fn deserialize_variant<'de, A: VariantAccess<'de>>(
+        &mut self,
+        index: usize,
+        variant: A,
+    ) -> Result<(), A::Error>;
+}
+
+/// Implemented by generated archive builders to write resources deserialized
+/// from a `serde::Deserializer`.
+pub trait DeserializeResources {
+    /// Name of the archive.
+    const NAME: &'static str;
+    /// Names of the resources in the order of the schema.
+    const RESOURCES: &'static [&'static str];
+
+    /// Deserializes the resource with the given index and writes it to the
+    /// archive.
+    fn deserialize_resource<'de, D: Deserializer<'de>>(
+        &self,
+        index: usize,
+        deserializer: D,
+    ) -> Result<(), D::Error>;
+}
+
+/// Deserializes a struct which can be created stand-alone.
+pub fn deserialize_struct<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeFields + Default,
+    D: Deserializer<'de>,
+{
+    let mut result = T::default();
+    StructSeed(&mut result).deserialize(deserializer)?;
+    Ok(result)
+}
+
+/// Deserializes the name of a unit variant and returns its index in
+/// `variants`.
+pub fn deserialize_unit_variant<'de, D>(
+    deserializer: D,
+    name: &'static str,
+    variants: &'static [&'static str],
+) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_enum(name, variants, UnitVariantVisitor(variants))
+}
+
+/// Deserializes a sequence of structs into `vector` and closes it.
+pub fn deserialize_vector<'de, T, D>(
+    mut vector: ExternalVector<'_, T>,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    T: Struct + DeserializeFields,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(VectorVisitor(&mut vector))?;
+    vector.close().map(|_| ()).map_err(de::Error::custom)
+}
+
+/// Deserializes a sequence of items into `vector` and closes it.
+pub fn deserialize_multivector<'de, Ts, D>(
+    mut vector: MultiVector<'_, Ts>,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    Ts: VariadicRefFactory,
+    for<'b> <Ts as VariadicStruct<'b>>::ItemMut: DeserializeVariant,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(MultiVectorVisitor(&mut vector))?;
+    vector.close().map(|_| ()).map_err(de::Error::custom)
+}
+
+/// Deserializes bytes into `writer` and closes it.
+pub fn deserialize_raw_data<'de, D>(
+    mut writer: RawDataWriter<'_>,
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(RawDataVisitor(&mut writer))?;
+    writer.close().map(|_| ()).map_err(de::Error::custom)
+}
+
+/// Deserializes a map of resources and writes them with `builder`.
+pub fn deserialize_archive<'de, B, D>(builder: &B, deserializer: D) -> Result<(), D::Error>
+where
+    B: DeserializeResources,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(ArchiveVisitor(builder))
+}
+
+/// Deserializes a struct in place.
+#[derive(Debug)]
+pub struct StructSeed<'b, T>(pub &'b mut T);
+
+impl<'de, T: DeserializeFields> DeserializeSeed<'de> for StructSeed<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_struct(T::NAME, T::FIELDS, self)
+    }
+}
+
+impl<'de, T: DeserializeFields> Visitor<'de> for StructSeed<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "struct {}", T::NAME)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for index in 0..T::FIELDS.len() {
+            let seed = FieldSeed {
+                target: &mut *self.0,
+                index,
+            };
+            if seq.next_element_seed(seed)?.is_none() {
+                return Err(de::Error::invalid_length(index, &self));
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut seen = vec![false; T::FIELDS.len()];
+        while let Some(index) = map.next_key_seed(Identifier::field(T::FIELDS))? {
+            if seen[index] {
+                return Err(de::Error::duplicate_field(T::FIELDS[index]));
+            }
+            seen[index] = true;
+            map.next_value_seed(FieldSeed {
+                target: &mut *self.0,
+                index,
+            })?;
+        }
+        match seen.iter().position(|seen| !seen) {
+            Some(index) => Err(de::Error::missing_field(T::FIELDS[index])),
+            None => Ok(()),
+        }
+    }
+}
+
+struct FieldSeed<'b, T> {
+    target: &'b mut T,
+    index: usize,
+}
+
+impl<'de, T: DeserializeFields> DeserializeSeed<'de> for FieldSeed<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.target.deserialize_field(self.index, deserializer)
+    }
+}
+
+/// Deserializes a field or variant name (or its index) into its index.
+struct Identifier {
+    names: &'static [&'static str],
+    is_variant: bool,
+}
+
+impl Identifier {
+    fn field(names: &'static [&'static str]) -> Self {
+        Self {
+            names,
+            is_variant: false,
+        }
+    }
+
+    fn variant(names: &'static [&'static str]) -> Self {
+        Self {
+            names,
+            is_variant: true,
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Identifier {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Identifier {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_variant {
+            write!(f, "one of the variants {:?}", self.names)
+        } else {
+            write!(f, "one of the names {:?}", self.names)
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<usize, E> {
+        if value < self.names.len() as u64 {
+            Ok(value as usize)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        match self.names.iter().position(|name| *name == value) {
+            Some(index) => Ok(index),
+            None if self.is_variant => Err(E::unknown_variant(value, self.names)),
+            None => Err(E::unknown_field(value, self.names)),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<usize, E> {
+        match str::from_utf8(value) {
+            Ok(value) => self.visit_str(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(value), &self)),
+        }
+    }
+}
+
+struct UnitVariantVisitor(&'static [&'static str]);
+
+impl<'de> Visitor<'de> for UnitVariantVisitor {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "one of the variants {:?}", self.0)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<usize, A::Error> {
+        let (index, variant) = data.variant_seed(Identifier::variant(self.0))?;
+        variant.unit_variant()?;
+        Ok(index)
+    }
+}
+
+struct VectorVisitor<'b, 'a, T: Struct>(&'b mut ExternalVector<'a, T>);
+
+impl<'de, T> Visitor<'de> for VectorVisitor<'_, '_, T>
+where
+    T: Struct + DeserializeFields,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {}", T::NAME)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(ElementSeed(&mut *self.0))?.is_some() {}
+        Ok(())
+    }
+}
+
+/// Appends an element to the vector only if there is one to deserialize.
+struct ElementSeed<'b, 'a, T: Struct>(&'b mut ExternalVector<'a, T>);
+
+impl<'de, T> DeserializeSeed<'de> for ElementSeed<'_, '_, T>
+where
+    T: Struct + DeserializeFields,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let element = self.0.grow().map_err(de::Error::custom)?;
+        StructSeed(element).deserialize(deserializer)
+    }
+}
+
+struct MultiVectorVisitor<'b, 'a, Ts: VariadicRefFactory>(&'b mut MultiVector<'a, Ts>);
+
+impl<'de, Ts> Visitor<'de> for MultiVectorVisitor<'_, '_, Ts>
+where
+    Ts: VariadicRefFactory,
+    for<'b> <Ts as VariadicStruct<'b>>::ItemMut: DeserializeVariant,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(ItemSeed(&mut *self.0))?.is_some() {}
+        Ok(())
+    }
+}
+
+/// Appends an item to the multivector only if there is one to deserialize.
+struct ItemSeed<'b, 'a, Ts: VariadicRefFactory>(&'b mut MultiVector<'a, Ts>);
+
+impl<'de, Ts> DeserializeSeed<'de> for ItemSeed<'_, '_, Ts>
+where
+    Ts: VariadicRefFactory,
+    for<'b> <Ts as VariadicStruct<'b>>::ItemMut: DeserializeVariant,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let mut item = self.0.grow().map_err(de::Error::custom)?;
+        deserializer.deserialize_seq(ItemVisitor(&mut item))
+    }
+}
+
+struct ItemVisitor<'b, T>(&'b mut T);
+
+impl<'de, T: DeserializeVariant> Visitor<'de> for ItemVisitor<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {}", T::NAME)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element_seed(VariantSeed(&mut *self.0))?.is_some() {}
+        Ok(())
+    }
+}
+
+struct VariantSeed<'b, T>(&'b mut T);
+
+impl<'de, T: DeserializeVariant> DeserializeSeed<'de> for VariantSeed<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_enum(T::NAME, T::VARIANTS, self)
+    }
+}
+
+impl<'de, T: DeserializeVariant> Visitor<'de> for VariantSeed<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "one of the variants {:?}", T::VARIANTS)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<(), A::Error> {
+        let (index, variant) = data.variant_seed(Identifier::variant(T::VARIANTS))?;
+        self.0.deserialize_variant(index, variant)
+    }
+}
+
+struct RawDataVisitor<'b, 'a>(&'b mut RawDataWriter<'a>);
+
+impl<'de> Visitor<'de> for RawDataVisitor<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bytes or a string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<(), E> {
+        self.0.write_all(value).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<(), E> {
+        self.visit_bytes(value.as_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(byte) = seq.next_element::<u8>()? {
+            self.0.write_all(&[byte]).map_err(de::Error::custom)?;
+        }
+        Ok(())
+    }
+}
+
+struct ArchiveVisitor<'b, B>(&'b B);
+
+impl<'de, B: DeserializeResources> Visitor<'de> for ArchiveVisitor<'_, B> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "archive {}", B::NAME)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut seen = vec![false; B::RESOURCES.len()];
+        while let Some(index) = map.next_key_seed(Identifier::field(B::RESOURCES))? {
+            if seen[index] {
+                return Err(de::Error::duplicate_field(B::RESOURCES[index]));
+            }
+            seen[index] = true;
+            map.next_value_seed(ResourceSeed {
+                builder: self.0,
+                index,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a resource unless it is `null`.
+struct ResourceSeed<'b, B> {
+    builder: &'b B,
+    index: usize,
+}
+
+impl<'de, B: DeserializeResources> DeserializeSeed<'de> for ResourceSeed<'_, B> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, B: DeserializeResources> Visitor<'de> for ResourceSeed<'_, B> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "resource {}", B::RESOURCES[self.index])
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        self.builder.deserialize_resource(self.index, deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        memstorage::MemoryResourceStorage,
+        test::{AbRef, WBuilder, XBuilder, YBuilder, ZBuilder, A, E, W, X, Y, Z},
+        StorageHandle,
+    };
+
+    fn deserialize<F, T>(json: &str, build: F) -> Result<T, serde_json::Error>
+    where
+        F: FnOnce(
+            StorageHandle,
+            &mut serde_json::Deserializer<serde_json::de::StrRead>,
+        ) -> Result<T, serde_json::Error>,
+    {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        build(storage, &mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn structs() {
+        let a: A = serde_json::from_str(r#"{"y": 2, "x": 1, "e": "Value"}"#).unwrap();
+        assert_eq!((a.x(), a.y(), a.e()), (1, 2, E::Value));
+        let a: A = serde_json::from_str(r#"[3, 4, "Value"]"#).unwrap();
+        assert_eq!((a.x(), a.y(), a.e()), (3, 4, E::Value));
+
+        let error = |json| serde_json::from_str::<A>(json).unwrap_err().to_string();
+        assert!(error(r#"{"x": 1, "y": 2}"#).starts_with("missing field `e`"));
+        assert!(error(r#"{"x": 1, "x": 2}"#).starts_with("duplicate field `x`"));
+        assert!(error(r#"{"z": 1}"#).starts_with("unknown field `z`"));
+        assert!(error(r#"{"x": 1, "y": 2, "e": "Other"}"#).starts_with("unknown variant `Other`"));
+        assert!(error(r#"[1, 2]"#).starts_with("invalid length 2"));
+    }
+
+    #[test]
+    fn vector() {
+        let json = r#"{"data": [{"x": 1, "y": 2, "e": "Value"}, {"x": 3, "y": 4, "e": "Value"}]}"#;
+        let archive = deserialize(json, |storage, deserializer| {
+            XBuilder::new(storage.clone())
+                .unwrap()
+                .deserialize(deserializer)?;
+            Ok(X::open(storage).unwrap())
+        })
+        .unwrap();
+        let data: Vec<_> = archive.data().iter().map(|a| (a.x(), a.y())).collect();
+        assert_eq!(data, [(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn vector_with_ranges() {
+        let json = r#"{"data": [{"first_x": 1, "y": 2}, {"first_x": 5, "y": 0}]}"#;
+        let archive = deserialize(json, |storage, deserializer| {
+            YBuilder::new(storage.clone())
+                .unwrap()
+                .deserialize(deserializer)?;
+            Ok(Y::open(storage).unwrap())
+        })
+        .unwrap();
+        assert_eq!(archive.data().len(), 1);
+        assert_eq!(archive.data()[0].x(), 1..5);
+        assert_eq!(archive.data()[0].y(), 2);
+    }
+
+    #[test]
+    fn multivector() {
+        let json = r#"{"ab": [[{"B": {"id": 1}}, {"A": {"x": 2, "y": 3, "e": "Value"}}], [], [{"B": {"id": 4}}]]}"#;
+        let archive = deserialize(json, |storage, deserializer| {
+            ZBuilder::new(storage.clone())
+                .unwrap()
+                .deserialize(deserializer)?;
+            Ok(Z::open(storage).unwrap())
+        })
+        .unwrap();
+        let ab = archive.ab();
+        assert_eq!(ab.len(), 3);
+        let item: Vec<_> = ab.at(0).collect();
+        match (&item[0], &item[1]) {
+            (AbRef::B(b), AbRef::A(a)) => assert_eq!((b.id(), a.x(), a.y()), (1, 2, 3)),
+            otherwise => panic!("unexpected value: {:?}", otherwise),
+        }
+        assert_eq!(ab.at(1).count(), 0);
+        assert_eq!(ab.at(2).count(), 1);
+    }
+
+    #[test]
+    fn raw_data() {
+        for json in &[r#"{"blob": "abc\u0000"}"#, r#"{"blob": [97, 98, 99, 0]}"#] {
+            let archive = deserialize(json, |storage, deserializer| {
+                WBuilder::new(storage.clone())
+                    .unwrap()
+                    .deserialize(deserializer)?;
+                Ok(W::open(storage).unwrap())
+            })
+            .unwrap();
+            assert_eq!(archive.blob().as_bytes(), b"abc\0");
+        }
+    }
+
+    #[test]
+    fn unknown_resource() {
+        let error = deserialize(r#"{"blob": "", "data": []}"#, |storage, deserializer| {
+            WBuilder::new(storage).unwrap().deserialize(deserializer)
+        })
+        .unwrap_err();
+        assert!(error.to_string().starts_with("unknown field `data`"));
+    }
+}
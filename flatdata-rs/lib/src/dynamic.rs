@@ -0,0 +1,716 @@
+use crate::{
+    error::{ResourceStorageError, SchemaError},
+    rawdata::RawData,
+    schema::{self, BasicType, ResourceType, Schema},
+    storage::{ResourceStorage, StorageHandle},
+};
+
+use std::{fmt, io, ops::Range, str};
+
+/// Value of a struct field read by [`DynStruct::get`].
+///
+/// [`DynStruct::get`]: struct.DynStruct.html#method.get
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value<'a> {
+    /// Value of a `bool` field.
+    Bool(bool),
+    /// Value of an unsigned integer field.
+    UInt(u64),
+    /// Value of a signed integer field.
+    Int(i64),
+    /// Value of an enum field.
+    Enum {
+        /// Underlying value.
+        value: i128,
+        /// Name of the variant, or `None` if the value has no variant.
+        variant: Option<&'a str>,
+    },
+    /// Missing value of an optional field.
+    Null,
+}
+
+impl Value<'_> {
+    /// Returns the value as integer, or `None` for missing values.
+    ///
+    /// Booleans are returned as `0` or `1`, and enums as their underlying
+    /// value.
+    pub fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Value::Bool(x) => Some(x as i128),
+            Value::UInt(x) => Some(x.into()),
+            Value::Int(x) => Some(x.into()),
+            Value::Enum { value, .. } => Some(value),
+            Value::Null => None,
+        }
+    }
+}
+
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Bool(x) => write!(f, "{}", x),
+            Value::UInt(x) => write!(f, "{}", x),
+            Value::Int(x) => write!(f, "{}", x),
+            Value::Enum {
+                variant: Some(variant),
+                ..
+            } => write!(f, "{}", variant),
+            Value::Enum { value, .. } => write!(f, "{}", value),
+            Value::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// Reads `width` bits at bit `offset` of `data`.
+///
+/// `data` must be followed by at least 8 bytes of memory, as it is the case for
+/// resources of archives, which are padded.
+pub(crate) fn read_bits(data: &[u8], offset: usize, width: usize, is_signed: bool) -> i128 {
+    if is_signed {
+        flatdata_read_bytes!(i64, data.as_ptr(), offset, width).into()
+    } else {
+        flatdata_read_bytes!(u64, data.as_ptr(), offset, width).into()
+    }
+}
+
+/// A struct read with a schema at runtime.
+///
+/// Provides the same values as the getters of the generated structs.
+#[derive(Clone)]
+pub struct DynStruct<'a> {
+    schema: &'a Schema,
+    definition: &'a schema::Struct,
+    // the struct followed by the rest of the resource
+    data: &'a [u8],
+}
+
+impl<'a> DynStruct<'a> {
+    fn new(schema: &'a Schema, definition: &'a schema::Struct, data: &'a [u8]) -> Self {
+        assert!(data.len() >= definition.size_in_bytes, "Corrupted data");
+        Self {
+            schema,
+            definition,
+            data,
+        }
+    }
+
+    /// Definition of the struct in the schema.
+    pub fn definition(&self) -> &'a schema::Struct {
+        self.definition
+    }
+
+    /// Returns the value of the field with the given name, or `None` if there
+    /// is no such field.
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        self.definition
+            .field(name)
+            .map(|field| self.decode(field, field.offset))
+    }
+
+    /// Returns the range defined by `@range(name)`, or `None` if there is no
+    /// such range.
+    pub fn range(&self, name: &str) -> Option<Range<Value<'a>>> {
+        let field = self
+            .definition
+            .fields
+            .iter()
+            .find(|field| field.range.as_deref() == Some(name))?;
+        let next = field.offset + self.definition.size_in_bytes * 8;
+        Some(self.decode(field, field.offset)..self.decode(field, next))
+    }
+
+    /// Returns an iterator through the names and values of all fields.
+    pub fn fields(&self) -> impl Iterator<Item = (&'a str, Value<'a>)> + '_ {
+        self.definition
+            .fields
+            .iter()
+            .map(move |field| (&field.name[..], self.decode(field, field.offset)))
+    }
+
+    /// Raw bytes of the struct.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        &self.data[..self.definition.size_in_bytes]
+    }
+
+    fn decode(&self, field: &'a schema::Field, offset: usize) -> Value<'a> {
+        let value = read_bits(self.data, offset, field.width, field.basic_type.is_signed());
+        if field.invalid_value == Some(value) {
+            return Value::Null;
+        }
+        if let Some(enum_type) = &field.enum_type {
+            let variant = self
+                .schema
+                .find_enum(enum_type)
+                .and_then(|x| x.variant_name(value));
+            return Value::Enum { value, variant };
+        }
+        match field.basic_type {
+            BasicType::Bool => Value::Bool(value != 0),
+            x if x.is_signed() => Value::Int(value as i64),
+            _ => Value::UInt(value as u64),
+        }
+    }
+}
+
+impl fmt::Debug for DynStruct<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.definition.name.rsplit('.').next().unwrap_or_default();
+        let mut s = f.debug_struct(name);
+        for (name, value) in self.fields() {
+            s.field(name, &format_args!("{}", value));
+        }
+        s.finish()
+    }
+}
+
+/// A vector of structs read with a schema at runtime.
+#[derive(Clone)]
+pub struct DynVector<'a> {
+    schema: &'a Schema,
+    definition: &'a schema::Struct,
+    data: &'a [u8],
+    len: usize,
+}
+
+impl<'a> DynVector<'a> {
+    fn new(
+        schema: &'a Schema,
+        definition: &'a schema::Struct,
+        data: &'a [u8],
+    ) -> Result<Self, ResourceStorageError> {
+        let size = definition.size_in_bytes;
+        if size == 0 || !data.len().is_multiple_of(size) {
+            return Err(ResourceStorageError::UnexpectedDataSize);
+        }
+        let mut len = data.len() / size;
+        if definition.has_range() {
+            // the last element is a sentinel
+            len = len
+                .checked_sub(1)
+                .ok_or(ResourceStorageError::UnexpectedDataSize)?;
+        }
+        Ok(Self {
+            schema,
+            definition,
+            data,
+            len,
+        })
+    }
+
+    /// Definition of the elements in the schema.
+    pub fn definition(&self) -> &'a schema::Struct {
+        self.definition
+    }
+
+    /// Number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<DynStruct<'a>> {
+        if index >= self.len {
+            return None;
+        }
+        let data = &self.data[index * self.definition.size_in_bytes..];
+        Some(DynStruct::new(self.schema, self.definition, data))
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn at(&self, index: usize) -> DynStruct<'a> {
+        self.get(index).expect("index out of bounds")
+    }
+
+    /// Returns an iterator through the elements.
+    pub fn iter(&self) -> impl Iterator<Item = DynStruct<'a>> + '_ {
+        (0..self.len).map(move |index| self.at(index))
+    }
+
+    /// Raw bytes of the vector, including the sentinel for structs with
+    /// ranges.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl fmt::Debug for DynVector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let preview: Vec<_> = self.iter().take(super::DEBUG_PREVIEW_LEN).collect();
+        write!(
+            f,
+            "DynVector {{ len: {}, data: {:?}{} }}",
+            self.len(),
+            preview,
+            if self.len() <= super::DEBUG_PREVIEW_LEN {
+                ""
+            } else {
+                "..."
+            }
+        )
+    }
+}
+
+/// A multivector read with a schema at runtime.
+#[derive(Clone)]
+pub struct DynMultiVector<'a> {
+    schema: &'a Schema,
+    types: Vec<&'a schema::Struct>,
+    index: &'a [u8],
+    index_width: usize,
+    data: &'a [u8],
+    len: usize,
+}
+
+impl<'a> DynMultiVector<'a> {
+    fn new(
+        schema: &'a Schema,
+        types: Vec<&'a schema::Struct>,
+        index_width: usize,
+        index: &'a [u8],
+        data: &'a [u8],
+    ) -> Result<Self, ResourceStorageError> {
+        let index_size = index_width.div_ceil(8);
+        if !index.len().is_multiple_of(index_size) || index.is_empty() {
+            return Err(ResourceStorageError::UnexpectedDataSize);
+        }
+        Ok(Self {
+            schema,
+            types,
+            index,
+            index_width,
+            data,
+            // the last index element is a sentinel
+            len: index.len() / index_size - 1,
+        })
+    }
+
+    /// Definitions of the types of the elements in the order of their type
+    /// index.
+    pub fn types(&self) -> &[&'a schema::Struct] {
+        &self.types
+    }
+
+    /// Number of items in the multivector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the multivector has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Range of the bytes of the item at `index` in the data of the
+    /// multivector.
+    fn item_range(&self, index: usize) -> Range<usize> {
+        let index_size_in_bits = self.index_width.div_ceil(8) * 8;
+        let offset = index * index_size_in_bits;
+        let read = |offset| read_bits(self.index, offset, self.index_width, false) as usize;
+        read(offset)..read(offset + index_size_in_bits)
+    }
+
+    /// Returns an iterator through the elements of the item at `index`, or
+    /// `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<DynMultiVectorItemIter<'a>> {
+        if index >= self.len {
+            return None;
+        }
+        let range = self.item_range(index);
+        Some(DynMultiVectorItemIter {
+            schema: self.schema,
+            types: self.types.clone(),
+            data: self.data.get(range).expect("Corrupted data"),
+        })
+    }
+
+    /// Returns an iterator through the elements of the item at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn at(&self, index: usize) -> DynMultiVectorItemIter<'a> {
+        self.get(index).expect("index out of bounds")
+    }
+
+    /// Returns an iterator through the items.
+    pub fn iter(&self) -> impl Iterator<Item = DynMultiVectorItemIter<'a>> + '_ {
+        (0..self.len).map(move |index| self.at(index))
+    }
+
+    /// Raw bytes of the index, including the sentinel.
+    pub fn index_as_bytes(&self) -> &'a [u8] {
+        self.index
+    }
+
+    /// Raw bytes of the data.
+    pub fn data_as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+impl fmt::Debug for DynMultiVector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let preview: Vec<(usize, Vec<_>)> = self
+            .iter()
+            .take(super::DEBUG_PREVIEW_LEN)
+            .map(|item| item.collect())
+            .enumerate()
+            .collect();
+        write!(
+            f,
+            "DynMultiVector {{ len: {}, data: {:?}{} }}",
+            self.len(),
+            preview,
+            if self.len() <= super::DEBUG_PREVIEW_LEN {
+                ""
+            } else {
+                "..."
+            }
+        )
+    }
+}
+
+/// Iterator through the elements of an item of a [`DynMultiVector`].
+///
+/// [`DynMultiVector`]: struct.DynMultiVector.html
+#[derive(Clone)]
+pub struct DynMultiVectorItemIter<'a> {
+    schema: &'a Schema,
+    types: Vec<&'a schema::Struct>,
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for DynMultiVectorItemIter<'a> {
+    type Item = DynStruct<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&type_index, data) = self.data.split_first()?;
+        let definition = *self
+            .types
+            .get(type_index as usize)
+            .unwrap_or_else(|| panic!("invalid type index {}", type_index));
+        let result = DynStruct::new(self.schema, definition, data);
+        self.data = &data[definition.size_in_bytes..];
+        Some(result)
+    }
+}
+
+impl fmt::Debug for DynMultiVectorItemIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let preview: Vec<_> = self.clone().collect();
+        write!(f, "DynMultiVectorItemIter {{ data: {:?} }}", preview)
+    }
+}
+
+/// A resource of a [`DynArchive`].
+///
+/// [`DynArchive`]: struct.DynArchive.html
+#[derive(Debug, Clone)]
+pub enum DynResource<'a> {
+    /// A single struct.
+    Instance(DynStruct<'a>),
+    /// A vector of structs.
+    Vector(DynVector<'a>),
+    /// A multivector.
+    MultiVector(DynMultiVector<'a>),
+    /// Raw bytes.
+    RawData(RawData<'a>),
+    /// A subarchive.
+    Archive(DynArchive),
+}
+
+/// An archive read with the schema stored in the archive.
+///
+/// In contrast to the generated archives, which check that the stored schema
+/// matches the schema they were generated from, a dynamic archive parses the
+/// schema stored in `<Name>.archive.schema` at runtime and reads its resources
+/// accordingly. This allows to write tools working on arbitrary archives.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{DynArchive, DynResource, MemoryResourceStorage};
+/// # use flatdata::test::XBuilder;
+///
+/// let storage = MemoryResourceStorage::new("/root/dynamic");
+/// # let builder = XBuilder::new(storage.clone()).unwrap();
+/// # let mut data = builder.start_data().unwrap();
+/// # data.grow().unwrap().set_x(17);
+/// # data.close().unwrap();
+/// // storage contains an archive `X` with a vector resource `data`
+/// let archive = DynArchive::open(storage, "X").expect("failed to open archive");
+/// match archive.resource("data").expect("failed to read resource") {
+///     Some(DynResource::Vector(data)) => {
+///         assert_eq!(data.at(0).get("x").map(|x| x.to_string()), Some("17".into()));
+///     }
+///     _ => panic!("unexpected resource"),
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynArchive {
+    storage: StorageHandle,
+    schema: Schema,
+    name: String,
+}
+
+/// Reads the schema stored for the given resource.
+fn read_schema(
+    storage: &dyn ResourceStorage,
+    resource_name: &str,
+) -> Result<String, ResourceStorageError> {
+    let schema = storage
+        .read_resource(&format!("{}.schema", resource_name))
+        .map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                ResourceStorageError::Missing
+            } else {
+                ResourceStorageError::from_io_error(e, resource_name.into())
+            }
+        })?;
+    Ok(str::from_utf8(schema)
+        .map_err(ResourceStorageError::Utf8Error)?
+        .into())
+}
+
+impl DynArchive {
+    /// Opens the archive with the given name, e.g. `"A"` for an archive stored
+    /// with the signature `A.archive`.
+    pub fn open(storage: StorageHandle, name: &str) -> Result<Self, ResourceStorageError> {
+        let signature_name = format!("{}.archive", name);
+        let schema = read_schema(&*storage, &signature_name)?;
+        storage.read(&signature_name, &schema)?;
+        let invalid_schema = |error| ResourceStorageError::InvalidSchema {
+            resource_name: signature_name.clone(),
+            error,
+        };
+        let schema = Schema::parse(&schema).map_err(invalid_schema)?;
+        // the archive itself is defined after the archives it depends on
+        let name = schema
+            .archives
+            .iter()
+            .rev()
+            .find(|archive| archive.name.rsplit('.').next() == Some(name))
+            .map(|archive| archive.name.clone())
+            .ok_or_else(|| {
+                invalid_schema(SchemaError {
+                    line: None,
+                    message: format!("missing archive {}", name),
+                })
+            })?;
+        Ok(Self {
+            storage,
+            schema,
+            name,
+        })
+    }
+
+    /// Fully qualified name of the archive.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Schema stored in the archive.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Definition of the archive in the schema.
+    pub fn definition(&self) -> &schema::Archive {
+        self.schema
+            .find_archive(&self.name)
+            .expect("archive missing in schema")
+    }
+
+    /// Storage of the archive.
+    pub fn storage(&self) -> &StorageHandle {
+        &self.storage
+    }
+
+    /// Reads the resource with the given name.
+    ///
+    /// Returns `None` if the resource is optional and missing. Fails with
+    /// [`ResourceStorageError::Missing`] if there is no such resource in the
+    /// schema.
+    ///
+    /// [`ResourceStorageError::Missing`]: enum.ResourceStorageError.html#variant.Missing
+    pub fn resource(&self, name: &str) -> Result<Option<DynResource<'_>>, ResourceStorageError> {
+        let resource = self
+            .definition()
+            .resource(name)
+            .ok_or(ResourceStorageError::Missing)?;
+        match self.read_resource(resource) {
+            Ok(x) => Ok(Some(x)),
+            Err(ResourceStorageError::Missing) if resource.is_optional => Ok(None),
+            Err(ResourceStorageError::Missing) => Err(ResourceStorageError::MissingData),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn read(&self, resource_name: &str) -> Result<&[u8], ResourceStorageError> {
+        let schema = read_schema(&*self.storage, resource_name)?;
+        self.storage.read(resource_name, &schema)
+    }
+
+    fn find_struct(
+        &self,
+        resource_name: &str,
+        name: &str,
+    ) -> Result<&schema::Struct, ResourceStorageError> {
+        self.schema
+            .find_struct(name)
+            .ok_or_else(|| ResourceStorageError::InvalidSchema {
+                resource_name: resource_name.into(),
+                error: SchemaError {
+                    line: None,
+                    message: format!("missing struct {}", name),
+                },
+            })
+    }
+
+    fn read_resource(
+        &self,
+        resource: &schema::Resource,
+    ) -> Result<DynResource<'_>, ResourceStorageError> {
+        let name = &resource.name[..];
+        Ok(match &resource.resource_type {
+            ResourceType::Instance(type_name) => {
+                let definition = self.find_struct(name, type_name)?;
+                let data = self.read(name)?;
+                if data.len() < definition.size_in_bytes {
+                    return Err(ResourceStorageError::UnexpectedDataSize);
+                }
+                DynResource::Instance(DynStruct::new(&self.schema, definition, data))
+            }
+            ResourceType::Vector(type_name) => {
+                let definition = self.find_struct(name, type_name)?;
+                let data = self.read(name)?;
+                DynResource::Vector(DynVector::new(&self.schema, definition, data)?)
+            }
+            ResourceType::MultiVector { index_width, types } => {
+                let types = types
+                    .iter()
+                    .map(|type_name| self.find_struct(name, type_name))
+                    .collect::<Result<_, _>>()?;
+                let index = self.read(&format!("{}_index", name))?;
+                let data = self.read(name)?;
+                DynResource::MultiVector(DynMultiVector::new(
+                    &self.schema,
+                    types,
+                    *index_width,
+                    index,
+                    data,
+                )?)
+            }
+            ResourceType::RawData => DynResource::RawData(RawData::new(self.read(name)?)),
+            ResourceType::Archive(type_name) => {
+                let archive_name = type_name.rsplit('.').next().unwrap_or_default();
+                DynResource::Archive(DynArchive::open(self.storage.subdir(name), archive_name)?)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, MemoryResourceStorage};
+
+    #[test]
+    fn test_vector() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let builder = YBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        for &(first_x, y) in &[(1, 10), (3, 20), (7, 30)] {
+            let r = data.grow().expect("grow failed");
+            r.set_first_x(first_x);
+            r.set_y(y);
+        }
+        data.close().expect("failed to close data");
+
+        let archive = DynArchive::open(storage, "Y").expect("failed to open archive");
+        assert_eq!(archive.name(), ".test.Y");
+        let data = match archive.resource("data").expect("failed to read data") {
+            Some(DynResource::Vector(data)) => data,
+            x => panic!("unexpected resource {:?}", x),
+        };
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.at(1).get("y"), Some(Value::UInt(20)));
+        assert_eq!(data.at(1).range("x"), Some(Value::UInt(3)..Value::UInt(7)));
+        assert_eq!(data.at(1).get("z"), None);
+        assert!(data.get(2).is_none());
+        assert_eq!(format!("{:?}", data.at(0)), "R { first_x: 1, y: 10 }");
+        assert!(archive.resource("unknown").is_err());
+    }
+
+    #[test]
+    fn test_instance_and_enum() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let builder = SBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut a = A::new();
+        a.set_x(1);
+        a.set_y(2);
+        builder.set_data(&a).expect("failed to set data");
+
+        let archive = DynArchive::open(storage, "S").expect("failed to open archive");
+        let data = match archive.resource("data").expect("failed to read data") {
+            Some(DynResource::Instance(data)) => data,
+            x => panic!("unexpected resource {:?}", x),
+        };
+        let fields: Vec<_> = data
+            .fields()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        assert_eq!(fields, ["x=1", "y=2", "e=Value"]);
+        assert_eq!(data.as_bytes(), a.as_bytes());
+    }
+
+    #[test]
+    fn test_multivector() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut ab = builder.start_ab().expect("failed to start ab");
+        {
+            let mut item = ab.grow().expect("grow failed");
+            item.add_a().set_x(1);
+            item.add_b().set_id(2);
+        }
+        ab.grow().expect("grow failed");
+        ab.close().expect("failed to close ab");
+
+        let archive = DynArchive::open(storage, "Z").expect("failed to open archive");
+        let ab = match archive.resource("ab").expect("failed to read ab") {
+            Some(DynResource::MultiVector(ab)) => ab,
+            x => panic!("unexpected resource {:?}", x),
+        };
+        assert_eq!(ab.len(), 2);
+        let item: Vec<_> = ab.at(0).collect();
+        assert_eq!(item.len(), 2);
+        assert_eq!(item[0].definition().name, ".test.A");
+        assert_eq!(item[0].get("x"), Some(Value::UInt(1)));
+        assert_eq!(item[1].definition().name, ".test.B");
+        assert_eq!(ab.at(1).count(), 0);
+    }
+
+    #[test]
+    fn test_raw_data_and_missing() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        assert!(DynArchive::open(storage.clone(), "W").is_err());
+
+        let builder = WBuilder::new(storage.clone()).expect("failed to create builder");
+        builder.set_blob(b"hello\0").expect("failed to set blob");
+        let archive = DynArchive::open(storage, "W").expect("failed to open archive");
+        match archive.resource("blob").expect("failed to read blob") {
+            Some(DynResource::RawData(blob)) => assert_eq!(blob.substring(0), Ok("hello")),
+            x => panic!("unexpected resource {:?}", x),
+        }
+    }
+}
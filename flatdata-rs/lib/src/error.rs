@@ -1,4 +1,4 @@
-use std::{error, fmt, io, str::Utf8Error};
+use std::{error, fmt, io, path::PathBuf, str::Utf8Error};
 
 /// Error indicating failures when reading and writing data from/to a
 /// [`Storage`].
@@ -45,6 +45,22 @@ pub enum ResourceStorageError {
     },
     /// A resource / archive is missing completely
     Missing,
+    /// A range of an element in a resource ends before it starts, e.g. since
+    /// its value was truncated to the width of the field
+    InvalidRange {
+        /// Resource name for which the error occurred.
+        resource_name: String,
+        /// Index of the element with the invalid range.
+        index: usize,
+    },
+    /// Indicates that the schema stored in resource storage could not be
+    /// parsed or does not define the expected elements.
+    InvalidSchema {
+        /// Resource name for which the error occurred.
+        resource_name: String,
+        /// Error in the schema.
+        error: SchemaError,
+    },
 }
 
 impl ResourceStorageError {
@@ -73,6 +89,151 @@ impl error::Error for ResourceStorageError {
             ResourceStorageError::WrongSignature { .. } => "schema is not matching expected schema",
             ResourceStorageError::TooBig { .. } => "resource is too big",
             ResourceStorageError::Missing => "Missing resource / archive",
+            ResourceStorageError::InvalidRange { .. } => "resource contains an invalid range",
+            ResourceStorageError::InvalidSchema { .. } => "schema of resource is invalid",
+        }
+    }
+}
+
+/// Error indicating that a value does not fit into the bit width of a struct
+/// field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldOverflowError {
+    /// Name of the struct.
+    pub struct_name: &'static str,
+    /// Name of the field.
+    pub field_name: &'static str,
+    /// Value which does not fit into the field.
+    pub value: i128,
+    /// Width of the field in bits.
+    pub num_bits: usize,
+}
+
+impl fmt::Display for FieldOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "value {} of {}::{} does not fit into {} bits",
+            self.value, self.struct_name, self.field_name, self.num_bits
+        )
+    }
+}
+
+impl error::Error for FieldOverflowError {}
+
+/// Error indicating that a flatdata schema could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    /// Line in the schema where the error occurred, if known.
+    pub line: Option<usize>,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl SchemaError {
+    pub(crate) fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl error::Error for SchemaError {}
+
+/// Error indicating that a resource could not be exported with
+/// [`export_resource`].
+///
+/// [`export_resource`]: fn.export_resource.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExportError {
+    /// Reading the resource from the archive failed.
+    Storage(ResourceStorageError),
+    /// Writing the file at the given path failed.
+    Io(io::Error, PathBuf),
+    /// The resource with the given name is neither a struct, nor a vector or
+    /// multivector.
+    UnsupportedResource(String),
+}
+
+impl From<ResourceStorageError> for ExportError {
+    fn from(error: ResourceStorageError) -> Self {
+        ExportError::Storage(error)
+    }
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ExportError::Storage(error) => write!(f, "{}", error),
+            ExportError::Io(error, path) => write!(f, "{}: {}", path.display(), error),
+            ExportError::UnsupportedResource(name) => {
+                write!(f, "resource {} cannot be exported", name)
+            }
+        }
+    }
+}
+
+impl error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ExportError::Storage(error) => Some(error),
+            ExportError::Io(error, _) => Some(error),
+            ExportError::UnsupportedResource(_) => None,
+        }
+    }
+}
+
+/// Error indicating that records could not be imported into a vector, e.g.
+/// with [`import_csv`].
+///
+/// [`import_csv`]: fn.import_csv.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImportError {
+    /// Reading the input or writing the vector failed.
+    Io(io::Error),
+    /// The record starting at the given line of the input does not match the
+    /// struct, e.g. since a column is missing or a value does not fit into
+    /// its field.
+    InvalidRecord {
+        /// Line of the input, starting at 1.
+        line: usize,
+        /// Description of the error.
+        message: String,
+    },
+}
+
+impl From<io::Error> for ImportError {
+    fn from(error: io::Error) -> Self {
+        ImportError::Io(error)
+    }
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ImportError::Io(error) => write!(f, "{}", error),
+            ImportError::InvalidRecord { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ImportError::Io(error) => Some(error),
+            ImportError::InvalidRecord { .. } => None,
         }
     }
 }
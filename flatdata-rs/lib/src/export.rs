@@ -0,0 +1,663 @@
+use crate::{
+    dynamic::{DynArchive, DynResource, DynStruct, Value},
+    error::{ExportError, ResourceStorageError},
+    rawdata::RawData,
+    schema,
+};
+
+#[cfg(feature = "parquet")]
+use crate::schema::BasicType;
+#[cfg(feature = "parquet")]
+use arrow_array::{
+    types::Int32Type, ArrayRef, BooleanArray, DictionaryArray, Int16Array, Int32Array, Int64Array,
+    Int8Array, RecordBatch, StringArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+#[cfg(feature = "parquet")]
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+#[cfg(feature = "parquet")]
+use parquet::arrow::ArrowWriter;
+#[cfg(feature = "parquet")]
+use std::sync::Arc;
+
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// Name of the column containing the index of the item of a multivector.
+pub const EXPORT_BUCKET_COLUMN: &str = "bucket";
+
+/// Number of rows written to Parquet files at once.
+#[cfg(feature = "parquet")]
+const BATCH_SIZE: usize = 64 * 1024;
+
+/// File format of [`export_resource`].
+///
+/// [`export_resource`]: fn.export_resource.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// Comma separated values with a header row.
+    ///
+    /// Missing values of `@optional` fields are empty.
+    Csv,
+    /// Apache Parquet, with columns of the same types as created by the
+    /// `arrow` module.
+    ///
+    /// Missing values of `@optional` fields are nulls.
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl ExportFormat {
+    /// Extension of the exported files.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            #[cfg(feature = "parquet")]
+            ExportFormat::Parquet => "parquet",
+        }
+    }
+}
+
+/// Options of [`export_resource`].
+///
+/// [`export_resource`]: fn.export_resource.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Format of the exported files.
+    pub format: ExportFormat,
+    /// Whether to replace fields with an `@explicit_reference` to a raw data
+    /// resource of the same archive by the referenced strings.
+    ///
+    /// References which cannot be resolved, e.g. missing optional values or
+    /// offsets out of range, are written as missing values.
+    pub resolve_references: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            format: ExportFormat::Csv,
+            resolve_references: false,
+        }
+    }
+}
+
+/// Exports the resource with the given name to files in `directory`, and
+/// returns their paths.
+///
+/// Every field of the struct becomes a column. A vector or a single struct is
+/// written to `<name>.<extension>`. Each variant of a multivector is written
+/// to a separate file `<name>.<Variant>.<extension>`, whose first column
+/// [`EXPORT_BUCKET_COLUMN`] contains the index of the item the element belongs
+/// to.
+///
+/// Values of enums are written as names of their variants, or as decimal
+/// values if they have no variant.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{export_resource, DynArchive, ExportOptions, MemoryResourceStorage};
+/// # use flatdata::test::XBuilder;
+///
+/// let storage = MemoryResourceStorage::new("/root/export");
+/// # let builder = XBuilder::new(storage.clone()).unwrap();
+/// # let mut data = builder.start_data().unwrap();
+/// # data.grow().unwrap().set_x(17);
+/// # data.close().unwrap();
+/// // storage contains an archive `X` with a vector resource `data`
+/// let archive = DynArchive::open(storage, "X").expect("failed to open archive");
+/// let directory = std::env::temp_dir();
+/// let files = export_resource(&archive, "data", &directory, &ExportOptions::default())
+///     .expect("failed to export");
+/// assert_eq!(files, vec![directory.join("data.csv")]);
+/// let csv = std::fs::read_to_string(&files[0]).unwrap();
+/// assert_eq!(csv, "x,y,e\n17,0,Value\n");
+/// # std::fs::remove_file(&files[0]).unwrap();
+/// ```
+///
+/// [`EXPORT_BUCKET_COLUMN`]: constant.EXPORT_BUCKET_COLUMN.html
+pub fn export_resource(
+    archive: &DynArchive,
+    name: &str,
+    directory: &Path,
+    options: &ExportOptions,
+) -> Result<Vec<PathBuf>, ExportError> {
+    let resource = archive
+        .resource(name)?
+        .ok_or(ResourceStorageError::Missing)?;
+    let definition = archive
+        .definition()
+        .resource(name)
+        .expect("missing resource");
+    let columns = |element| columns(archive, definition, element, options);
+    let path =
+        |suffix: &str| directory.join(format!("{}{}.{}", name, suffix, options.format.extension()));
+
+    match resource {
+        DynResource::Instance(element) => {
+            let mut table = Table::create(path(""), columns(element.definition()), false, options)?;
+            table.push(0, &element)?;
+            Ok(vec![table.finish()?])
+        }
+        DynResource::Vector(vector) => {
+            let mut table = Table::create(path(""), columns(vector.definition()), false, options)?;
+            for element in vector.iter() {
+                table.push(0, &element)?;
+            }
+            Ok(vec![table.finish()?])
+        }
+        DynResource::MultiVector(multivector) => {
+            let mut tables = multivector
+                .types()
+                .iter()
+                .map(|&variant| {
+                    let short_name = variant.name.rsplit('.').next().unwrap_or_default();
+                    let path = path(&format!(".{}", short_name));
+                    Table::create(path, columns(variant), true, options)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for (bucket, item) in multivector.iter().enumerate() {
+                for element in item {
+                    let index = multivector
+                        .types()
+                        .iter()
+                        .position(|&variant| std::ptr::eq(variant, element.definition()))
+                        .expect("unknown variant");
+                    tables[index].push(bucket, &element)?;
+                }
+            }
+            tables.into_iter().map(Table::finish).collect()
+        }
+        DynResource::RawData(_) | DynResource::Archive(_) => {
+            Err(ExportError::UnsupportedResource(name.into()))
+        }
+    }
+}
+
+/// A column of an exported table.
+struct Column<'a> {
+    field: &'a schema::Field,
+    /// Raw data the field references, if references are resolved.
+    strings: Option<RawData<'a>>,
+}
+
+fn columns<'a>(
+    archive: &'a DynArchive,
+    resource: &schema::Resource,
+    definition: &'a schema::Struct,
+    options: &ExportOptions,
+) -> Vec<Column<'a>> {
+    definition
+        .fields
+        .iter()
+        .map(|field| {
+            let strings = resource
+                .explicit_reference(&definition.name, &field.name)
+                .filter(|_| options.resolve_references)
+                .filter(|reference| reference.archive_name == archive.name())
+                .and_then(|reference| match archive.resource(&reference.destination) {
+                    Ok(Some(DynResource::RawData(strings))) => Some(strings),
+                    _ => None,
+                });
+            Column { field, strings }
+        })
+        .collect()
+}
+
+/// Value of a cell of an exported table.
+enum Cell<'a> {
+    Value(Value<'a>),
+    String(Cow<'a, str>),
+}
+
+impl Cell<'_> {
+    fn to_str(&self) -> Option<Cow<'_, str>> {
+        match self {
+            Cell::Value(Value::Null) => None,
+            Cell::Value(value) => Some(value.to_string().into()),
+            Cell::String(value) => Some(Cow::Borrowed(value)),
+        }
+    }
+
+    #[cfg(feature = "parquet")]
+    fn to_i128(&self) -> Option<i128> {
+        match self {
+            Cell::Value(value) => value.as_i128(),
+            Cell::String(_) => None,
+        }
+    }
+}
+
+impl<'a> Column<'a> {
+    fn cell(&self, element: &DynStruct<'a>) -> Cell<'a> {
+        let value = element.get(&self.field.name).expect("missing field");
+        let strings = match &self.strings {
+            Some(strings) => strings,
+            None => return Cell::Value(value),
+        };
+        match value.as_i128() {
+            Some(offset) if 0 <= offset && (offset as usize) < strings.as_bytes().len() => {
+                Cell::String(strings.substring_lossy(offset as usize))
+            }
+            // offsets must not be mixed with the strings of the column
+            _ => Cell::Value(Value::Null),
+        }
+    }
+}
+
+enum Sink {
+    Csv(BufWriter<File>),
+    #[cfg(feature = "parquet")]
+    Parquet {
+        writer: Box<ArrowWriter<File>>,
+        schema: SchemaRef,
+    },
+}
+
+/// A file the elements of a struct type are written to.
+struct Table<'a> {
+    path: PathBuf,
+    columns: Vec<Column<'a>>,
+    with_bucket: bool,
+    sink: Sink,
+    /// Buckets and cells of the rows not yet written to a Parquet file.
+    #[cfg(feature = "parquet")]
+    rows: Vec<(usize, Vec<Cell<'a>>)>,
+}
+
+impl<'a> Table<'a> {
+    fn create(
+        path: PathBuf,
+        columns: Vec<Column<'a>>,
+        with_bucket: bool,
+        options: &ExportOptions,
+    ) -> Result<Self, ExportError> {
+        let file = File::create(&path).map_err(|e| ExportError::Io(e, path.clone()))?;
+        let sink = match options.format {
+            ExportFormat::Csv => {
+                let mut out = BufWriter::new(file);
+                let names = columns.iter().map(|column| &column.field.name[..]);
+                let header: Vec<_> = if with_bucket {
+                    std::iter::once(EXPORT_BUCKET_COLUMN).chain(names).collect()
+                } else {
+                    names.collect()
+                };
+                write_csv_row(&mut out, &header).map_err(|e| ExportError::Io(e, path.clone()))?;
+                Sink::Csv(out)
+            }
+            #[cfg(feature = "parquet")]
+            ExportFormat::Parquet => {
+                let schema = parquet_schema(&columns, with_bucket);
+                let writer = ArrowWriter::try_new(file, schema.clone(), None)
+                    .map_err(|e| ExportError::Io(io::Error::other(e), path.clone()))?;
+                Sink::Parquet {
+                    writer: Box::new(writer),
+                    schema,
+                }
+            }
+        };
+        Ok(Self {
+            path,
+            columns,
+            with_bucket,
+            sink,
+            #[cfg(feature = "parquet")]
+            rows: Vec::new(),
+        })
+    }
+
+    fn push(&mut self, bucket: usize, element: &DynStruct<'a>) -> Result<(), ExportError> {
+        let cells: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| column.cell(element))
+            .collect();
+        let result = match &mut self.sink {
+            Sink::Csv(out) => {
+                let bucket = bucket.to_string();
+                let values = cells.iter().map(|cell| cell.to_str().unwrap_or_default());
+                let row: Vec<_> = if self.with_bucket {
+                    std::iter::once(Cow::Borrowed(&bucket[..]))
+                        .chain(values)
+                        .collect()
+                } else {
+                    values.collect()
+                };
+                write_csv_row(out, &row)
+            }
+            #[cfg(feature = "parquet")]
+            Sink::Parquet { .. } => {
+                self.rows.push((bucket, cells));
+                if self.rows.len() < BATCH_SIZE {
+                    return Ok(());
+                }
+                self.write_batch()
+            }
+        };
+        result.map_err(|e| ExportError::Io(e, self.path.clone()))
+    }
+
+    #[cfg(feature = "parquet")]
+    fn write_batch(&mut self) -> io::Result<()> {
+        if let Sink::Parquet { writer, schema } = &mut self.sink {
+            let mut arrays = Vec::new();
+            if self.with_bucket {
+                let buckets = self.rows.iter().map(|(bucket, _)| *bucket as u64);
+                arrays.push(Arc::new(buckets.collect::<UInt64Array>()) as ArrayRef);
+            }
+            for (index, column) in self.columns.iter().enumerate() {
+                let cells = self.rows.iter().map(|(_, cells)| &cells[index]);
+                arrays.push(parquet_column(column, cells));
+            }
+            let batch = RecordBatch::try_new(schema.clone(), arrays).map_err(io::Error::other)?;
+            writer.write(&batch).map_err(io::Error::other)?;
+            self.rows.clear();
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        match &mut self.sink {
+            Sink::Csv(out) => out.flush(),
+            #[cfg(feature = "parquet")]
+            Sink::Parquet { .. } => {
+                self.write_batch()?;
+                match &mut self.sink {
+                    Sink::Parquet { writer, .. } => {
+                        writer.finish().map(|_| ()).map_err(io::Error::other)
+                    }
+                    Sink::Csv(_) => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn finish(mut self) -> Result<PathBuf, ExportError> {
+        self.close()
+            .map_err(|e| ExportError::Io(e, self.path.clone()))?;
+        Ok(self.path)
+    }
+}
+
+/// Writes a row of a CSV file, quoting values as defined in RFC 4180.
+fn write_csv_row<S: AsRef<str>>(out: &mut dyn Write, values: &[S]) -> io::Result<()> {
+    for (index, value) in values.iter().enumerate() {
+        if index != 0 {
+            out.write_all(b",")?;
+        }
+        let value = value.as_ref();
+        if value.contains(&[',', '"', '\n', '\r'][..]) {
+            write!(out, "\"{}\"", value.replace('"', "\"\""))?;
+        } else {
+            out.write_all(value.as_bytes())?;
+        }
+    }
+    out.write_all(b"\n")
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_schema(columns: &[Column], with_bucket: bool) -> SchemaRef {
+    let bucket = Field::new(EXPORT_BUCKET_COLUMN, DataType::UInt64, false);
+    let fields = columns.iter().map(|column| {
+        let field = column.field;
+        let data_type = if column.strings.is_some() {
+            DataType::Utf8
+        } else if field.enum_type.is_some() {
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        } else {
+            match field.basic_type {
+                BasicType::Bool => DataType::Boolean,
+                BasicType::U8 => DataType::UInt8,
+                BasicType::I8 => DataType::Int8,
+                BasicType::U16 => DataType::UInt16,
+                BasicType::I16 => DataType::Int16,
+                BasicType::U32 => DataType::UInt32,
+                BasicType::I32 => DataType::Int32,
+                BasicType::U64 => DataType::UInt64,
+                BasicType::I64 => DataType::Int64,
+            }
+        };
+        let is_nullable = field.invalid_value.is_some() || column.strings.is_some();
+        Field::new(&field.name, data_type, is_nullable)
+    });
+    let fields: Vec<_> = if with_bucket {
+        std::iter::once(bucket).chain(fields).collect()
+    } else {
+        fields.collect()
+    };
+    Arc::new(Schema::new(fields))
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_column<'b>(column: &Column, cells: impl Iterator<Item = &'b Cell<'b>>) -> ArrayRef {
+    if column.strings.is_some() {
+        return Arc::new(cells.map(|cell| cell.to_str()).collect::<StringArray>());
+    }
+    if column.field.enum_type.is_some() {
+        let names: Vec<_> = cells.map(|cell| cell.to_str()).collect();
+        let names = names.iter().map(|name| name.as_deref());
+        return Arc::new(names.collect::<DictionaryArray<Int32Type>>());
+    }
+    let values = cells.map(|cell| cell.to_i128());
+    match column.field.basic_type {
+        BasicType::Bool => Arc::new(values.map(|x| x.map(|x| x != 0)).collect::<BooleanArray>()),
+        BasicType::U8 => Arc::new(values.map(|x| x.map(|x| x as u8)).collect::<UInt8Array>()),
+        BasicType::I8 => Arc::new(values.map(|x| x.map(|x| x as i8)).collect::<Int8Array>()),
+        BasicType::U16 => Arc::new(values.map(|x| x.map(|x| x as u16)).collect::<UInt16Array>()),
+        BasicType::I16 => Arc::new(values.map(|x| x.map(|x| x as i16)).collect::<Int16Array>()),
+        BasicType::U32 => Arc::new(values.map(|x| x.map(|x| x as u32)).collect::<UInt32Array>()),
+        BasicType::I32 => Arc::new(values.map(|x| x.map(|x| x as i32)).collect::<Int32Array>()),
+        BasicType::U64 => Arc::new(values.map(|x| x.map(|x| x as u64)).collect::<UInt64Array>()),
+        BasicType::I64 => Arc::new(values.map(|x| x.map(|x| x as i64)).collect::<Int64Array>()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, FileResourceStorage, MemoryResourceStorage, ResourceStorage};
+
+    use std::fs;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "flatdata-test-export-{}-{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(&path).expect("failed to create directory");
+        path
+    }
+
+    fn read(files: &[PathBuf]) -> Vec<String> {
+        files
+            .iter()
+            .map(|path| fs::read_to_string(path).expect("failed to read file"))
+            .collect()
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let mut out = Vec::new();
+        write_csv_row(&mut out, &["a", "b,c", "say \"hi\"", ""]).unwrap();
+        assert_eq!(out, b"a,\"b,c\",\"say \"\"hi\"\"\",\n");
+    }
+
+    #[test]
+    fn test_vector() {
+        let storage = MemoryResourceStorage::new("/root/export");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        for &(x, y) in &[(3, 1), (200, 2)] {
+            let a = data.grow().expect("grow failed");
+            a.set_x(x);
+            a.set_y(y);
+        }
+        data.close().expect("failed to close data");
+
+        let archive = DynArchive::open(storage, "X").expect("failed to open archive");
+        let path = temp_dir("vector");
+        let files = export_resource(&archive, "data", &path, &ExportOptions::default());
+        let files = files.expect("failed to export");
+        assert_eq!(files, vec![path.join("data.csv")]);
+        assert_eq!(read(&files), vec!["x,y,e\n3,1,Value\n200,2,Value\n"]);
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+    }
+
+    #[test]
+    fn test_multivector() {
+        let storage = MemoryResourceStorage::new("/root/export");
+        let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut ab = builder.start_ab().expect("failed to start ab");
+        {
+            let mut item = ab.grow().expect("grow failed");
+            item.add_a().set_x(1);
+            item.add_b().set_id(2);
+        }
+        ab.grow().expect("grow failed");
+        ab.grow().expect("grow failed").add_b().set_id(3);
+        ab.close().expect("failed to close ab");
+
+        let archive = DynArchive::open(storage, "Z").expect("failed to open archive");
+        let path = temp_dir("multivector");
+        let files = export_resource(&archive, "ab", &path, &ExportOptions::default());
+        let files = files.expect("failed to export");
+        assert_eq!(files, vec![path.join("ab.A.csv"), path.join("ab.B.csv")]);
+        assert_eq!(
+            read(&files),
+            vec!["bucket,x,y,e\n0,1,0,Value\n", "bucket,id\n0,2\n2,3\n"]
+        );
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let storage = MemoryResourceStorage::new("/root/export");
+        let builder = WBuilder::new(storage.clone()).expect("failed to create builder");
+        builder.set_blob(b"abc").expect("failed to set blob");
+        let archive = DynArchive::open(storage, "W").expect("failed to open archive");
+        let path = std::env::temp_dir();
+        match export_resource(&archive, "blob", &path, &ExportOptions::default()) {
+            Err(ExportError::UnsupportedResource(name)) => assert_eq!(name, "blob"),
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_resolve_references() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../tests/coappearances/assets/karenina.archive");
+        let storage = FileResourceStorage::new(assets);
+        let archive = DynArchive::open(storage, "Graph").expect("failed to open archive");
+        let path = temp_dir("references");
+        let options = ExportOptions {
+            format: ExportFormat::Csv,
+            resolve_references: true,
+        };
+        let files = export_resource(&archive, "vertices", &path, &options);
+        let vertices = read(&files.expect("failed to export")).remove(0);
+        let files = export_resource(&archive, "vertices_data", &path, &options);
+        let nicknames = read(&files.expect("failed to export")[..1]).remove(0);
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+
+        assert!(vertices.starts_with("name_ref\nAnnushka\nAgafea Mihalovna\n"));
+        assert!(nicknames.starts_with("bucket,ref\n7,Mitya\n19,Dolly\n"));
+    }
+
+    #[test]
+    fn test_unresolvable_references() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../tests/coappearances/assets/karenina.archive");
+        let path = temp_dir("unresolvable");
+        let archive_path = path.join("archive");
+        fs::create_dir_all(&archive_path).expect("failed to create directory");
+        for entry in fs::read_dir(&assets).expect("failed to read archive") {
+            let entry = entry.expect("failed to read entry");
+            if entry.path().is_file() {
+                fs::copy(entry.path(), archive_path.join(entry.file_name()))
+                    .expect("failed to copy resource");
+            }
+        }
+
+        // strings referenced by most of the vertices are cut off
+        let storage = FileResourceStorage::new(&archive_path);
+        let schema =
+            fs::read_to_string(archive_path.join("strings.schema")).expect("failed to read schema");
+        let strings = storage.read("strings", &schema).expect("failed to read");
+        let end = strings
+            .windows(9)
+            .position(|x| x == b"Annushka\0")
+            .expect("missing string")
+            + 9;
+        let strings = strings[..end].to_vec();
+        let storage = FileResourceStorage::new(&archive_path);
+        storage
+            .write("strings", &schema, &strings)
+            .expect("failed to write");
+
+        let archive = DynArchive::open(storage, "Graph").expect("failed to open archive");
+        let options = ExportOptions {
+            format: ExportFormat::Csv,
+            resolve_references: true,
+        };
+        let files = export_resource(&archive, "vertices", &path, &options);
+        let vertices = read(&files.expect("failed to export")).remove(0);
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+
+        let names: Vec<_> = vertices.lines().skip(1).collect();
+        assert_eq!(names[0], "Annushka");
+        assert!(names.contains(&""));
+        assert!(names.iter().all(|name| name.parse::<u64>().is_err()));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet() {
+        use arrow_array::cast::AsArray;
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let storage = MemoryResourceStorage::new("/root/export");
+        let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut ab = builder.start_ab().expect("failed to start ab");
+        ab.grow().expect("grow failed").add_a().set_x(7);
+        ab.grow().expect("grow failed").add_a().set_x(9);
+        ab.close().expect("failed to close ab");
+
+        let archive = DynArchive::open(storage, "Z").expect("failed to open archive");
+        let path = temp_dir("parquet");
+        let options = ExportOptions {
+            format: ExportFormat::Parquet,
+            resolve_references: false,
+        };
+        let files = export_resource(&archive, "ab", &path, &options).expect("failed to export");
+        assert_eq!(files[0], path.join("ab.A.parquet"));
+        let file = File::open(&files[0]).expect("failed to open file");
+        let batches: Vec<_> = ParquetRecordBatchReaderBuilder::try_new(file)
+            .and_then(|builder| builder.build())
+            .expect("failed to read file")
+            .collect::<Result<_, _>>()
+            .expect("failed to read batch");
+        fs::remove_dir_all(&path).expect("failed to remove directory");
+
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.schema().field(0).name(), EXPORT_BUCKET_COLUMN);
+        let buckets = batch
+            .column(0)
+            .as_primitive::<arrow_array::types::UInt64Type>();
+        assert_eq!(buckets.values(), &[0, 1]);
+        let x = batch
+            .column(1)
+            .as_primitive::<arrow_array::types::UInt32Type>();
+        assert_eq!(x.values(), &[7, 9]);
+        assert_eq!(
+            batch.schema().field(3).data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+    }
+}
@@ -1,12 +1,15 @@
-use crate::storage::{ResourceStorage, StorageHandle, Stream};
+use crate::{
+    error::ResourceStorageError,
+    storage::{check_resource_data, ResourceStorage, StorageHandle, Stream},
+};
 
-use memmap2::Mmap;
+use memmap2::{Mmap, MmapMut};
 
 use std::{
     collections::BTreeMap,
-    fs::{self, File},
-    io,
-    path::PathBuf,
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, Write},
+    path::{Path, PathBuf},
     slice,
     sync::{Arc, Mutex},
 };
@@ -15,6 +18,9 @@ use std::{
 #[derive(Debug, Default)]
 struct MemoryMappedFileStorage {
     maps: Mutex<BTreeMap<String, Mmap>>,
+    // Mappings of files which were replaced afterwards. They are kept alive,
+    // since data read from them might still be in use.
+    outdated: Mutex<Vec<Mmap>>,
 }
 
 impl MemoryMappedFileStorage {
@@ -30,6 +36,18 @@ impl MemoryMappedFileStorage {
         let extended_lifetime_data = unsafe { slice::from_raw_parts(data.as_ptr(), data.len()) };
         Ok(extended_lifetime_data)
     }
+
+    /// Makes sure that the next read of `path` maps the file again.
+    pub fn invalidate(&self, path: &str) {
+        if let Some(map) = self.maps.lock().unwrap().remove(path) {
+            self.outdated.lock().unwrap().push(map);
+        }
+    }
+
+    /// Unmaps `path`, which invalidates all data read from it.
+    pub unsafe fn unmap(&self, path: &str) {
+        self.maps.lock().unwrap().remove(path);
+    }
 }
 
 /// Resource storage on disk using memory mapped files.
@@ -54,7 +72,7 @@ impl MemoryMappedFileStorage {
 /// ```
 #[derive(Debug)]
 pub struct FileResourceStorage {
-    storage: MemoryMappedFileStorage,
+    storage: Arc<MemoryMappedFileStorage>,
     path: PathBuf,
 }
 
@@ -62,7 +80,7 @@ impl FileResourceStorage {
     /// Create an empty memory mapped file storage at a given path.
     pub fn new<P: Into<PathBuf>>(path: P) -> Arc<Self> {
         Arc::new(Self {
-            storage: MemoryMappedFileStorage::default(),
+            storage: Arc::default(),
             path: path.into(),
         })
     }
@@ -103,4 +121,430 @@ impl ResourceStorage for FileResourceStorage {
         let file = File::create(resource_path)?;
         Ok(Box::new(file))
     }
+
+    fn reopen_output_stream(&self, resource_name: &str, len: u64) -> io::Result<Box<dyn Stream>> {
+        // The resource might be mapped, so it is never modified in place. Instead,
+        // a copy is written and replaces it when flushed.
+        let resource_path = self.path.join(resource_name);
+        let source = File::open(&resource_path)?;
+        if source.metadata()?.len() < len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is shorter than {} bytes", resource_name, len),
+            ));
+        }
+        let temp_path = self.path.join(format!("{}.reopened", resource_name));
+        let mut file = File::create(&temp_path)?;
+        io::copy(&mut source.take(len), &mut file)?;
+        Ok(Box::new(ReplacingFile {
+            file,
+            temp_path,
+            resource_path,
+            storage: self.storage.clone(),
+            replaced: false,
+        }))
+    }
+
+    unsafe fn remove(&self, resource_name: &str) -> io::Result<()> {
+        let resource_path = self.path.join(resource_name);
+        fs::remove_file(&resource_path)?;
+        // The disk space is only freed when the file is not mapped anymore
+        if let Some(p) = resource_path.to_str() {
+            self.storage.unmap(p);
+        }
+        Ok(())
+    }
+}
+
+/// Copy of a reopened resource, which replaces the resource when flushed.
+struct ReplacingFile {
+    file: File,
+    temp_path: PathBuf,
+    resource_path: PathBuf,
+    storage: Arc<MemoryMappedFileStorage>,
+    replaced: bool,
+}
+
+impl Write for ReplacingFile {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.file.write(data)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if !self.replaced {
+            // Existing mappings keep referring to the replaced file
+            fs::rename(&self.temp_path, &self.resource_path)?;
+            self.replaced = true;
+            if let Some(p) = self.resource_path.to_str() {
+                self.storage.invalidate(p);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Seek for ReplacingFile {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.file.seek(pos)
+    }
+}
+
+impl Drop for ReplacingFile {
+    fn drop(&mut self) {
+        if !self.replaced {
+            // the resource is left unchanged
+            let _ = fs::remove_file(&self.temp_path);
+        }
+    }
+}
+
+/// Resource storage on disk using writable memory mapped files.
+///
+/// Used to modify the data of existing archives in place, without rewriting
+/// them. Resources are validated exactly like when opening them read-only, but
+/// can neither be created nor resized. Changes are written back to the files
+/// by the operating system at the latest when the storage is dropped; call
+/// [`flush`] to write them synchronously.
+///
+/// Generated archives are opened for modification with `{Archive}Mut::open`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use flatdata::FileResourceStorageMut;
+/// use flatdata::test::XMut;
+///
+/// let storage = FileResourceStorageMut::new("/root/to/my/archive");
+/// let mut archive = XMut::open(storage).expect("failed to open");
+/// for a in archive.data() {
+///     a.set_x(a.x() + 1);
+/// }
+/// archive.flush().expect("failed to flush");
+/// ```
+///
+/// [`flush`]: #method.flush
+#[derive(Debug)]
+pub struct FileResourceStorageMut {
+    maps: Mutex<BTreeMap<PathBuf, MmapMut>>,
+    path: PathBuf,
+}
+
+impl FileResourceStorageMut {
+    /// Create a writable memory mapped file storage of the archive at a
+    /// given path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Arc<Self> {
+        Arc::new(Self {
+            maps: Mutex::default(),
+            path: path.into(),
+        })
+    }
+
+    /// Creates a writable storage at a given subdirectory.
+    pub fn subdir(&self, dir: &str) -> Arc<Self> {
+        Self::new(self.path.join(dir))
+    }
+
+    /// Path of the archive.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` if resource exists in the storage.
+    pub fn exists(&self, resource_name: &str) -> bool {
+        self.path.join(resource_name).exists()
+    }
+
+    /// Maps a flatdata resource for writing, checks it and its schema like
+    /// [`ResourceStorage::read`] and returns its raw data.
+    ///
+    /// Each resource can be mapped only once per storage, further calls fail
+    /// with an error of kind `AlreadyExists`.
+    ///
+    /// [`ResourceStorage::read`]: trait.ResourceStorage.html#method.read
+    // Each mapping is handed out only once and never removed, so the returned
+    // slices do not alias and live as long as the storage.
+    #[allow(clippy::mut_from_ref)]
+    pub fn read_mut(
+        &self,
+        resource_name: &str,
+        schema: &str,
+    ) -> Result<&mut [u8], ResourceStorageError> {
+        let resource_path = self.path.join(resource_name);
+        let mut maps = self.maps.lock().unwrap();
+        if maps.contains_key(&resource_path) {
+            return Err(ResourceStorageError::from_io_error(
+                io::Error::new(io::ErrorKind::AlreadyExists, "resource is already mapped"),
+                resource_name.into(),
+            ));
+        }
+
+        let stored_schema = self.read_schema(resource_name)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&resource_path)
+            .map_err(|e| Self::map_io_error(e, resource_name))?;
+        let mut map = unsafe { MmapMut::map_mut(&file) }
+            .map_err(|e| ResourceStorageError::from_io_error(e, resource_name.into()))?;
+        let range = check_resource_data(resource_name, &map, &stored_schema, schema)?;
+
+        // We cannot prove to Rust that the buffer will live as long as the storage
+        // (we never delete mappings), so we need to manually extend lifetime
+        let data = unsafe { slice::from_raw_parts_mut(map.as_mut_ptr(), map.len()) };
+        maps.insert(resource_path, map);
+        Ok(&mut data[range])
+    }
+
+    /// Synchronously writes all modifications of the mapped resources to disk.
+    pub fn flush(&self) -> io::Result<()> {
+        for map in self.maps.lock().unwrap().values() {
+            map.flush()?;
+        }
+        Ok(())
+    }
+
+    fn read_file(&self, resource_name: &str) -> Result<Vec<u8>, ResourceStorageError> {
+        fs::read(self.path.join(resource_name)).map_err(|e| Self::map_io_error(e, resource_name))
+    }
+
+    fn read_schema(&self, resource_name: &str) -> Result<Vec<u8>, ResourceStorageError> {
+        let schema_name = format!("{}.schema", resource_name);
+        match self.read_file(&schema_name) {
+            Err(ResourceStorageError::Missing) if !self.exists(resource_name) => {
+                Err(ResourceStorageError::Missing)
+            }
+            Err(ResourceStorageError::Missing) => Err(ResourceStorageError::from_io_error(
+                io::Error::new(io::ErrorKind::NotFound, schema_name),
+                resource_name.into(),
+            )),
+            x => x,
+        }
+    }
+
+    fn map_io_error(e: io::Error, resource_name: &str) -> ResourceStorageError {
+        if e.kind() == io::ErrorKind::NotFound {
+            // resource is missing completely, this might not be an error,
+            // e.g. in case of optional resources / archives
+            ResourceStorageError::Missing
+        } else {
+            ResourceStorageError::from_io_error(e, resource_name.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{SBuilder, SMut, XBuilder, XMut, ZBuilder, ZMut, A, X};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "flatdata-test-filestorage-{}-{}",
+            name,
+            std::process::id()
+        ));
+        if path.exists() {
+            fs::remove_dir_all(&path).expect("failed to remove directory");
+        }
+        path
+    }
+
+    fn create_archive(path: &Path) {
+        let builder = XBuilder::new(FileResourceStorage::new(path)).expect("failed to create");
+        let mut data = builder.start_data().expect("failed to start data");
+        for x in 0..10 {
+            data.grow().expect("grow failed").set_x(x);
+        }
+        data.close().expect("failed to close data");
+    }
+
+    #[test]
+    fn modify_in_place() {
+        let path = temp_dir("modify");
+        create_archive(&path);
+
+        let mut archive = XMut::open(FileResourceStorageMut::new(&path)).expect("failed to open");
+        assert_eq!(archive.data().len(), 10);
+        for a in archive.data() {
+            a.set_y(a.x() * 2);
+        }
+        archive.flush().expect("failed to flush");
+
+        let archive = X::open(FileResourceStorage::new(&path)).expect("failed to open");
+        let values: Vec<_> = archive.data().iter().map(|a| (a.x(), a.y())).collect();
+        assert_eq!(values, (0..10).map(|x| (x, x * 2)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn open_mut_checks_all_resources() {
+        // a required multivector is missing
+        let path = temp_dir("missing_multivector");
+        ZBuilder::new(FileResourceStorage::new(&path)).expect("failed to create");
+        assert!(matches!(
+            ZMut::open(FileResourceStorageMut::new(&path)),
+            Err(ResourceStorageError::MissingData)
+        ));
+
+        // the schema of an instance resource does not match
+        let path = temp_dir("wrong_instance_schema");
+        let builder = SBuilder::new(FileResourceStorage::new(&path)).expect("failed to create");
+        builder.set_data(&A::new()).expect("failed to set data");
+        SMut::open(FileResourceStorageMut::new(&path)).expect("failed to open");
+        fs::write(path.join("data.schema"), "struct A {}").expect("failed to write schema");
+        assert!(matches!(
+            SMut::open(FileResourceStorageMut::new(&path)),
+            Err(ResourceStorageError::WrongSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn append_to_archive() {
+        let path = temp_dir("append");
+        create_archive(&path);
+
+        let storage = FileResourceStorage::new(&path);
+        let before = X::open(storage.clone()).expect("failed to open");
+        assert_eq!(before.data().len(), 10);
+        assert!(XBuilder::new(storage.clone()).is_err());
+        assert!(matches!(
+            XBuilder::open(FileResourceStorage::new(path.join("missing"))),
+            Err(ResourceStorageError::Missing)
+        ));
+
+        let builder = XBuilder::open(storage.clone()).expect("failed to open builder");
+
+        // an append which is not closed leaves the resource unchanged
+        let mut data = builder.append_data().expect("failed to append");
+        data.grow().expect("grow failed").set_x(100);
+        data.flush().expect("flush failed");
+        drop(data);
+        assert!(!path.join("data.reopened").exists());
+        let archive = X::open(FileResourceStorage::new(&path)).expect("failed to open");
+        assert_eq!(archive.data().len(), 10);
+
+        let mut data = builder.append_data().expect("failed to append");
+        for x in 10..15 {
+            data.grow().expect("grow failed").set_x(x);
+        }
+        data.close().expect("failed to close data");
+
+        let archive = X::open(storage).expect("failed to open");
+        let values: Vec<_> = archive.data().iter().map(|a| a.x()).collect();
+        assert_eq!(values, (0..15).collect::<Vec<_>>());
+
+        // data read before appending is not modified
+        let values: Vec<_> = before.data().iter().map(|a| a.x()).collect();
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn append_to_multivector_failure() {
+        let path = temp_dir("append_multivector");
+        let builder = ZBuilder::new(FileResourceStorage::new(&path)).expect("failed to create");
+        let mut ab = builder.start_ab().expect("failed to start");
+        ab.grow().expect("grow failed").add_a().set_x(1);
+        ab.close().expect("failed to close");
+        let read = || {
+            (
+                fs::read(path.join("ab_index")).unwrap(),
+                fs::read(path.join("ab")).unwrap(),
+            )
+        };
+        let original = read();
+
+        // an append which is not closed leaves both resources unchanged
+        let builder = ZBuilder::open(FileResourceStorage::new(&path)).expect("failed to open");
+        let mut ab = builder.append_ab().expect("failed to append");
+        ab.grow().expect("grow failed").add_b().set_id(2);
+        ab.flush().expect("flush failed");
+        drop(ab);
+        assert_eq!(read(), original);
+
+        // a mismatching schema of the data does not affect the index
+        fs::write(path.join("ab.schema"), "struct A {}").expect("failed to write schema");
+        let builder = ZBuilder::open(FileResourceStorage::new(&path)).expect("failed to open");
+        assert!(matches!(
+            builder.append_ab(),
+            Err(ResourceStorageError::WrongSignature { .. })
+        ));
+        assert_eq!(read(), original);
+    }
+
+    #[test]
+    fn read_mut_checks_resources() {
+        let path = temp_dir("check");
+        create_archive(&path);
+
+        let storage = FileResourceStorageMut::new(&path);
+        let schema = crate::test::schema::x::resources::DATA;
+        assert!(matches!(
+            storage.read_mut("data", "struct A {}"),
+            Err(ResourceStorageError::WrongSignature { .. })
+        ));
+        assert!(matches!(
+            storage.read_mut("missing", schema),
+            Err(ResourceStorageError::Missing)
+        ));
+        assert!(storage.read_mut("data", schema).is_ok());
+        assert!(matches!(
+            storage.read_mut("data", schema),
+            Err(ResourceStorageError::Io(ref e, _)) if e.kind() == io::ErrorKind::AlreadyExists
+        ));
+        assert!(matches!(
+            XMut::open(FileResourceStorageMut::new(path.join("missing"))),
+            Err(ResourceStorageError::Missing)
+        ));
+    }
+
+    #[test]
+    fn remove_resource() {
+        let path = temp_dir("remove");
+        create_archive(&path);
+
+        let storage = FileResourceStorage::new(&path);
+        storage
+            .read_resource("data")
+            .expect("failed to read resource");
+        assert_eq!(storage.storage.maps.lock().unwrap().len(), 1);
+        unsafe { storage.remove("data") }.expect("failed to remove");
+        assert!(!storage.exists("data"));
+        assert!(storage.exists("data.schema"));
+        // the mapping is released
+        assert!(storage.storage.maps.lock().unwrap().is_empty());
+        assert!(storage.storage.outdated.lock().unwrap().is_empty());
+        assert_eq!(
+            unsafe { storage.remove("data") }.map_err(|e| e.kind()),
+            Err(io::ErrorKind::NotFound)
+        );
+    }
+
+    #[test]
+    fn sorter_runs_are_released() {
+        let path = temp_dir("sorter");
+        let tmp = FileResourceStorage::new(&path);
+        let storage = crate::MemoryResourceStorage::new("/root/sorter");
+        let mut sorter = crate::ExternalSorter::by_key(&*tmp, |a: &A| a.x());
+        sorter.set_buffering(crate::WriteBuffering::new(
+            <A as crate::Struct>::SIZE_IN_BYTES,
+        ));
+        let mut a = A::new();
+        for x in (0..10).rev() {
+            a.set_x(x);
+            sorter.push(&a).expect("push failed");
+        }
+        assert_eq!(sorter.num_runs(), 9);
+        let output = crate::create_external_vector::<A>(&*storage, "sorted", "Some schema")
+            .expect("failed to create ExternalVector");
+        let view = sorter.finish(output).expect("sort failed");
+        assert_eq!(
+            view.iter().map(|a| a.x()).collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+
+        // neither files nor mappings of the runs are left
+        assert_eq!(fs::read_dir(&path).expect("failed to read dir").count(), 0);
+        assert!(tmp.storage.maps.lock().unwrap().is_empty());
+        assert!(tmp.storage.outdated.lock().unwrap().is_empty());
+    }
 }
@@ -1,5 +1,7 @@
 //! Module which contains internal helper traits.
 
+use crate::error::FieldOverflowError;
+
 /// Helper trait which defines a constant for an integer type whether it is signed.
 pub trait Int {
     /// `true` if the implementing type is signed, otherwise `false`.
@@ -41,3 +43,34 @@ impl Int for i64 {
 impl Int for u64 {
     const IS_SIGNED: bool = false;
 }
+
+/// Returns `true` if `value` can be stored in `num_bits` bits without loss.
+pub fn fits_in_bits<T: Int + Into<i128>>(value: T, num_bits: usize) -> bool {
+    let value = value.into();
+    if T::IS_SIGNED {
+        let bound = 1i128 << (num_bits - 1);
+        -bound <= value && value < bound
+    } else {
+        value < 1i128 << num_bits
+    }
+}
+
+/// Checks that `value` of the field `field_name` of the struct `struct_name`
+/// can be stored in `num_bits` bits without loss.
+pub fn check_bits<T: Int + Into<i128> + Copy>(
+    struct_name: &'static str,
+    field_name: &'static str,
+    value: T,
+    num_bits: usize,
+) -> Result<(), FieldOverflowError> {
+    if fits_in_bits(value, num_bits) {
+        Ok(())
+    } else {
+        Err(FieldOverflowError {
+            struct_name,
+            field_name,
+            value: value.into(),
+            num_bits,
+        })
+    }
+}
@@ -0,0 +1,428 @@
+use crate::{
+    de::{DeserializeFields, StructSeed},
+    error::ImportError,
+    structs::Struct,
+    vector::{copy_struct, ExternalVector, Vector},
+};
+
+use serde::de::{
+    self, value::Error as ValueError, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess,
+    Visitor,
+};
+use std::{io::BufRead, str::FromStr};
+
+/// Imports the records of a CSV file into `vector`, and returns the number of
+/// imported records.
+///
+/// The first line contains the names of the columns, which are mapped to the
+/// fields of the struct by name. Every field needs a column, and every column
+/// a field. Values are parsed as the type of their field and must fit into
+/// its width; enums are given by the names of their variants, and empty
+/// values of `@optional` fields are missing. Values containing commas,
+/// quotes or line breaks are quoted as defined in RFC 4180.
+///
+/// Records are streamed into the vector, so the input may be larger than
+/// the available memory. Import stops at the first invalid record; the
+/// records before it stay in the vector.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{import_csv, MemoryResourceStorage};
+/// use flatdata::test::{X, XBuilder};
+///
+/// let storage = MemoryResourceStorage::new("/root/import");
+/// let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+/// let mut data = builder.start_data().expect("failed to start data");
+/// let input = "x,y,e\n1,2,Value\n3,4,Value\n";
+/// let count = import_csv(input.as_bytes(), &mut data).expect("failed to import");
+/// assert_eq!(count, 2);
+/// data.close().expect("failed to close data");
+///
+/// let archive = X::open(storage).expect("failed to open");
+/// assert_eq!(archive.data()[1].y(), 4);
+/// ```
+pub fn import_csv<T>(
+    mut input: impl BufRead,
+    vector: &mut ExternalVector<'_, T>,
+) -> Result<usize, ImportError>
+where
+    T: Struct + DeserializeFields,
+{
+    let mut line = 0;
+    let mut headers = Vec::new();
+    if read_csv_record(&mut input, &mut line, &mut headers)?.is_none() {
+        return Ok(0);
+    }
+    let mut values = Vec::new();
+    let mut scratch = Vector::<T>::new();
+    let record_struct = scratch.grow();
+    let mut count = 0;
+    while let Some(start) = read_csv_record(&mut input, &mut line, &mut values)? {
+        if values.len() != headers.len() {
+            return Err(ImportError::InvalidRecord {
+                line: start,
+                message: format!("expected {} values, found {}", headers.len(), values.len()),
+            });
+        }
+        let record = CsvRecord {
+            headers: &headers,
+            values: &values,
+        };
+        StructSeed(&mut *record_struct)
+            .deserialize(record)
+            .map_err(|e| ImportError::InvalidRecord {
+                line: start,
+                message: e.to_string(),
+            })?;
+        copy_struct(record_struct, vector.grow()?);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Imports the records of a JSON Lines file into `vector`, and returns the
+/// number of imported records.
+///
+/// Every non-empty line contains a JSON object mapping the names of all
+/// fields to their values, in the same format as used by the `serde`
+/// support of the generated structs. Values must fit into the width of
+/// their field; enums are given by the names of their variants, and missing
+/// values of `@optional` fields are `null`.
+///
+/// Records are streamed into the vector, so the input may be larger than
+/// the available memory. Import stops at the first invalid record; the
+/// records before it stay in the vector.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{import_json_lines, MemoryResourceStorage};
+/// use flatdata::test::{X, XBuilder};
+///
+/// let storage = MemoryResourceStorage::new("/root/import");
+/// let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+/// let mut data = builder.start_data().expect("failed to start data");
+/// let input = r#"{"x": 1, "y": 2, "e": "Value"}"#;
+/// let count = import_json_lines(input.as_bytes(), &mut data).expect("failed to import");
+/// assert_eq!(count, 1);
+/// data.close().expect("failed to close data");
+///
+/// let archive = X::open(storage).expect("failed to open");
+/// assert_eq!(archive.data()[0].x(), 1);
+/// ```
+#[cfg(feature = "serde_json")]
+pub fn import_json_lines<T>(
+    input: impl BufRead,
+    vector: &mut ExternalVector<'_, T>,
+) -> Result<usize, ImportError>
+where
+    T: Struct + DeserializeFields,
+{
+    let mut scratch = Vector::<T>::new();
+    let record_struct = scratch.grow();
+    let mut count = 0;
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut deserializer = serde_json::Deserializer::from_str(&line);
+        StructSeed(&mut *record_struct)
+            .deserialize(&mut deserializer)
+            .and_then(|_| deserializer.end())
+            .map_err(|e| ImportError::InvalidRecord {
+                line: index + 1,
+                message: e.to_string(),
+            })?;
+        copy_struct(record_struct, vector.grow()?);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Reads the values of the next record of a CSV file into `values`, and
+/// returns the line the record starts at, or `None` at the end of the input.
+///
+/// Empty lines are skipped. `line` is the number of lines read so far.
+fn read_csv_record(
+    input: &mut impl BufRead,
+    line: &mut usize,
+    values: &mut Vec<String>,
+) -> Result<Option<usize>, ImportError> {
+    let mut buffer = String::new();
+    loop {
+        buffer.clear();
+        if input.read_line(&mut buffer)? == 0 {
+            return Ok(None);
+        }
+        *line += 1;
+        if !buffer.trim_end_matches(&['\r', '\n'][..]).is_empty() {
+            break;
+        }
+    }
+    let start = *line;
+    values.clear();
+    let mut value = String::new();
+    let mut is_quoted = false;
+    loop {
+        let mut chars = buffer.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if is_quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    value.push('"');
+                }
+                '"' => is_quoted = !is_quoted,
+                ',' if !is_quoted => values.push(std::mem::take(&mut value)),
+                '\r' | '\n' if !is_quoted => (),
+                c => value.push(c),
+            }
+        }
+        if !is_quoted {
+            values.push(value);
+            return Ok(Some(start));
+        }
+        buffer.clear();
+        if input.read_line(&mut buffer)? == 0 {
+            return Err(ImportError::InvalidRecord {
+                line: start,
+                message: "unterminated quoted value".into(),
+            });
+        }
+        *line += 1;
+    }
+}
+
+/// Deserializer of a CSV record as a map from column names to values.
+struct CsvRecord<'r> {
+    headers: &'r [String],
+    values: &'r [String],
+}
+
+impl<'de> Deserializer<'de> for CsvRecord<'_> {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        visitor.visit_map(CsvRecordAccess {
+            record: self,
+            index: 0,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct CsvRecordAccess<'r> {
+    record: CsvRecord<'r>,
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for CsvRecordAccess<'_> {
+    type Error = ValueError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ValueError> {
+        match self.record.headers.get(self.index) {
+            Some(name) => seed
+                .deserialize(name.as_str().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ValueError> {
+        let value = &self.record.values[self.index];
+        self.index += 1;
+        seed.deserialize(CsvValue(value))
+    }
+}
+
+/// Deserializer of a single value of a CSV record, parsing it as the
+/// requested type.
+struct CsvValue<'r>(&'r str);
+
+impl CsvValue<'_> {
+    fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ValueError> {
+        self.0
+            .trim()
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(self.0), &expected))
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $T:ty,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+                visitor.$visit(self.parse::<$T>(stringify!($T))?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for CsvValue<'_> {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_enum(self.0.trim().into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, MemoryResourceStorage};
+
+    fn import(
+        f: impl FnOnce(&mut ExternalVector<'_, A>) -> Result<usize, ImportError>,
+    ) -> Result<Vec<(u32, u32)>, ImportError> {
+        let storage = MemoryResourceStorage::new("/root/import");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        let count = f(&mut data)?;
+        let view = data.close().expect("failed to close data");
+        assert_eq!(view.len(), count);
+        Ok(view.iter().map(|a| (a.x(), a.y())).collect())
+    }
+
+    fn invalid_record(error: ImportError) -> (usize, String) {
+        match error {
+            ImportError::InvalidRecord { line, message } => (line, message),
+            x => panic!("unexpected error {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_read_csv_record() {
+        let mut input = "a,\"b,\"\"c\"\"\"\r\n\n\"multi\nline\",\n\"open".as_bytes();
+        let mut line = 0;
+        let mut values = Vec::new();
+        let record = read_csv_record(&mut input, &mut line, &mut values);
+        assert_eq!(record.expect("failed to read"), Some(1));
+        assert_eq!(values, ["a", "b,\"c\""]);
+        let record = read_csv_record(&mut input, &mut line, &mut values);
+        assert_eq!(record.expect("failed to read"), Some(3));
+        assert_eq!(values, ["multi\nline", ""]);
+        let error = read_csv_record(&mut input, &mut line, &mut values).unwrap_err();
+        assert_eq!(invalid_record(error).0, 5);
+    }
+
+    #[test]
+    fn test_import_csv() {
+        let input = "y,x,e\n2,1,Value\n\n 4 ,3,Value\n";
+        let result = import(|data| import_csv(input.as_bytes(), data));
+        assert_eq!(result.expect("failed to import"), [(1, 2), (3, 4)]);
+
+        let result = import(|data| import_csv("".as_bytes(), data));
+        assert_eq!(result.expect("failed to import"), []);
+    }
+
+    #[test]
+    fn test_import_csv_errors() {
+        let error = |input: &str| {
+            invalid_record(import(|data| import_csv(input.as_bytes(), data)).unwrap_err())
+        };
+        let (line, message) = error("x,y,e\n1,2,Value\n65536,2,Value\n");
+        assert_eq!(line, 3);
+        assert_eq!(message, "value 65536 of A::x does not fit into 16 bits");
+        let (_, message) = error("x,y,e\n1,2,Other\n");
+        assert!(message.contains("unknown variant `Other`"), "{}", message);
+        let (_, message) = error("x,y,e\n-1,2,Value\n");
+        assert!(message.contains("expected u32"), "{}", message);
+        let (_, message) = error("x,y\n1,2\n");
+        assert_eq!(message, "missing field `e`");
+        let (_, message) = error("x,y,z,e\n1,2,3,Value\n");
+        assert!(message.contains("unknown field `z`"), "{}", message);
+        let (_, message) = error("x,y,e\n1,2\n");
+        assert_eq!(message, "expected 3 values, found 2");
+    }
+
+    #[test]
+    fn test_import_csv_keeps_valid_records() {
+        let storage = MemoryResourceStorage::new("/root/import");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        let input = "x,y,e\n1,2,Value\n3,65536,Value\n5,6,Value\n";
+        assert!(import_csv(input.as_bytes(), &mut data).is_err());
+        let view = data.close().expect("failed to close data");
+        assert_eq!(view.len(), 1);
+        assert_eq!((view[0].x(), view[0].y()), (1, 2));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_import_json_lines() {
+        let input =
+            "{\"x\": 1, \"y\": 2, \"e\": \"Value\"}\n\n{\"e\": \"Value\", \"y\": 4, \"x\": 3}\n";
+        let result = import(|data| import_json_lines(input.as_bytes(), data));
+        assert_eq!(result.expect("failed to import"), [(1, 2), (3, 4)]);
+
+        let input =
+            "{\"x\": 1, \"y\": 2, \"e\": \"Value\"}\n{\"x\": 1, \"y\": 65536, \"e\": \"Value\"}";
+        let error = import(|data| import_json_lines(input.as_bytes(), data)).unwrap_err();
+        let (line, message) = invalid_record(error);
+        assert_eq!(line, 2);
+        assert!(
+            message.starts_with("value 65536 of A::y does not fit into 16 bits"),
+            "{}",
+            message
+        );
+        let input = "{\"x\": 1, \"y\": 2, \"e\": \"Value\"} 7";
+        let error = import(|data| import_json_lines(input.as_bytes(), data)).unwrap_err();
+        assert_eq!(invalid_record(error).0, 1);
+
+        // the invalid record is not written when closing the vector
+        let storage = MemoryResourceStorage::new("/root/import");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        let input = "{\"x\": 1, \"y\": 2, \"e\": \"Value\"}\n{\"x\": 3, \"y\": 4}\n";
+        assert!(import_json_lines(input.as_bytes(), &mut data).is_err());
+        let view = data.close().expect("failed to close data");
+        assert_eq!(view.len(), 1);
+        assert_eq!((view[0].x(), view[0].y()), (1, 2));
+    }
+}
@@ -18,6 +18,9 @@ struct MemoryStorage {
     streams: Arc<Mutex<BTreeMap<PathBuf, MemoryStorageStream>>>,
     // Data of resources that were opened for reading.
     resources: Arc<Mutex<BTreeMap<PathBuf, Arc<Vec<u8>>>>>,
+    // Data of resources that were replaced after reading them. It is kept
+    // alive, since it might still be in use.
+    outdated: Arc<Mutex<Vec<Arc<Vec<u8>>>>>,
 }
 
 impl fmt::Debug for MemoryStorage {
@@ -153,6 +156,92 @@ impl ResourceStorage for MemoryResourceStorage {
             .clone();
         Ok(Box::new(StreamWrapper { stream }))
     }
+
+    fn reopen_output_stream(&self, resource_name: &str, len: u64) -> io::Result<Box<dyn Stream>> {
+        let resource_path = self.path.join(resource_name);
+        let data = match self.storage.streams.lock().unwrap().get(&resource_path) {
+            Some(stream) => stream
+                .lock()
+                .unwrap()
+                .get_ref()
+                .get(..len as usize)
+                .map(<[u8]>::to_vec),
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    String::from(resource_path.to_str().unwrap_or(resource_name)),
+                ))
+            }
+        };
+        let data = data.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is shorter than {} bytes", resource_name, len),
+            )
+        })?;
+        let mut cursor = Cursor::new(data);
+        cursor.set_position(len);
+        Ok(Box::new(ReplacingStream {
+            stream: Arc::new(Mutex::new(cursor)),
+            path: resource_path,
+            storage: self.storage.clone(),
+            replaced: false,
+        }))
+    }
+
+    unsafe fn remove(&self, resource_name: &str) -> io::Result<()> {
+        let resource_path = self.path.join(resource_name);
+        let stream = self.storage.streams.lock().unwrap().remove(&resource_path);
+        let data = self
+            .storage
+            .resources
+            .lock()
+            .unwrap()
+            .remove(&resource_path);
+        if stream.is_none() && data.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                String::from(resource_path.to_str().unwrap_or(resource_name)),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Copy of a reopened resource, which replaces the resource when flushed.
+struct ReplacingStream {
+    stream: MemoryStorageStream,
+    path: PathBuf,
+    storage: MemoryStorage,
+    replaced: bool,
+}
+
+impl Seek for ReplacingStream {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::result::Result<u64, std::io::Error> {
+        self.stream.lock().unwrap().seek(pos)
+    }
+}
+
+impl Write for ReplacingStream {
+    fn write(&mut self, data: &[u8]) -> std::result::Result<usize, std::io::Error> {
+        self.stream.lock().unwrap().write(data)
+    }
+
+    fn flush(&mut self) -> std::result::Result<(), std::io::Error> {
+        if !self.replaced {
+            self.replaced = true;
+            self.storage
+                .streams
+                .lock()
+                .unwrap()
+                .insert(self.path.clone(), self.stream.clone());
+            // data read before stays alive, since it might still be in use
+            if let Some(data) = self.storage.resources.lock().unwrap().remove(&self.path) {
+                self.storage.outdated.lock().unwrap().push(data);
+            }
+        }
+        Ok(())
+    }
 }
 
 struct StreamWrapper {
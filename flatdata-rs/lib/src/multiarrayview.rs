@@ -1,4 +1,4 @@
-use crate::structs::{IndexStruct, VariadicRef, VariadicRefFactory, VariadicStruct};
+use crate::structs::{IndexStruct, VariadicRef, VariadicRefFactory, VariadicStruct, VariantOf};
 
 use std::{fmt, iter, marker};
 
@@ -63,6 +63,16 @@ where
         }
     }
 
+    /// Returns a read-only iterator to the elements of the item at position
+    /// `index`, or `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<MultiArrayViewItemIter<'a, Ts>> {
+        if index < self.len() {
+            Some(self.at(index))
+        } else {
+            None
+        }
+    }
+
     /// Slice this array view by a given range.
     ///
     /// # Panics
@@ -79,6 +89,18 @@ where
     pub fn iter(&self) -> MultiArrayViewIter<'a, Ts> {
         MultiArrayViewIter { view: self.clone() }
     }
+
+    /// Index of the items, without the sentinel.
+    #[cfg(feature = "arrow")]
+    pub(crate) fn index(&self) -> &'a [Ts::Index] {
+        self.index
+    }
+
+    /// Data of all items, followed by padding.
+    #[cfg(feature = "arrow")]
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.data
+    }
 }
 
 /// Iterator through elements of an array item.
@@ -114,6 +136,85 @@ where
 // we always check -> iterator is already fused
 impl<'a, Ts> iter::FusedIterator for MultiArrayViewItemIter<'a, Ts> where Ts: VariadicRefFactory {}
 
+impl<'a, Ts> MultiArrayViewItemIter<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+{
+    /// Returns an iterator through the elements of type `T` of the item,
+    /// skipping elements of all other types.
+    pub fn iter_variant<T>(self) -> MultiArrayViewVariantIter<'a, Ts, T>
+    where
+        T: VariantOf<Ts>,
+    {
+        MultiArrayViewVariantIter {
+            inner: self,
+            _phantom: marker::PhantomData,
+        }
+    }
+
+    /// Returns the first element of type `T` of the item, if any.
+    pub fn first_of<T>(&self) -> Option<&'a T>
+    where
+        T: VariantOf<Ts>,
+    {
+        self.clone().iter_variant::<T>().next()
+    }
+}
+
+/// Iterator through elements of a single type of an array item.
+///
+/// Created by [`MultiArrayViewItemIter::iter_variant`].
+///
+/// [`MultiArrayViewItemIter::iter_variant`]: struct.MultiArrayViewItemIter.html#method.iter_variant
+pub struct MultiArrayViewVariantIter<'a, Ts, T>
+where
+    Ts: VariadicRefFactory,
+{
+    inner: MultiArrayViewItemIter<'a, Ts>,
+    _phantom: marker::PhantomData<&'a T>,
+}
+
+impl<'a, Ts, T> Clone for MultiArrayViewVariantIter<'a, Ts, T>
+where
+    Ts: VariadicRefFactory,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _phantom: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, Ts, T> iter::Iterator for MultiArrayViewVariantIter<'a, Ts, T>
+where
+    Ts: VariadicRefFactory,
+    T: VariantOf<Ts> + 'a,
+{
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find_map(T::from_variant)
+    }
+}
+
+impl<'a, Ts, T> iter::FusedIterator for MultiArrayViewVariantIter<'a, Ts, T>
+where
+    Ts: VariadicRefFactory,
+    T: VariantOf<Ts> + 'a,
+{
+}
+
+impl<'a, Ts, T> fmt::Debug for MultiArrayViewVariantIter<'a, Ts, T>
+where
+    Ts: VariadicRefFactory,
+    T: VariantOf<Ts> + 'a,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let preview: Vec<_> = self.clone().collect();
+        write!(f, "MultiArrayViewVariantIter {{ data: {:?} }}", preview)
+    }
+}
+
 impl<'a, Ts> fmt::Debug for MultiArrayViewItemIter<'a, Ts>
 where
     Ts: VariadicRefFactory,
@@ -247,6 +348,174 @@ where
     }
 }
 
+/// Serializes a bucket as a sequence of its elements.
+///
+/// Each element is serialized as a variant tagged by its type name.
+#[cfg(feature = "serde")]
+impl<'a, Ts> serde::Serialize for MultiArrayViewItemIter<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+    <Ts as VariadicStruct<'a>>::Item: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.clone())
+    }
+}
+
+/// Serializes a multivector as a sequence of its buckets.
+#[cfg(feature = "serde")]
+impl<'a, Ts: 'a> serde::Serialize for MultiArrayView<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+    <Ts as VariadicStruct<'a>>::Item: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> MultiArrayView<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    /// Returns a parallel iterator through the indexed items of the array.
+    ///
+    /// The items are split between threads by their index, so each item is
+    /// still decoded sequentially.
+    pub fn par_iter(&self) -> MultiArrayViewParIter<'a, Ts> {
+        MultiArrayViewParIter { view: self.clone() }
+    }
+}
+
+/// Parallel iterator through items of an multivector.
+#[cfg(feature = "rayon")]
+#[derive(Clone)]
+pub struct MultiArrayViewParIter<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+{
+    view: MultiArrayView<'a, Ts>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> fmt::Debug for MultiArrayViewParIter<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_format("MultiArrayViewParIter", self.view.iter(), f)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> rayon::iter::ParallelIterator for MultiArrayViewParIter<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    type Item = MultiArrayViewItemIter<'a, Ts>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.view.len())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> rayon::iter::IndexedParallelIterator for MultiArrayViewParIter<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::Consumer<Self::Item>,
+    {
+        rayon::iter::plumbing::bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: rayon::iter::plumbing::ProducerCallback<Self::Item>,
+    {
+        callback.callback(MultiArrayViewProducer { view: self.view })
+    }
+}
+
+/// Splits a view at positions of its index.
+#[cfg(feature = "rayon")]
+struct MultiArrayViewProducer<'a, Ts>
+where
+    Ts: VariadicRefFactory,
+{
+    view: MultiArrayView<'a, Ts>,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> rayon::iter::plumbing::Producer for MultiArrayViewProducer<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    type Item = MultiArrayViewItemIter<'a, Ts>;
+    type IntoIter = MultiArrayViewIter<'a, Ts>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.view.iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        (
+            Self {
+                view: self.view.slice(..index),
+            },
+            Self {
+                view: self.view.slice(index..),
+            },
+        )
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> rayon::iter::IntoParallelIterator for MultiArrayView<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    type Item = MultiArrayViewItemIter<'a, Ts>;
+    type Iter = MultiArrayViewParIter<'a, Ts>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, Ts: 'a> rayon::iter::IntoParallelIterator for &MultiArrayView<'a, Ts>
+where
+    Ts: VariadicRefFactory + Sync,
+    Ts::Index: Sync,
+{
+    type Item = MultiArrayViewItemIter<'a, Ts>;
+    type Iter = MultiArrayViewParIter<'a, Ts>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
 #[cfg(test)]
 #[allow(dead_code)]
 mod tests {
@@ -254,7 +523,7 @@ mod tests {
     use crate::{
         memstorage::MemoryResourceStorage,
         storage::create_multi_vector,
-        test::{Ab, AbRef},
+        test::{Ab, AbBucket, AbRef, A, B},
     };
 
     fn create_view<'a>(storage: &'a MemoryResourceStorage, size: usize) -> MultiArrayView<'a, Ab> {
@@ -294,6 +563,45 @@ mod tests {
         assert_eq!(value(view.slice(2..8).iter().next().unwrap()), 2);
     }
 
+    #[test]
+    fn get() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let view = create_view(&storage, 10);
+
+        assert_eq!(view.get(3).map(|item| item.count()), Some(2));
+        assert!(view.get(10).is_none());
+        assert!(view.slice(2..4).get(2).is_none());
+    }
+
+    #[test]
+    fn iter_variant() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let view = create_view(&storage, 10);
+
+        let item = view.at(3);
+        let ids: Vec<_> = item.clone().iter_variant::<B>().map(|b| b.id()).collect();
+        assert_eq!(ids, [3]);
+        let xs: Vec<_> = item.as_().map(|a| (a.x(), a.y())).collect();
+        assert_eq!(xs, [(13, 23)]);
+        assert_eq!(item.bs().count(), 1);
+        assert_eq!(item.first_of::<A>().map(|a| a.x()), Some(13));
+        assert_eq!(item.first_of::<B>().map(|b| b.id()), Some(3));
+        assert_eq!(
+            format!("{:?}", item.bs()),
+            "MultiArrayViewVariantIter { data: [B { id: 3 }] }"
+        );
+
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let mut mv = create_multi_vector::<Ab>(&*storage, "multivector", "Some schema")
+            .expect("failed to create MultiVector");
+        mv.grow().expect("grow failed").add_a().set_x(1);
+        mv.grow().expect("grow failed");
+        let view = mv.close().expect("close failed");
+        assert!(view.at(0).first_of::<B>().is_none());
+        assert_eq!(view.at(0).as_().count(), 1);
+        assert!(view.at(1).first_of::<A>().is_none());
+    }
+
     #[test]
     fn reverse() {
         let storage = MemoryResourceStorage::new("/root/resources");
@@ -347,6 +655,42 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let view = create_view(&storage, 2);
+        assert_eq!(
+            serde_json::to_string(&view).expect("failed to serialize"),
+            r#"[[{"B":{"id":0}},{"A":{"x":2,"y":4,"e":"Value"}}],"#.to_string()
+                + r#"[{"B":{"id":1}},{"A":{"x":3,"y":5,"e":"Value"}}]]"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let view = create_view(&storage, 1000);
+
+        let value = |mut iter: MultiArrayViewItemIter<_>| match iter.next().unwrap() {
+            AbRef::B(v) => v.id(),
+            otherwise => panic!("unexpected value: {:?}", otherwise),
+        };
+
+        let values: Vec<_> = view.par_iter().map(value).collect();
+        assert_eq!(values, (0..1000).collect::<Vec<_>>());
+        assert_eq!(view.par_iter().len(), 1000);
+        assert_eq!(
+            view.slice(10..20).into_par_iter().map(value).sum::<u32>(),
+            145
+        );
+        let sizes: Vec<_> = view.par_iter().with_min_len(7).map(|x| x.count()).collect();
+        assert_eq!(sizes, vec![2; 1000]);
+    }
+
     fn test_fused_iterator(mut iter: impl Iterator, size: usize) {
         for _ in 0..size {
             iter.next().unwrap();
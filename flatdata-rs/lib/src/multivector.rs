@@ -1,4 +1,5 @@
 use crate::{
+    buffering::{Reservation, WriteBuffering},
     error::ResourceStorageError,
     multiarrayview::MultiArrayView,
     storage::ResourceHandle,
@@ -102,6 +103,8 @@ where
     data: Vec<u8>,
     data_handle: ResourceHandle<'a>,
     size_flushed: usize,
+    buffering: WriteBuffering,
+    reservation: Reservation,
     _phantom: marker::PhantomData<Ts>,
 }
 
@@ -110,16 +113,61 @@ where
     Ts: VariadicRefFactory,
 {
     /// Creates an empty multivector.
+    ///
+    /// The data of the multivector is buffered like its index.
     pub fn new(index: ExternalVector<'a, Ts::Index>, data_handle: ResourceHandle<'a>) -> Self {
+        let buffering = index.buffering().clone();
         Self {
             index,
             data: Vec::new(),
             data_handle,
             size_flushed: 0,
+            reservation: Reservation::new(buffering.budget().cloned()),
+            buffering,
             _phantom: marker::PhantomData,
         }
     }
 
+    /// Creates a multivector appending to a reopened resource, whose `index`
+    /// is reopened as well.
+    pub(crate) fn new_appending(
+        index: ExternalVector<'a, Ts::Index>,
+        data_handle: ResourceHandle<'a>,
+    ) -> Self {
+        let size_flushed = data_handle.size_in_bytes();
+        let mut vector = Self::new(index, data_handle);
+        vector.size_flushed = size_flushed;
+        vector
+    }
+
+    /// Sets how much data this multivector buffers before flushing it to
+    /// storage.
+    ///
+    /// The buffer size applies to the data and the index separately, whereas
+    /// a budget is shared by both.
+    pub fn set_buffering(&mut self, buffering: WriteBuffering) {
+        self.index.set_buffering(buffering.clone());
+        self.reservation = Reservation::new(buffering.budget().cloned());
+        self.buffering = buffering;
+    }
+
+    /// Returns the buffering configuration of this multivector.
+    pub fn buffering(&self) -> &WriteBuffering {
+        &self.buffering
+    }
+
+    /// Enables or disables the strict mode of this multivector.
+    ///
+    /// In strict mode, offsets into the data which do not fit into the width
+    /// of the index are detected when an item is added, and the index is
+    /// checked before it is written to storage (cf. [`check_ranges`]). The
+    /// failing call returns an error of kind `InvalidData`.
+    ///
+    /// [`check_ranges`]: fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.index.set_strict(strict);
+    }
+
     /// Appends a new item to the end of this multivector and returns a builder
     /// for it.
     ///
@@ -131,8 +179,11 @@ where
     ///
     /// [`flush`]: #method.flush
     pub fn grow(&mut self) -> io::Result<<Ts as VariadicStruct<'_>>::ItemMut> {
-        if self.data.len() > 1024 * 1024 * 32 {
-            self.flush()?;
+        // the size of the items is only known after they were added
+        if self.data.len() > self.buffering.buffer_size()
+            || !self.reservation.reserve(self.data.len())
+        {
+            self.flush_data()?;
         }
         self.add_to_index()?;
         Ok(<Ts as VariadicStruct>::create_mut(&mut self.data))
@@ -140,17 +191,38 @@ where
 
     /// Flushes the not yet flushed content in this multivector to storage.
     ///
-    /// Only data is flushed.
-    fn flush(&mut self) -> io::Result<()> {
+    /// Flushing happens automatically when growing the multivector,
+    /// depending on its buffering (cf. [`set_buffering`]). Calling it
+    /// explicitly allows to release buffered memory early.
+    ///
+    /// [`set_buffering`]: #method.set_buffering
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_data()?;
+        self.index.flush()
+    }
+
+    fn flush_data(&mut self) -> io::Result<()> {
         self.data_handle.borrow_mut().write(&self.data)?;
         self.size_flushed += self.data.len();
         self.data.clear();
+        if self.reservation.is_budgeted() {
+            // memory is only accounted for while it is in use
+            self.data.shrink_to_fit();
+        }
+        self.reservation.release();
         Ok(())
     }
 
     fn add_to_index(&mut self) -> io::Result<()> {
+        let offset = self.size_flushed + self.data.len();
+        let strict = self.index.is_strict();
         let idx_mut = self.index.grow()?;
-        Ts::Index::set_index(idx_mut, self.size_flushed + self.data.len());
+        if strict {
+            Ts::Index::try_set_index(idx_mut, offset)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        } else {
+            Ts::Index::set_index(idx_mut, offset);
+        }
         Ok(())
     }
 
@@ -162,7 +234,7 @@ where
         let name: String = self.data_handle.name().into();
         let into_storage_error = |e| ResourceStorageError::from_io_error(e, name.clone());
         self.add_to_index().map_err(into_storage_error)?; // sentinel for last item
-        self.flush().map_err(into_storage_error)?;
+        self.flush_data().map_err(into_storage_error)?;
         let index_view = self.index.close()?;
         let data = self.data_handle.close()?;
         Ok(MultiArrayView::new(index_view, data))
@@ -184,8 +256,9 @@ mod tests {
     use crate::{
         memstorage::MemoryResourceStorage,
         multiarrayview::MultiArrayView,
-        storage::{create_multi_vector, ResourceStorage},
+        storage::{append_multi_vector, create_multi_vector, ResourceStorage},
         test::{Ab, AbRef, _builtin::multivector::IndexType16},
+        MemoryBudget, WriteBuffering,
     };
 
     #[test]
@@ -281,4 +354,80 @@ mod tests {
             AbRef::B(_) => panic!("unexpected variant B"),
         }
     }
+
+    #[test]
+    fn test_multi_vector_buffering() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let budget = MemoryBudget::new(1024 * 1024);
+        let mut mv = create_multi_vector::<Ab>(&*storage, "multivector", "Some schema")
+            .expect("failed to create MultiVector");
+        mv.set_buffering(WriteBuffering::new(16).with_budget(budget.clone()));
+        for x in 0..10 {
+            let mut item = mv.grow().expect("grow failed");
+            item.add_a().set_x(x);
+            item.add_b().set_id(x);
+            if x == 4 {
+                mv.flush().expect("flush failed");
+                assert_eq!(budget.used(), 0);
+            }
+        }
+        // small buffers are not taken from the budget
+        assert_eq!(budget.used(), 0);
+        let view = mv.close().expect("close failed");
+
+        assert_eq!(view.len(), 10);
+        for (x, mut item) in view.iter().enumerate() {
+            match item.next().unwrap() {
+                AbRef::A(a) => assert_eq!(a.x(), x as u32),
+                AbRef::B(_) => panic!("unexpected variant B"),
+            }
+            match item.next().unwrap() {
+                AbRef::B(b) => assert_eq!(b.id(), x as u32),
+                AbRef::A(_) => panic!("unexpected variant A"),
+            }
+            assert!(item.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_multi_vector_strict() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let mut mv = create_multi_vector::<Ab>(&*storage, "multivector", "Some schema")
+            .expect("failed to create MultiVector");
+        mv.set_strict(true);
+        // offsets into the data do not fit into the 16 bits of the index after
+        // 65536 bytes
+        let error = (0..1 << 16)
+            .try_for_each(|id| -> std::io::Result<()> {
+                mv.grow()?.add_b().set_id(id);
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("does not fit into 16 bits"));
+    }
+
+    #[test]
+    fn test_multi_vector_append() {
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let mut mv = create_multi_vector::<Ab>(&*storage, "multivector", "Some schema")
+            .expect("failed to create MultiVector");
+        for x in 0..2 {
+            mv.grow().expect("grow failed").add_a().set_x(x);
+        }
+        mv.close().expect("close failed");
+
+        let mut mv = append_multi_vector::<Ab>(&*storage, "multivector", "Some schema")
+            .expect("failed to append to MultiVector");
+        mv.grow().expect("grow failed").add_b().set_id(2);
+        mv.grow().expect("grow failed");
+        let view = mv.close().expect("close failed");
+
+        assert_eq!(view.len(), 4);
+        let items: Vec<Vec<_>> = view.iter().map(|item| item.collect()).collect();
+        assert!(matches!(items[0][..], [AbRef::A(a)] if a.x() == 0));
+        assert!(matches!(items[1][..], [AbRef::A(a)] if a.x() == 1));
+        assert!(matches!(items[2][..], [AbRef::B(b)] if b.id() == 2));
+        assert!(items[3].is_empty());
+    }
 }
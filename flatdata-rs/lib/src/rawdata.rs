@@ -1,5 +1,10 @@
-use std::borrow::Cow;
-use std::str;
+use crate::{
+    buffering::{Reservation, WriteBuffering},
+    error::ResourceStorageError,
+    storage::ResourceHandle,
+};
+
+use std::{borrow::Cow, fmt, io, str};
 
 /// Exposes blocks of raw data, providing auxiliary functionality like
 /// extracting substrings.
@@ -67,6 +72,150 @@ impl<'a> RawData<'a> {
     }
 }
 
+/// Raw data writer which flushes its content when growing.
+///
+/// Useful for serialization of raw data which does not fully fit in memory,
+/// e.g. large blobs of strings referenced from other resources. Data is written
+/// via the [`io::Write`] implementation, [`offset`] returns the position at
+/// which the next byte will be stored.
+///
+/// A raw data writer *must* be closed, after the last byte was written to
+/// it. After closing, it can not be used anymore.
+///
+/// # Examples
+/// ``` flatdata
+/// archive W {
+///    blob : raw_data;
+/// }
+/// ```
+///
+/// ```
+/// use flatdata::MemoryResourceStorage;
+/// use flatdata::test::{W, WBuilder};
+/// use std::io::Write;
+///
+/// let storage = MemoryResourceStorage::new("/root/rawdata");
+/// let builder = WBuilder::new(storage.clone()).expect("failed to create builder");
+/// {
+///     let mut blob = builder.start_blob().expect("failed to start");
+///     blob.write_all(b"hello\0").expect("write failed");
+///     let offset = blob.offset();
+///     blob.write_all(b"world\0").expect("write failed");
+///
+///     let data = blob.close().expect("close failed");
+///
+///     // data can also be inspected directly after closing
+///     assert_eq!(data.substring(offset), Ok("world"));
+/// }
+///
+/// let archive = W::open(storage).expect("failed to open");
+/// assert_eq!(archive.blob().substring(0), Ok("hello"));
+/// ```
+///
+/// [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+/// [`offset`]: #method.offset
+pub struct RawDataWriter<'a> {
+    data: Vec<u8>,
+    size_flushed: usize,
+    resource_handle: ResourceHandle<'a>,
+    buffering: WriteBuffering,
+    reservation: Reservation,
+}
+
+impl<'a> RawDataWriter<'a> {
+    /// Creates an empty `RawDataWriter` in the given resource storage.
+    ///
+    /// The writer uses the default buffering at the time it is created (cf.
+    /// [`WriteBuffering`]).
+    ///
+    /// [`WriteBuffering`]: struct.WriteBuffering.html
+    pub fn new(resource_handle: ResourceHandle<'a>) -> Self {
+        let buffering = WriteBuffering::default();
+        Self {
+            data: Vec::new(),
+            size_flushed: 0,
+            resource_handle,
+            reservation: Reservation::new(buffering.budget().cloned()),
+            buffering,
+        }
+    }
+
+    /// Sets how much data this writer buffers before flushing it to storage.
+    ///
+    /// Memory reserved from a previously set budget is returned to it.
+    pub fn set_buffering(&mut self, buffering: WriteBuffering) {
+        self.reservation = Reservation::new(buffering.budget().cloned());
+        self.buffering = buffering;
+    }
+
+    /// Returns the buffering configuration of this writer.
+    pub fn buffering(&self) -> &WriteBuffering {
+        &self.buffering
+    }
+
+    /// Number of bytes written so far.
+    ///
+    /// This is the offset at which the next written byte will be stored.
+    pub fn offset(&self) -> usize {
+        self.size_flushed + self.data.len()
+    }
+
+    /// Flushes the not yet flushed content in this writer to storage.
+    fn flush_data(&mut self) -> io::Result<()> {
+        self.resource_handle.write(&self.data)?;
+        self.size_flushed += self.data.len();
+        self.data.clear();
+        if self.reservation.is_budgeted() {
+            // memory is only accounted for while it is in use
+            self.data.shrink_to_fit();
+        }
+        self.reservation.release();
+        Ok(())
+    }
+
+    /// Flushes the remaining not yet flushed data in this writer and
+    /// finalizes the data inside the storage.
+    ///
+    /// A raw data writer *must* be closed
+    pub fn close(mut self) -> Result<RawData<'a>, ResourceStorageError> {
+        self.flush_data().map_err(|e| {
+            ResourceStorageError::from_io_error(e, self.resource_handle.name().into())
+        })?;
+        self.resource_handle.close().map(RawData::new)
+    }
+}
+
+impl io::Write for RawDataWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let size = self.data.len() + buf.len();
+        if size > self.buffering.buffer_size() || !self.reservation.reserve(size) {
+            self.flush_data()?;
+            // if the budget is exhausted by other writers, buffer the data anyway
+            self.reservation.reserve(buf.len());
+        }
+        self.data.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_data()
+    }
+}
+
+impl fmt::Debug for RawDataWriter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RawDataWriter {{ offset: {} }}", self.offset())
+    }
+}
+
+/// Serializes raw data as a sequence of bytes.
+#[cfg(feature = "serde")]
+impl serde::Serialize for RawData<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,4 +258,57 @@ mod tests {
         assert_eq!(raw_data.substring_lossy(1), "b�");
         assert_eq!(raw_data.substring_raw(1), b"b\xF0\x90\x80");
     }
+
+    #[test]
+    fn writer() {
+        use crate::{memstorage::MemoryResourceStorage, storage::create_raw_data_writer};
+        use std::io::Write;
+
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let mut writer = create_raw_data_writer(&*storage, "rawdata", "Some schema")
+            .expect("failed to create RawDataWriter");
+        let mut offsets = Vec::new();
+        for word in &["abc", "", "de"] {
+            offsets.push(writer.offset());
+            writer.write_all(word.as_bytes()).unwrap();
+            writer.write_all(b"\0").unwrap();
+        }
+        writer.flush().unwrap();
+        assert_eq!(writer.offset(), 8);
+        let raw_data = writer.close().expect("close failed");
+        assert_eq!(raw_data.as_bytes(), b"abc\0\0de\0");
+        assert_eq!(offsets, [0, 4, 5]);
+        assert_eq!(raw_data.substring(offsets[0]), Ok("abc"));
+        assert_eq!(raw_data.substring(offsets[1]), Ok(""));
+        assert_eq!(raw_data.substring(offsets[2]), Ok("de"));
+    }
+
+    #[test]
+    fn writer_buffering() {
+        use crate::{
+            buffering::MINIMUM_BUFFER_SIZE, memstorage::MemoryResourceStorage,
+            storage::create_raw_data_writer, MemoryBudget,
+        };
+        use std::io::Write;
+
+        let storage = MemoryResourceStorage::new("/root/resources");
+        let budget = MemoryBudget::new(1024 * 1024);
+        let mut writer = create_raw_data_writer(&*storage, "rawdata", "Some schema")
+            .expect("failed to create RawDataWriter");
+        writer.set_buffering(WriteBuffering::new(4).with_budget(budget.clone()));
+        for word in &["abc", "", "defgh"] {
+            writer.write_all(word.as_bytes()).unwrap();
+            writer.write_all(b"\0").unwrap();
+        }
+        // small buffers are not taken from the budget
+        assert_eq!(budget.used(), 0);
+        assert_eq!(writer.offset(), 11);
+
+        writer.set_buffering(WriteBuffering::default().with_budget(budget.clone()));
+        writer.write_all(&[0; 2 * MINIMUM_BUFFER_SIZE]).unwrap();
+        assert!(budget.used() > 0);
+        let raw_data = writer.close().expect("close failed");
+        assert_eq!(budget.used(), 0);
+        assert_eq!(&raw_data.as_bytes()[..11], b"abc\0\0defgh\0");
+    }
 }
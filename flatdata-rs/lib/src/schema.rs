@@ -0,0 +1,878 @@
+//! Runtime representation of flatdata schemas.
+//!
+//! Parses the flatdata schema language, e.g. the schemas stored next to each
+//! resource of an archive, and computes the layout of structs in the same way
+//! as the generator does. This allows to read archives without generated code,
+//! cf. [`DynArchive`].
+//!
+//! All names of schema elements are fully qualified, e.g. `.n.S` for the
+//! struct `S` in the namespace `n`.
+//!
+//! [`DynArchive`]: ../struct.DynArchive.html
+
+use crate::error::SchemaError;
+
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+/// Primitive type of a field, an enum or a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BasicType {
+    /// `bool`
+    Bool,
+    /// `u8`
+    U8,
+    /// `i8`
+    I8,
+    /// `u16`
+    U16,
+    /// `i16`
+    I16,
+    /// `u32`
+    U32,
+    /// `i32`
+    I32,
+    /// `u64`
+    U64,
+    /// `i64`
+    I64,
+}
+
+impl BasicType {
+    /// Returns the basic type with the given name, e.g. `"u32"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "bool" => BasicType::Bool,
+            "u8" => BasicType::U8,
+            "i8" => BasicType::I8,
+            "u16" => BasicType::U16,
+            "i16" => BasicType::I16,
+            "u32" => BasicType::U32,
+            "i32" => BasicType::I32,
+            "u64" => BasicType::U64,
+            "i64" => BasicType::I64,
+            _ => return None,
+        })
+    }
+
+    /// Name of the type as used in the schema.
+    pub fn name(self) -> &'static str {
+        match self {
+            BasicType::Bool => "bool",
+            BasicType::U8 => "u8",
+            BasicType::I8 => "i8",
+            BasicType::U16 => "u16",
+            BasicType::I16 => "i16",
+            BasicType::U32 => "u32",
+            BasicType::I32 => "i32",
+            BasicType::U64 => "u64",
+            BasicType::I64 => "i64",
+        }
+    }
+
+    /// Maximal width of the type in bits.
+    pub fn width(self) -> usize {
+        match self {
+            BasicType::Bool => 1,
+            BasicType::U8 | BasicType::I8 => 8,
+            BasicType::U16 | BasicType::I16 => 16,
+            BasicType::U32 | BasicType::I32 => 32,
+            BasicType::U64 | BasicType::I64 => 64,
+        }
+    }
+
+    /// Whether the type is signed.
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            BasicType::I8 | BasicType::I16 | BasicType::I32 | BasicType::I64
+        )
+    }
+}
+
+/// A constant, e.g. `const u32 INVALID = 0xFFFFFFFF;`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constant {
+    /// Fully qualified name.
+    pub name: String,
+    /// Type of the constant.
+    pub basic_type: BasicType,
+    /// Value of the constant.
+    pub value: i128,
+}
+
+/// An enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enum {
+    /// Fully qualified name.
+    pub name: String,
+    /// Underlying type of the enum.
+    pub basic_type: BasicType,
+    /// Width of the enum in bits.
+    pub width: usize,
+    /// Names and values of the variants in the order of their definition.
+    pub variants: Vec<(String, i128)>,
+}
+
+impl Enum {
+    /// Returns the name of the variant with the given value, if any.
+    pub fn variant_name(&self, value: i128) -> Option<&str> {
+        self.variants
+            .iter()
+            .find(|(_, x)| *x == value)
+            .map(|(name, _)| &name[..])
+    }
+}
+
+/// A field of a struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Primitive type the field is stored as. For enum fields, this is the
+    /// underlying type of the enum.
+    pub basic_type: BasicType,
+    /// Fully qualified name of the enum type of the field, if any.
+    pub enum_type: Option<String>,
+    /// Offset of the field in bits from the start of the struct.
+    pub offset: usize,
+    /// Width of the field in bits.
+    pub width: usize,
+    /// Value marking the field as missing (`@optional`).
+    pub invalid_value: Option<i128>,
+    /// Name of the range defined by the field (`@range`).
+    pub range: Option<String>,
+}
+
+/// A struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Struct {
+    /// Fully qualified name.
+    pub name: String,
+    /// Fields in the order of their definition.
+    pub fields: Vec<Field>,
+    /// Size of the struct in bytes.
+    pub size_in_bytes: usize,
+}
+
+impl Struct {
+    /// Returns the field with the given name, if any.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Whether the struct has a field with a range, i.e. whether it uses the
+    /// data of the next struct.
+    pub fn has_range(&self) -> bool {
+        self.fields.iter().any(|field| field.range.is_some())
+    }
+}
+
+/// Type of a resource of an archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceType {
+    /// A single struct with the given fully qualified name.
+    Instance(String),
+    /// A vector of structs with the given fully qualified name.
+    Vector(String),
+    /// A multivector.
+    MultiVector {
+        /// Width of the index in bits.
+        index_width: usize,
+        /// Fully qualified names of the structs in the order of their type
+        /// index.
+        types: Vec<String>,
+    },
+    /// Raw bytes.
+    RawData,
+    /// A subarchive with the given fully qualified name.
+    Archive(String),
+}
+
+impl fmt::Display for ResourceType {
+    /// Formats the type as in the schema language, e.g. `vector<.n.S>`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResourceType::Instance(name) => write!(f, "{}", name),
+            ResourceType::Vector(name) => write!(f, "vector<{}>", name),
+            ResourceType::MultiVector { index_width, types } => {
+                write!(f, "multivector<{}, {}>", index_width, types.join(", "))
+            }
+            ResourceType::RawData => write!(f, "raw_data"),
+            ResourceType::Archive(name) => write!(f, "archive {}", name),
+        }
+    }
+}
+
+/// A field referencing elements of another resource by their index
+/// (`@explicit_reference`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplicitReference {
+    /// Fully qualified name of the struct containing the field.
+    pub struct_name: String,
+    /// Name of the referencing field.
+    pub field: String,
+    /// Fully qualified name of the archive containing the referenced resource.
+    pub archive_name: String,
+    /// Name of the referenced resource.
+    pub destination: String,
+}
+
+/// A resource of an archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resource {
+    /// Name of the resource.
+    pub name: String,
+    /// Type of the resource.
+    pub resource_type: ResourceType,
+    /// Whether the resource is optional (`@optional`).
+    pub is_optional: bool,
+    /// Fields of the elements of the resource referencing other resources.
+    pub explicit_references: Vec<ExplicitReference>,
+}
+
+impl Resource {
+    /// Returns the reference of the given field of the struct with the given
+    /// fully qualified name, if any.
+    pub fn explicit_reference(&self, struct_name: &str, field: &str) -> Option<&ExplicitReference> {
+        self.explicit_references
+            .iter()
+            .find(|x| x.struct_name == struct_name && x.field == field)
+    }
+}
+
+/// An archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Archive {
+    /// Fully qualified name.
+    pub name: String,
+    /// Resources in the order of their definition.
+    pub resources: Vec<Resource>,
+}
+
+impl Archive {
+    /// Returns the resource with the given name, if any.
+    pub fn resource(&self, name: &str) -> Option<&Resource> {
+        self.resources.iter().find(|resource| resource.name == name)
+    }
+}
+
+/// A parsed flatdata schema.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schema {
+    /// All constants.
+    pub constants: Vec<Constant>,
+    /// All enums.
+    pub enums: Vec<Enum>,
+    /// All structs with computed layout.
+    pub structs: Vec<Struct>,
+    /// All archives.
+    pub archives: Vec<Archive>,
+}
+
+impl Schema {
+    /// Parses a schema.
+    ///
+    /// Definitions occurring several times, as it is the case for schemas of
+    /// resources of nested archives, are only taken into account once.
+    pub fn parse(schema: &str) -> Result<Self, SchemaError> {
+        Parser::new(schema)?.parse()
+    }
+
+    /// Returns the constant with the given fully qualified name, if any.
+    pub fn find_constant(&self, name: &str) -> Option<&Constant> {
+        self.constants.iter().find(|x| x.name == name)
+    }
+
+    /// Returns the enum with the given fully qualified name, if any.
+    pub fn find_enum(&self, name: &str) -> Option<&Enum> {
+        self.enums.iter().find(|x| x.name == name)
+    }
+
+    /// Returns the struct with the given fully qualified name, if any.
+    pub fn find_struct(&self, name: &str) -> Option<&Struct> {
+        self.structs.iter().find(|x| x.name == name)
+    }
+
+    /// Returns the archive with the given fully qualified name, if any.
+    pub fn find_archive(&self, name: &str) -> Option<&Archive> {
+        self.archives.iter().find(|x| x.name == name)
+    }
+}
+
+//
+// Parser
+//
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    Number(i128),
+    Symbol(char),
+}
+
+fn tokenize(schema: &str) -> Result<Vec<(Token, usize)>, SchemaError> {
+    // the first character at `start` is already consumed and is ASCII
+    fn take_while(
+        schema: &str,
+        chars: &mut Peekable<CharIndices>,
+        start: usize,
+        pred: impl Fn(char) -> bool,
+    ) -> String {
+        let mut end = start + 1;
+        while let Some(&(pos, c)) = chars.peek() {
+            if !pred(c) {
+                break;
+            }
+            end = pos + c.len_utf8();
+            chars.next();
+        }
+        schema[start..end].into()
+    }
+
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut chars = schema.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => (),
+            '/' if chars.peek().map(|x| x.1) == Some('/') => {
+                while chars.peek().is_some_and(|x| x.1 != '\n') {
+                    chars.next();
+                }
+            }
+            '/' if chars.peek().map(|x| x.1) == Some('*') => {
+                chars.next();
+                let mut last = ' ';
+                loop {
+                    match chars.next() {
+                        Some((_, '/')) if last == '*' => break,
+                        Some((_, c)) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            last = c;
+                        }
+                        None => return Err(SchemaError::new(line, "unterminated comment")),
+                    }
+                }
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                let literal = take_while(schema, &mut chars, start, |c| c.is_ascii_alphanumeric());
+                let (negative, digits) = match literal.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, &literal[..]),
+                };
+                let value = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+                    Some(hex) => i128::from_str_radix(hex, 16),
+                    None => digits.parse(),
+                }
+                .map_err(|_| SchemaError::new(line, format!("invalid number '{}'", literal)))?;
+                tokens.push((Token::Number(if negative { -value } else { value }), line));
+            }
+            c if c.is_ascii_alphabetic() || c == '_' || c == '.' => {
+                let name = take_while(schema, &mut chars, start, |c| {
+                    c.is_ascii_alphanumeric() || c == '_' || c == '.'
+                });
+                tokens.push((Token::Name(name), line));
+            }
+            '{' | '}' | '(' | ')' | '<' | '>' | ':' | ';' | ',' | '=' | '@' => {
+                tokens.push((Token::Symbol(c), line))
+            }
+            c => {
+                return Err(SchemaError::new(
+                    line,
+                    format!("unexpected character '{}'", c),
+                ))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct RawField {
+    name: String,
+    type_name: String,
+    width: Option<usize>,
+    optional: Option<String>,
+    range: Option<String>,
+    line: usize,
+}
+
+struct RawStruct {
+    name: String,
+    namespace: String,
+    fields: Vec<RawField>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    schema: Schema,
+    structs: Vec<RawStruct>,
+}
+
+impl Parser {
+    fn new(schema: &str) -> Result<Self, SchemaError> {
+        Ok(Self {
+            tokens: tokenize(schema)?,
+            pos: 0,
+            schema: Schema::default(),
+            structs: Vec::new(),
+        })
+    }
+
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map_or(1, |x| x.1)
+    }
+
+    fn error(&self, message: impl Into<String>) -> SchemaError {
+        SchemaError::new(self.line(), message)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|x| &x.0)
+    }
+
+    fn next(&mut self) -> Result<Token, SchemaError> {
+        let token = self.tokens.get(self.pos).map(|x| x.0.clone());
+        self.pos += 1;
+        token.ok_or_else(|| self.error("unexpected end of schema"))
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), SchemaError> {
+        match self.next()? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            token => Err(self.unexpected(token, &format!("'{}'", symbol))),
+        }
+    }
+
+    fn expect_name(&mut self) -> Result<String, SchemaError> {
+        match self.next()? {
+            Token::Name(name) => Ok(name),
+            token => Err(self.unexpected(token, "name")),
+        }
+    }
+
+    fn expect_number(&mut self) -> Result<i128, SchemaError> {
+        match self.next()? {
+            Token::Number(value) => Ok(value),
+            token => Err(self.unexpected(token, "number")),
+        }
+    }
+
+    fn expect_width(&mut self) -> Result<usize, SchemaError> {
+        let width = self.expect_number()?;
+        if !(1..=64).contains(&width) {
+            return Err(self.error(format!("invalid width {}", width)));
+        }
+        Ok(width as usize)
+    }
+
+    fn unexpected(&mut self, token: Token, expected: &str) -> SchemaError {
+        self.pos -= 1;
+        self.error(format!("expected {}, found {:?}", expected, token))
+    }
+
+    /// Parses annotations like `@optional` or `@range( x )`, and returns their
+    /// names and the names in their arguments.
+    fn parse_annotations(&mut self) -> Result<Vec<(String, Vec<String>)>, SchemaError> {
+        let mut result = Vec::new();
+        while self.is_symbol('@') {
+            self.next()?;
+            let name = self.expect_name()?;
+            let mut args = Vec::new();
+            if self.is_symbol('(') {
+                self.next()?;
+                let mut depth = 1;
+                while depth > 0 {
+                    match self.next()? {
+                        Token::Symbol('(') => depth += 1,
+                        Token::Symbol(')') => depth -= 1,
+                        Token::Name(arg) => args.push(arg),
+                        _ => (),
+                    }
+                }
+            }
+            result.push((name, args));
+        }
+        Ok(result)
+    }
+
+    fn parse(mut self) -> Result<Schema, SchemaError> {
+        while self.peek().is_some() {
+            match &self.expect_name()?[..] {
+                "namespace" => (),
+                name => return Err(self.error(format!("expected namespace, found {}", name))),
+            }
+            let namespace = format!(".{}", self.expect_name()?);
+            self.expect_symbol('{')?;
+            while !self.is_symbol('}') {
+                self.parse_definition(&namespace)?;
+            }
+            self.next()?;
+        }
+        self.resolve_structs()?;
+        Ok(self.schema)
+    }
+
+    fn parse_definition(&mut self, namespace: &str) -> Result<(), SchemaError> {
+        self.parse_annotations()?;
+        let keyword = self.expect_name()?;
+        match &keyword[..] {
+            "const" => {
+                let type_name = self.expect_name()?;
+                let basic_type = BasicType::from_name(&type_name)
+                    .ok_or_else(|| self.error(format!("unknown type {}", type_name)))?;
+                let name = format!("{}.{}", namespace, self.expect_name()?);
+                self.expect_symbol('=')?;
+                let value = self.expect_number()?;
+                self.expect_symbol(';')?;
+                if self.schema.find_constant(&name).is_none() {
+                    self.schema.constants.push(Constant {
+                        name,
+                        basic_type,
+                        value,
+                    });
+                }
+            }
+            "enum" => {
+                let name = format!("{}.{}", namespace, self.expect_name()?);
+                self.expect_symbol(':')?;
+                let type_name = self.expect_name()?;
+                let basic_type = BasicType::from_name(&type_name)
+                    .ok_or_else(|| self.error(format!("unknown type {}", type_name)))?;
+                let width = if self.is_symbol(':') {
+                    self.next()?;
+                    self.expect_width()?
+                } else {
+                    basic_type.width()
+                };
+                self.expect_symbol('{')?;
+                let mut variants = Vec::new();
+                let mut next_value = 0;
+                while !self.is_symbol('}') {
+                    let variant = self.expect_name()?;
+                    if self.is_symbol('=') {
+                        self.next()?;
+                        next_value = self.expect_number()?;
+                    }
+                    variants.push((variant, next_value));
+                    next_value += 1;
+                    if !self.is_symbol('}') {
+                        self.expect_symbol(',')?;
+                    }
+                }
+                self.next()?;
+                if self.schema.find_enum(&name).is_none() {
+                    self.schema.enums.push(Enum {
+                        name,
+                        basic_type,
+                        width,
+                        variants,
+                    });
+                }
+            }
+            "struct" => {
+                let name = format!("{}.{}", namespace, self.expect_name()?);
+                self.expect_symbol('{')?;
+                let mut fields = Vec::new();
+                while !self.is_symbol('}') {
+                    let mut field = RawField {
+                        name: String::new(),
+                        type_name: String::new(),
+                        width: None,
+                        optional: None,
+                        range: None,
+                        line: 0,
+                    };
+                    for (annotation, args) in self.parse_annotations()? {
+                        match &annotation[..] {
+                            "optional" => field.optional = args.into_iter().next(),
+                            "range" => field.range = args.into_iter().next(),
+                            _ => (),
+                        }
+                    }
+                    field.line = self.line();
+                    field.name = self.expect_name()?;
+                    self.expect_symbol(':')?;
+                    field.type_name = self.expect_name()?;
+                    if self.is_symbol(':') {
+                        self.next()?;
+                        field.width = Some(self.expect_width()?);
+                    }
+                    self.expect_symbol(';')?;
+                    fields.push(field);
+                }
+                self.next()?;
+                if !self.structs.iter().any(|x| x.name == name) {
+                    self.structs.push(RawStruct {
+                        name,
+                        namespace: namespace.into(),
+                        fields,
+                    });
+                }
+            }
+            "archive" => {
+                let name = format!("{}.{}", namespace, self.expect_name()?);
+                self.expect_symbol('{')?;
+                let mut resources = Vec::new();
+                while !self.is_symbol('}') {
+                    let mut is_optional = false;
+                    let mut explicit_references = Vec::new();
+                    for (annotation, args) in self.parse_annotations()? {
+                        match (&annotation[..], &args[..]) {
+                            ("optional", _) => is_optional = true,
+                            ("explicit_reference", [field, destination]) => {
+                                let split = |name: &str| {
+                                    name.rfind('.')
+                                        .map(|pos| (name[..pos].into(), name[pos + 1..].into()))
+                                };
+                                let invalid = || self.error("invalid explicit reference");
+                                let (struct_name, field) = split(field).ok_or_else(invalid)?;
+                                let (archive_name, destination) =
+                                    split(destination).ok_or_else(invalid)?;
+                                explicit_references.push(ExplicitReference {
+                                    struct_name,
+                                    field,
+                                    archive_name,
+                                    destination,
+                                });
+                            }
+                            _ => (),
+                        }
+                    }
+                    let resource_name = self.expect_name()?;
+                    self.expect_symbol(':')?;
+                    let resource_type = self.parse_resource_type(namespace)?;
+                    self.expect_symbol(';')?;
+                    resources.push(Resource {
+                        name: resource_name,
+                        resource_type,
+                        is_optional,
+                        explicit_references,
+                    });
+                }
+                self.next()?;
+                if self.schema.find_archive(&name).is_none() {
+                    self.schema.archives.push(Archive { name, resources });
+                }
+            }
+            _ => return Err(self.error(format!("unexpected {}", keyword))),
+        }
+        Ok(())
+    }
+
+    fn parse_resource_type(&mut self, namespace: &str) -> Result<ResourceType, SchemaError> {
+        let qualify = |name: String| {
+            if name.starts_with('.') {
+                name
+            } else {
+                format!("{}.{}", namespace, name)
+            }
+        };
+        let type_name = self.expect_name()?;
+        Ok(match &type_name[..] {
+            "vector" => {
+                self.expect_symbol('<')?;
+                let element_type = qualify(self.expect_name()?);
+                self.expect_symbol('>')?;
+                ResourceType::Vector(element_type)
+            }
+            "multivector" => {
+                self.expect_symbol('<')?;
+                let index_width = self.expect_width()?;
+                let mut types = Vec::new();
+                while self.is_symbol(',') {
+                    self.next()?;
+                    types.push(qualify(self.expect_name()?));
+                }
+                self.expect_symbol('>')?;
+                ResourceType::MultiVector { index_width, types }
+            }
+            "raw_data" => ResourceType::RawData,
+            "archive" => ResourceType::Archive(qualify(self.expect_name()?)),
+            _ => ResourceType::Instance(qualify(type_name)),
+        })
+    }
+
+    /// Resolves the types of the fields and computes the layout of all structs.
+    fn resolve_structs(&mut self) -> Result<(), SchemaError> {
+        let resolve = |namespace: &str, name: &str| -> Vec<String> {
+            if name.starts_with('.') {
+                vec![name.into()]
+            } else {
+                vec![format!("{}.{}", namespace, name), format!(".{}", name)]
+            }
+        };
+        for raw in &self.structs {
+            let mut offset = 0;
+            let mut fields = Vec::with_capacity(raw.fields.len());
+            for field in &raw.fields {
+                let error = |message: String| SchemaError::new(field.line, message);
+                let (basic_type, enum_type, default_width) =
+                    match BasicType::from_name(&field.type_name) {
+                        Some(basic_type) => (basic_type, None, basic_type.width()),
+                        None => {
+                            let enum_type = resolve(&raw.namespace, &field.type_name)
+                                .iter()
+                                .find_map(|name| self.schema.find_enum(name))
+                                .ok_or_else(|| {
+                                    error(format!("unknown type {}", field.type_name))
+                                })?;
+                            (
+                                enum_type.basic_type,
+                                Some(enum_type.name.clone()),
+                                enum_type.width,
+                            )
+                        }
+                    };
+                let width = field.width.unwrap_or(default_width);
+                if width > basic_type.width() {
+                    return Err(error(format!(
+                        "width {} of field {} exceeds type {}",
+                        width,
+                        field.name,
+                        basic_type.name()
+                    )));
+                }
+                let invalid_value = match &field.optional {
+                    Some(constant) => Some(
+                        resolve(&raw.namespace, constant)
+                            .iter()
+                            .find_map(|name| self.schema.find_constant(name))
+                            .ok_or_else(|| error(format!("unknown constant {}", constant)))?
+                            .value,
+                    ),
+                    None => None,
+                };
+                fields.push(Field {
+                    name: field.name.clone(),
+                    basic_type,
+                    enum_type,
+                    offset,
+                    width,
+                    invalid_value,
+                    range: field.range.clone(),
+                });
+                offset += width;
+            }
+            self.schema.structs.push(Struct {
+                name: raw.name.clone(),
+                fields,
+                size_in_bytes: offset.div_ceil(8),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schema = Schema::parse(crate::test::schema::z::Z).expect("failed to parse schema");
+        let a = schema.find_struct(".test.A").expect("missing struct");
+        assert_eq!(a.size_in_bytes, 5);
+        let offsets: Vec<_> = a
+            .fields
+            .iter()
+            .map(|x| (&x.name[..], x.offset, x.width))
+            .collect();
+        assert_eq!(offsets, [("x", 0, 16), ("y", 16, 16), ("e", 32, 1)]);
+        let e = a.field("e").expect("missing field");
+        assert_eq!(e.enum_type.as_deref(), Some(".test.E"));
+        assert_eq!(e.basic_type, BasicType::U32);
+        let enum_type = schema.find_enum(".test.E").expect("missing enum");
+        assert_eq!(enum_type.variant_name(0), Some("Value"));
+
+        let z = schema.find_archive(".test.Z").expect("missing archive");
+        assert_eq!(
+            z.resource("ab").map(|x| &x.resource_type),
+            Some(&ResourceType::MultiVector {
+                index_width: 16,
+                types: vec![".test.A".into(), ".test.B".into()]
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_annotations() {
+        let schema = Schema::parse(
+            r#"
+            // comment
+            namespace n {
+            const i8 INVALID = -0x10;
+            enum E : i8 { A, B = 5, C }
+            /* multi
+               line */
+            struct S {
+                @optional(INVALID)
+                x : i8 : 7;
+                @range(y_range)
+                first_y : u32 : 20;
+                e : E : 4;
+            }
+            @bound_implicitly( b : .n.A.data, .n.A.other )
+            archive A {
+                @explicit_reference( .n.S.first_y, .n.A.data )
+                @optional
+                data : vector< S >;
+                other : archive .m.B;
+            }
+            }
+            "#,
+        )
+        .expect("failed to parse schema");
+
+        let e = schema.find_enum(".n.E").expect("missing enum");
+        assert_eq!(e.width, 8);
+        assert_eq!(e.variants[2], ("C".into(), 6));
+
+        let s = schema.find_struct(".n.S").expect("missing struct");
+        assert_eq!(s.size_in_bytes, 4);
+        assert_eq!(s.fields[0].invalid_value, Some(-16));
+        assert_eq!(s.fields[1].range.as_deref(), Some("y_range"));
+        assert!(s.has_range());
+        assert_eq!((s.fields[2].offset, s.fields[2].width), (27, 4));
+
+        let a = schema.find_archive(".n.A").expect("missing archive");
+        assert!(a.resources[0].is_optional);
+        assert_eq!(
+            a.resources[0].explicit_reference(".n.S", "first_y"),
+            Some(&ExplicitReference {
+                struct_name: ".n.S".into(),
+                field: "first_y".into(),
+                archive_name: ".n.A".into(),
+                destination: "data".into(),
+            })
+        );
+        assert!(a.resources[1].explicit_references.is_empty());
+        assert_eq!(
+            a.resources[0].resource_type,
+            ResourceType::Vector(".n.S".into())
+        );
+        assert_eq!(
+            a.resources[1].resource_type,
+            ResourceType::Archive(".m.B".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Schema::parse("namespace n {\nstruct S {\n x : u8 : 9;\n}\n}").unwrap_err();
+        assert_eq!(error.line, Some(3));
+        let error = Schema::parse("namespace n {\nstruct S {\n x : F;\n}\n}").unwrap_err();
+        assert_eq!(error.to_string(), "line 3: unknown type F");
+        assert!(Schema::parse("namespace n {").is_err());
+    }
+}
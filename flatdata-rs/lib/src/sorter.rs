@@ -0,0 +1,488 @@
+use crate::{
+    buffering::{Reservation, WriteBuffering},
+    error::ResourceStorageError,
+    storage::{create_external_vector, ResourceStorage},
+    structs::{NoOverlap, Struct},
+    vector::{copy_struct, ExternalVector},
+};
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt, process,
+    sync::atomic::{self, AtomicUsize},
+};
+
+const RUN_SCHEMA: &str = "external sorter run";
+
+/// Distinguishes the runs of sorters sharing a temporary storage.
+static NEXT_SORTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Compare<'a, T> = dyn Fn(&T, &T) -> Ordering + 'a;
+
+/// Sorts structs which do not fit in memory.
+///
+/// Elements are collected in a buffer, which is sorted and spilled as a run to
+/// a temporary resource storage when it is full (cf. [`set_buffering`]). When
+/// finishing, all runs are merged into an [`ExternalVector`]. Sorting is
+/// stable, i.e. elements comparing equal keep the order in which they were
+/// added.
+///
+/// The names of the runs are unique per sorter and process, so several sorters
+/// can share a temporary storage. The runs are removed from the storage and
+/// their memory and disk space is freed when the sorter is finished or
+/// dropped, if the storage supports removing resources (cf.
+/// [`ResourceStorage::remove`]). Otherwise, the space is only reclaimed when
+/// the temporary storage is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{ExternalSorter, MemoryResourceStorage, WriteBuffering};
+/// use flatdata::test::{A, XBuilder};
+///
+/// let storage = MemoryResourceStorage::new("/root/sorter");
+/// let tmp = MemoryResourceStorage::new("/tmp/sorter");
+/// let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+///
+/// let mut sorter = ExternalSorter::by_key(&*tmp, |a: &A| a.x());
+/// sorter.set_buffering(WriteBuffering::new(64 * 1024 * 1024));
+/// sorter.set_dedup(true);
+/// let mut a = A::new();
+/// for &x in &[3, 1, 2, 1] {
+///     a.set_x(x);
+///     sorter.push(&a).expect("push failed");
+/// }
+///
+/// let view = sorter
+///     .finish(builder.start_data().expect("failed to start"))
+///     .expect("sort failed");
+/// assert_eq!(view.iter().map(|a| a.x()).collect::<Vec<_>>(), [1, 2, 3]);
+/// ```
+///
+/// [`set_buffering`]: #method.set_buffering
+/// [`ExternalVector`]: struct.ExternalVector.html
+/// [`ResourceStorage::remove`]: trait.ResourceStorage.html#method.remove
+pub struct ExternalSorter<'a, T>
+where
+    T: Struct + NoOverlap,
+{
+    storage: &'a (dyn ResourceStorage + Sync + Send),
+    compare: Box<Compare<'a, T>>,
+    buffer: Vec<T>,
+    buffering: WriteBuffering,
+    reservation: Reservation,
+    runs: Vec<&'a [T]>,
+    run_prefix: String,
+    run_names: Vec<String>,
+    dedup: bool,
+    len: usize,
+}
+
+impl<'a, T> ExternalSorter<'a, T>
+where
+    T: Struct + NoOverlap,
+{
+    /// Creates a sorter ordering elements by `compare`, which spills runs to
+    /// `storage`.
+    ///
+    /// The sorter uses the default buffering at the time it is created (cf.
+    /// [`WriteBuffering`]).
+    ///
+    /// [`WriteBuffering`]: struct.WriteBuffering.html
+    pub fn new<F>(storage: &'a (dyn ResourceStorage + Sync + Send), compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'a,
+    {
+        let buffering = WriteBuffering::default();
+        Self {
+            storage,
+            compare: Box::new(compare),
+            buffer: Vec::new(),
+            reservation: Reservation::new(buffering.budget().cloned()),
+            buffering,
+            runs: Vec::new(),
+            run_prefix: format!(
+                "sorter_{}_{}",
+                process::id(),
+                NEXT_SORTER_ID.fetch_add(1, atomic::Ordering::Relaxed)
+            ),
+            run_names: Vec::new(),
+            dedup: false,
+            len: 0,
+        }
+    }
+
+    /// Creates a sorter ordering elements by the key returned by `key`, which
+    /// spills runs to `storage`.
+    pub fn by_key<K, F>(storage: &'a (dyn ResourceStorage + Sync + Send), key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'a,
+    {
+        Self::new(storage, move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Sets how much memory the sorter buffers before spilling a run to
+    /// storage.
+    ///
+    /// The buffer size is the size of a run in bytes. Memory reserved from a
+    /// previously set budget is returned to it.
+    pub fn set_buffering(&mut self, buffering: WriteBuffering) {
+        self.reservation = Reservation::new(buffering.budget().cloned());
+        self.buffering = buffering;
+    }
+
+    /// Enables or disables removing duplicates.
+    ///
+    /// If enabled, only the first added of several elements comparing equal
+    /// is kept.
+    pub fn set_dedup(&mut self, dedup: bool) {
+        self.dedup = dedup;
+    }
+
+    /// Number of elements added to the sorter.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no element was added to the sorter yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of runs spilled to storage so far.
+    pub fn num_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Adds a copy of `element` to the sorter.
+    ///
+    /// Calling this method may spill a run to storage.
+    pub fn push(&mut self, element: &T) -> Result<(), ResourceStorageError> {
+        let size = (self.buffer.len() + 1) * T::SIZE_IN_BYTES;
+        if size > self.buffering.buffer_size() || !self.reservation.reserve(size) {
+            self.spill()?;
+            // if the budget is exhausted by other writers, buffer the element anyway
+            self.reservation.reserve(T::SIZE_IN_BYTES);
+        }
+        // Safety: T is NoOverlap, so its data does not depend on the next element
+        self.buffer.push(unsafe { T::create_unchecked() });
+        copy_struct(
+            element,
+            self.buffer.last_mut().expect("element was just added"),
+        );
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Adds copies of all elements of `iter` to the sorter.
+    pub fn extend<I>(&mut self, iter: I) -> Result<(), ResourceStorageError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        for element in iter {
+            self.push(element.borrow())?;
+        }
+        Ok(())
+    }
+
+    /// Sorts the remaining elements, merges them with the spilled runs into
+    /// `output` and closes it.
+    pub fn finish<'b>(
+        mut self,
+        mut output: ExternalVector<'b, T>,
+    ) -> Result<&'b [T], ResourceStorageError> {
+        self.sort_buffer();
+        let mut runs = std::mem::take(&mut self.runs);
+        runs.push(&self.buffer);
+        runs.retain(|run| !run.is_empty());
+
+        let name: String = output.name().into();
+        let result = if runs.len() == 1 {
+            // runs are already free of duplicates
+            output.write_slice(runs[0])
+        } else {
+            let compare = &*self.compare;
+            let dedup = self.dedup;
+            let mut last: Option<&T> = None;
+            output.extend(Merge::new(runs, compare).filter(|element| {
+                let keep = match last {
+                    Some(last) if dedup => compare(last, element).is_ne(),
+                    _ => true,
+                };
+                if keep {
+                    last = Some(element);
+                }
+                keep
+            }))
+        };
+        result.map_err(|e| ResourceStorageError::from_io_error(e, name))?;
+        output.close()
+    }
+
+    fn sort_buffer(&mut self) {
+        let compare = &self.compare;
+        self.buffer.sort_by(|a, b| compare(a, b));
+        if self.dedup {
+            // `dedup_by` passes the later element first
+            self.buffer
+                .dedup_by(|later, earlier| compare(earlier, later).is_eq());
+        }
+    }
+
+    fn spill(&mut self) -> Result<(), ResourceStorageError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.sort_buffer();
+        let name = format!("{}_run_{}", self.run_prefix, self.runs.len());
+        let into_storage_error = |e| ResourceStorageError::from_io_error(e, name.clone());
+        let mut run = create_external_vector::<T>(self.storage, &name, RUN_SCHEMA)
+            .map_err(into_storage_error)?;
+        run.write_slice(&self.buffer).map_err(into_storage_error)?;
+        self.run_names.push(name.clone());
+        self.runs.push(run.close()?);
+
+        self.buffer.clear();
+        if self.reservation.is_budgeted() {
+            // memory is only accounted for while it is in use
+            self.buffer.shrink_to_fit();
+        }
+        self.reservation.release();
+        Ok(())
+    }
+}
+
+impl<T> Drop for ExternalSorter<'_, T>
+where
+    T: Struct + NoOverlap,
+{
+    fn drop(&mut self) {
+        self.runs.clear();
+        for name in &self.run_names {
+            // Removing is best effort. Safety: the runs are not used anymore,
+            // and their names are unique to this sorter.
+            unsafe {
+                let _ = self.storage.remove(name);
+                let _ = self.storage.remove(&format!("{}.schema", name));
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for ExternalSorter<'_, T>
+where
+    T: Struct + NoOverlap,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ExternalSorter {{ len: {}, runs: {} }}",
+            self.len(),
+            self.num_runs()
+        )
+    }
+}
+
+/// Iterator merging sorted runs, preferring earlier runs for equal elements.
+struct Merge<'r, T> {
+    runs: Vec<&'r [T]>,
+    /// Min-heap of indexes of non-empty runs, ordered by their first element
+    heap: Vec<usize>,
+    compare: &'r Compare<'r, T>,
+}
+
+impl<'r, T> Merge<'r, T> {
+    fn new(runs: Vec<&'r [T]>, compare: &'r Compare<'r, T>) -> Self {
+        let heap = (0..runs.len()).filter(|&i| !runs[i].is_empty()).collect();
+        let mut merge = Self {
+            runs,
+            heap,
+            compare,
+        };
+        for pos in (0..merge.heap.len() / 2).rev() {
+            merge.sift_down(pos);
+        }
+        merge
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        match (self.compare)(&self.runs[a][0], &self.runs[b][0]) {
+            Ordering::Less => true,
+            Ordering::Equal => a < b,
+            Ordering::Greater => false,
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let mut smallest = pos;
+            for child in &[2 * pos + 1, 2 * pos + 2] {
+                if *child < self.heap.len() && self.less(self.heap[*child], self.heap[smallest]) {
+                    smallest = *child;
+                }
+            }
+            if smallest == pos {
+                return;
+            }
+            self.heap.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+}
+
+impl<'r, T> Iterator for Merge<'r, T> {
+    type Item = &'r T;
+
+    fn next(&mut self) -> Option<&'r T> {
+        let top = *self.heap.first()?;
+        let (element, rest) = self.runs[top].split_first()?;
+        self.runs[top] = rest;
+        if rest.is_empty() {
+            let last = self.heap.pop().expect("heap is not empty");
+            if self.heap.is_empty() {
+                return Some(element);
+            }
+            self.heap[0] = last;
+        }
+        self.sift_down(0);
+        Some(element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{memstorage::MemoryResourceStorage, test::A, vector::Vector};
+
+    fn sort(xs: &[u32], buffer_len: usize, dedup: bool) -> (Vec<(u32, u32)>, usize) {
+        let storage = MemoryResourceStorage::new("/root/sorter");
+        let tmp = MemoryResourceStorage::new("/tmp/sorter");
+        let mut sorter = ExternalSorter::by_key(&*tmp, |a: &A| a.x());
+        sorter.set_buffering(WriteBuffering::new(buffer_len * A::SIZE_IN_BYTES));
+        sorter.set_dedup(dedup);
+        let mut input: Vector<A> = Vector::new();
+        for (i, &x) in xs.iter().enumerate() {
+            let a = input.grow();
+            a.set_x(x);
+            a.set_y(i as u32);
+        }
+        sorter.extend(input.iter()).expect("extend failed");
+        assert_eq!(sorter.len(), xs.len());
+        let num_runs = sorter.num_runs();
+
+        let output = create_external_vector::<A>(&*storage, "sorted", "Some schema")
+            .expect("failed to create ExternalVector");
+        let view = sorter.finish(output).expect("sort failed");
+        (view.iter().map(|a| (a.x(), a.y())).collect(), num_runs)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sort(&[], 3, false), (vec![], 0));
+    }
+
+    #[test]
+    fn in_memory() {
+        let (sorted, num_runs) = sort(&[3, 1, 2], 3, false);
+        assert_eq!(num_runs, 0);
+        assert_eq!(sorted, [(1, 1), (2, 2), (3, 0)]);
+    }
+
+    #[test]
+    fn merge_is_stable() {
+        let xs = [5, 3, 5, 1, 3, 0, 5, 2, 1, 4, 0];
+        let (sorted, num_runs) = sort(&xs, 2, false);
+        assert_eq!(num_runs, 5);
+        let mut expected: Vec<_> = xs.iter().zip(0..).map(|(&x, i)| (x, i)).collect();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn dedup() {
+        let xs = [5, 3, 5, 1, 3, 0, 5, 2, 1, 4, 0];
+        let (sorted, _) = sort(&xs, 3, true);
+        assert_eq!(sorted, [(0, 5), (1, 3), (2, 7), (3, 1), (4, 9), (5, 0)]);
+        let (sorted, num_runs) = sort(&[2, 2, 1], 3, true);
+        assert_eq!(num_runs, 0);
+        assert_eq!(sorted, [(1, 2), (2, 0)]);
+    }
+
+    #[test]
+    fn comparator() {
+        let storage = MemoryResourceStorage::new("/root/sorter");
+        let tmp = MemoryResourceStorage::new("/tmp/sorter");
+        let mut sorter = ExternalSorter::new(&*tmp, |a: &A, b: &A| b.x().cmp(&a.x()));
+        sorter.set_buffering(WriteBuffering::new(A::SIZE_IN_BYTES));
+        let mut input: Vector<A> = Vector::new();
+        for x in 0..10 {
+            input.grow().set_x(x);
+        }
+        sorter.extend(&input[..]).expect("extend failed");
+        assert_eq!(sorter.num_runs(), 9);
+        let output = create_external_vector::<A>(&*storage, "sorted", "Some schema")
+            .expect("failed to create ExternalVector");
+        let view = sorter.finish(output).expect("sort failed");
+        assert_eq!(
+            view.iter().map(|a| a.x()).collect::<Vec<_>>(),
+            (0..10).rev().collect::<Vec<_>>()
+        );
+    }
+
+    fn spilling_sorter<'a>(tmp: &'a MemoryResourceStorage, xs: &[u32]) -> ExternalSorter<'a, A> {
+        let mut sorter = ExternalSorter::by_key(tmp, |a: &A| a.x());
+        sorter.set_buffering(WriteBuffering::new(A::SIZE_IN_BYTES));
+        for &x in xs {
+            let mut a = Vector::<A>::with_len(1);
+            a[0].set_x(x);
+            sorter.push(&a[0]).expect("push failed");
+        }
+        sorter
+    }
+
+    #[test]
+    fn shared_temporary_storage() {
+        let storage = MemoryResourceStorage::new("/root/sorter");
+        let tmp = MemoryResourceStorage::new("/tmp/sorter");
+        let first = spilling_sorter(&tmp, &[3, 1, 2]);
+        let second = spilling_sorter(&tmp, &[6, 4, 5]);
+        assert_eq!(first.num_runs(), 2);
+        assert_eq!(second.num_runs(), 2);
+        let names: Vec<_> = first
+            .run_names
+            .iter()
+            .chain(&second.run_names)
+            .cloned()
+            .collect();
+        assert!(names.iter().all(|name| tmp.exists(name)));
+
+        let output = create_external_vector::<A>(&*storage, "first", "Some schema")
+            .expect("failed to create ExternalVector");
+        let view = first.finish(output).expect("sort failed");
+        assert_eq!(view.iter().map(|a| a.x()).collect::<Vec<_>>(), [1, 2, 3]);
+        let output = create_external_vector::<A>(&*storage, "second", "Some schema")
+            .expect("failed to create ExternalVector");
+        let view = second.finish(output).expect("sort failed");
+        assert_eq!(view.iter().map(|a| a.x()).collect::<Vec<_>>(), [4, 5, 6]);
+
+        for name in names {
+            assert!(!tmp.exists(&name));
+            assert!(!tmp.exists(&format!("{}.schema", name)));
+        }
+    }
+
+    #[test]
+    fn runs_are_removed_on_drop() {
+        let tmp = MemoryResourceStorage::new("/tmp/sorter");
+        let sorter = spilling_sorter(&tmp, &[3, 1, 2]);
+        let names = sorter.run_names.clone();
+        assert_eq!(names.len(), 2);
+        drop(sorter);
+        for name in names {
+            assert!(!tmp.exists(&name));
+        }
+    }
+}
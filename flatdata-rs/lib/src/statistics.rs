@@ -0,0 +1,508 @@
+use crate::{
+    dynamic::{DynArchive, DynResource, DynStruct},
+    error::ResourceStorageError,
+    schema,
+};
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+/// Statistics of an archive computed by [`archive_statistics`].
+///
+/// [`archive_statistics`]: fn.archive_statistics.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveStatistics {
+    /// Fully qualified name of the archive.
+    pub name: String,
+    /// Statistics of the resources in the order of the schema.
+    pub resources: Vec<ResourceStatistics>,
+}
+
+impl ArchiveStatistics {
+    /// Returns the statistics of the resource with the given name, if any.
+    pub fn resource(&self, name: &str) -> Option<&ResourceStatistics> {
+        self.resources.iter().find(|resource| resource.name == name)
+    }
+
+    /// Total size of all resources in bytes, including subarchives.
+    pub fn size_in_bytes(&self) -> usize {
+        self.resources
+            .iter()
+            .map(|resource| resource.size_in_bytes)
+            .sum()
+    }
+}
+
+/// Statistics of a single resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceStatistics {
+    /// Name of the resource.
+    pub name: String,
+    /// Size of the resource in bytes, including the index of multivectors.
+    pub size_in_bytes: usize,
+    /// Statistics depending on the type of the resource.
+    pub kind: ResourceStatisticsKind,
+}
+
+/// Statistics depending on the type of a resource.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceStatisticsKind {
+    /// The resource is optional and missing.
+    Missing,
+    /// A single struct.
+    Instance {
+        /// Values of the fields.
+        fields: Vec<FieldStatistics>,
+    },
+    /// A vector of structs.
+    Vector {
+        /// Number of elements.
+        len: usize,
+        /// Values of the fields over all elements.
+        fields: Vec<FieldStatistics>,
+    },
+    /// A multivector.
+    MultiVector {
+        /// Number of items.
+        len: usize,
+        /// Statistics of the variants in the order of their type index.
+        variants: Vec<VariantStatistics>,
+        /// Number of items by their number of elements.
+        bucket_sizes: BTreeMap<usize, usize>,
+    },
+    /// Raw bytes.
+    RawData,
+    /// A subarchive.
+    Archive(ArchiveStatistics),
+}
+
+/// Statistics of a variant of a multivector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantStatistics {
+    /// Fully qualified name of the struct.
+    pub name: String,
+    /// Number of elements of this variant over all items.
+    pub count: usize,
+    /// Values of the fields over all elements of this variant.
+    pub fields: Vec<FieldStatistics>,
+}
+
+/// Statistics of the values of a field.
+///
+/// Values are the underlying integers, i.e. `0` and `1` for booleans, and the
+/// values of the variants for enums.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldStatistics {
+    /// Name of the field.
+    pub name: String,
+    /// Declared width of the field in bits.
+    pub width: usize,
+    /// Whether the field is signed.
+    pub is_signed: bool,
+    /// Minimal value, or `None` if there are no values.
+    pub min: Option<i128>,
+    /// Maximal value, or `None` if there are no values.
+    pub max: Option<i128>,
+    /// Number of missing values of `@optional` fields. These are not
+    /// considered for the other statistics.
+    pub num_missing: usize,
+    /// Estimated number of distinct values.
+    pub distinct_estimate: u64,
+}
+
+/// Number of bits needed to store `value`.
+fn bits_needed(value: i128, is_signed: bool) -> usize {
+    let magnitude = if value < 0 { !value } else { value };
+    let bits = (128 - magnitude.leading_zeros()) as usize;
+    if is_signed {
+        bits + 1
+    } else {
+        bits
+    }
+}
+
+impl FieldStatistics {
+    /// Number of bits needed to store all values of the field, including the
+    /// sign bit of signed fields.
+    ///
+    /// For `@optional` fields, the invalid value is not considered, i.e. the
+    /// result might be smaller than the bits needed for storing it.
+    pub fn used_bits(&self) -> usize {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => {
+                bits_needed(min, self.is_signed).max(bits_needed(max, self.is_signed))
+            }
+            _ => 0,
+        }
+    }
+
+    /// Number of declared bits which are not needed for storing the values,
+    /// i.e. how much the width could be tightened or how much the values
+    /// could grow before they overflow.
+    pub fn unused_bits(&self) -> usize {
+        self.width.saturating_sub(self.used_bits())
+    }
+}
+
+/// Scans an archive and computes statistics of all its resources.
+///
+/// This reads all data of the archive including subarchives.
+///
+/// # Examples
+///
+/// ```
+/// use flatdata::{archive_statistics, DynArchive, MemoryResourceStorage};
+/// # use flatdata::test::XBuilder;
+///
+/// let storage = MemoryResourceStorage::new("/root/statistics");
+/// # let builder = XBuilder::new(storage.clone()).unwrap();
+/// # let mut data = builder.start_data().unwrap();
+/// # for &x in &[3, 200, 17] {
+/// #     data.grow().unwrap().set_x(x);
+/// # }
+/// # data.close().unwrap();
+/// // storage contains an archive `X` with a vector resource `data` of
+/// // structs with a field `x : u32 : 16`
+/// let archive = DynArchive::open(storage, "X").expect("failed to open archive");
+/// let statistics = archive_statistics(&archive).expect("failed to read archive");
+/// let data = statistics.resource("data").expect("missing resource");
+/// # match &data.kind {
+/// #     flatdata::ResourceStatisticsKind::Vector { fields, .. } => {
+/// #         assert_eq!(fields[0].used_bits(), 8);
+/// #     }
+/// #     _ => unreachable!(),
+/// # }
+/// ```
+pub fn archive_statistics(archive: &DynArchive) -> Result<ArchiveStatistics, ResourceStorageError> {
+    let mut resources = Vec::new();
+    for resource in &archive.definition().resources {
+        let (size_in_bytes, kind) = match archive.resource(&resource.name)? {
+            None => (0, ResourceStatisticsKind::Missing),
+            Some(DynResource::Instance(x)) => {
+                let mut fields = FieldsAccumulator::new(x.definition());
+                fields.add(&x);
+                (
+                    x.as_bytes().len(),
+                    ResourceStatisticsKind::Instance {
+                        fields: fields.finish(),
+                    },
+                )
+            }
+            Some(DynResource::Vector(x)) => {
+                let mut fields = FieldsAccumulator::new(x.definition());
+                for element in x.iter() {
+                    fields.add(&element);
+                }
+                (
+                    x.as_bytes().len(),
+                    ResourceStatisticsKind::Vector {
+                        len: x.len(),
+                        fields: fields.finish(),
+                    },
+                )
+            }
+            Some(DynResource::MultiVector(x)) => {
+                let mut variants: Vec<_> = x
+                    .types()
+                    .iter()
+                    .map(|definition| (0, FieldsAccumulator::new(definition)))
+                    .collect();
+                let mut bucket_sizes = BTreeMap::new();
+                for item in x.iter() {
+                    let mut size = 0;
+                    for element in item {
+                        let (count, fields) = variants
+                            .iter_mut()
+                            .find(|(_, fields)| {
+                                std::ptr::eq(fields.definition, element.definition())
+                            })
+                            .expect("element of unknown type");
+                        *count += 1;
+                        fields.add(&element);
+                        size += 1;
+                    }
+                    *bucket_sizes.entry(size).or_insert(0) += 1;
+                }
+                let variants = variants
+                    .into_iter()
+                    .map(|(count, fields)| VariantStatistics {
+                        name: fields.definition.name.clone(),
+                        count,
+                        fields: fields.finish(),
+                    })
+                    .collect();
+                (
+                    x.index_as_bytes().len() + x.data_as_bytes().len(),
+                    ResourceStatisticsKind::MultiVector {
+                        len: x.len(),
+                        variants,
+                        bucket_sizes,
+                    },
+                )
+            }
+            Some(DynResource::RawData(x)) => (x.as_bytes().len(), ResourceStatisticsKind::RawData),
+            Some(DynResource::Archive(x)) => {
+                let statistics = archive_statistics(&x)?;
+                (
+                    statistics.size_in_bytes(),
+                    ResourceStatisticsKind::Archive(statistics),
+                )
+            }
+        };
+        resources.push(ResourceStatistics {
+            name: resource.name.clone(),
+            size_in_bytes,
+            kind,
+        });
+    }
+    Ok(ArchiveStatistics {
+        name: archive.name().into(),
+        resources,
+    })
+}
+
+/// Accumulates the statistics of the fields of a struct.
+struct FieldsAccumulator<'a> {
+    definition: &'a schema::Struct,
+    fields: Vec<FieldAccumulator>,
+}
+
+impl<'a> FieldsAccumulator<'a> {
+    fn new(definition: &'a schema::Struct) -> Self {
+        let fields = definition
+            .fields
+            .iter()
+            .map(|_| FieldAccumulator::default())
+            .collect();
+        Self { definition, fields }
+    }
+
+    fn add(&mut self, element: &DynStruct) {
+        for (field, (_, value)) in self.fields.iter_mut().zip(element.fields()) {
+            match value.as_i128() {
+                Some(value) => field.add(value),
+                None => field.num_missing += 1,
+            }
+        }
+    }
+
+    fn finish(self) -> Vec<FieldStatistics> {
+        self.definition
+            .fields
+            .iter()
+            .zip(self.fields)
+            .map(|(field, accumulator)| FieldStatistics {
+                name: field.name.clone(),
+                width: field.width,
+                is_signed: field.basic_type.is_signed(),
+                min: accumulator.min,
+                max: accumulator.max,
+                num_missing: accumulator.num_missing,
+                distinct_estimate: accumulator.distinct.estimate(),
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct FieldAccumulator {
+    min: Option<i128>,
+    max: Option<i128>,
+    num_missing: usize,
+    distinct: DistinctCounter,
+}
+
+impl FieldAccumulator {
+    fn add(&mut self, value: i128) {
+        self.min = Some(self.min.map_or(value, |x| x.min(value)));
+        self.max = Some(self.max.map_or(value, |x| x.max(value)));
+        self.distinct.add(value);
+    }
+}
+
+/// Number of bits of the hash used for selecting a register.
+const DISTINCT_PRECISION: u32 = 10;
+
+/// Number of distinct values up to which values are counted exactly.
+const DISTINCT_EXACT_LIMIT: usize = 1 << DISTINCT_PRECISION;
+
+/// Estimates the number of distinct values with HyperLogLog.
+///
+/// Small counts are exact, larger ones have a standard error of about 3%.
+struct DistinctCounter {
+    exact: Option<HashSet<i128>>,
+    registers: Vec<u8>,
+}
+
+impl Default for DistinctCounter {
+    fn default() -> Self {
+        Self {
+            exact: Some(HashSet::new()),
+            registers: vec![0; 1 << DISTINCT_PRECISION],
+        }
+    }
+}
+
+impl DistinctCounter {
+    fn add(&mut self, value: i128) {
+        if let Some(exact) = &mut self.exact {
+            exact.insert(value);
+            if exact.len() > DISTINCT_EXACT_LIMIT {
+                self.exact = None;
+            }
+        }
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - DISTINCT_PRECISION)) as usize;
+        let rank =
+            ((hash << DISTINCT_PRECISION) | (1 << (DISTINCT_PRECISION - 1))).leading_zeros() + 1;
+        self.registers[index] = self.registers[index].max(rank as u8);
+    }
+
+    fn estimate(&self) -> u64 {
+        if let Some(exact) = &self.exact {
+            return exact.len() as u64;
+        }
+        let m = self.registers.len() as f64;
+        let zeros = self.registers.iter().filter(|&&x| x == 0).count();
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&x| 1.0 / (1u64 << x) as f64)
+            .sum();
+        let estimate = 0.7213 / (1.0 + 1.079 / m) * m * m / sum;
+        if estimate <= 2.5 * m && zeros != 0 {
+            // linear counting for small cardinalities
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::*, MemoryResourceStorage};
+
+    #[test]
+    fn test_used_bits() {
+        let field = |is_signed, min, max| FieldStatistics {
+            name: "x".into(),
+            width: 16,
+            is_signed,
+            min,
+            max,
+            num_missing: 0,
+            distinct_estimate: 0,
+        };
+        assert_eq!(field(false, None, None).used_bits(), 0);
+        assert_eq!(field(false, Some(0), Some(0)).used_bits(), 0);
+        assert_eq!(field(false, Some(0), Some(200)).used_bits(), 8);
+        assert_eq!(field(false, Some(0), Some(200)).unused_bits(), 8);
+        assert_eq!(field(false, Some(0), Some(256)).used_bits(), 9);
+        assert_eq!(field(true, Some(-128), Some(127)).used_bits(), 8);
+        assert_eq!(field(true, Some(-129), Some(0)).used_bits(), 9);
+        assert_eq!(field(true, Some(0), Some(128)).used_bits(), 9);
+        assert_eq!(field(true, Some(-1), Some(0)).used_bits(), 1);
+    }
+
+    #[test]
+    fn test_distinct_counter() {
+        let mut counter = DistinctCounter::default();
+        assert_eq!(counter.estimate(), 0);
+        for x in 0..10 {
+            counter.add(x);
+            counter.add(x);
+        }
+        assert_eq!(counter.estimate(), 10);
+        for x in 0..100_000 {
+            counter.add(x * 7);
+        }
+        let estimate = counter.estimate() as f64;
+        assert!((estimate - 100_000.0).abs() < 10_000.0, "{}", estimate);
+    }
+
+    #[test]
+    fn test_vector() {
+        let storage = MemoryResourceStorage::new("/root/statistics");
+        let builder = XBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut data = builder.start_data().expect("failed to start data");
+        for &(x, y) in &[(3, 1), (200, 1), (17, 1)] {
+            let a = data.grow().expect("grow failed");
+            a.set_x(x);
+            a.set_y(y);
+        }
+        data.close().expect("failed to close data");
+
+        let archive = DynArchive::open(storage, "X").expect("failed to open archive");
+        let statistics = archive_statistics(&archive).expect("failed to compute statistics");
+        assert_eq!(statistics.name, ".test.X");
+        let data = statistics.resource("data").expect("missing data");
+        assert_eq!(data.size_in_bytes, 3 * 5);
+        let fields = match &data.kind {
+            ResourceStatisticsKind::Vector { len: 3, fields } => fields,
+            x => panic!("unexpected statistics {:?}", x),
+        };
+        assert_eq!(
+            fields[0],
+            FieldStatistics {
+                name: "x".into(),
+                width: 16,
+                is_signed: false,
+                min: Some(3),
+                max: Some(200),
+                num_missing: 0,
+                distinct_estimate: 3,
+            }
+        );
+        assert_eq!(fields[1].distinct_estimate, 1);
+        assert_eq!(fields[1].used_bits(), 1);
+        assert_eq!(fields[2].name, "e");
+    }
+
+    #[test]
+    fn test_multivector() {
+        let storage = MemoryResourceStorage::new("/root/statistics");
+        let builder = ZBuilder::new(storage.clone()).expect("failed to create builder");
+        let mut ab = builder.start_ab().expect("failed to start ab");
+        {
+            let mut item = ab.grow().expect("grow failed");
+            item.add_a().set_x(1);
+            item.add_b().set_id(2);
+            item.add_b().set_id(5);
+        }
+        ab.grow().expect("grow failed");
+        ab.grow().expect("grow failed").add_b().set_id(3);
+        ab.close().expect("failed to close ab");
+
+        let archive = DynArchive::open(storage, "Z").expect("failed to open archive");
+        let statistics = archive_statistics(&archive).expect("failed to compute statistics");
+        let (len, variants, bucket_sizes) = match &statistics.resource("ab").unwrap().kind {
+            ResourceStatisticsKind::MultiVector {
+                len,
+                variants,
+                bucket_sizes,
+            } => (*len, variants, bucket_sizes),
+            x => panic!("unexpected statistics {:?}", x),
+        };
+        assert_eq!(len, 3);
+        assert_eq!(
+            bucket_sizes
+                .iter()
+                .map(|(&k, &v)| (k, v))
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (1, 1), (3, 1)]
+        );
+        assert_eq!(variants[0].name, ".test.A");
+        assert_eq!(variants[0].count, 1);
+        assert_eq!(variants[1].name, ".test.B");
+        assert_eq!(variants[1].count, 3);
+        assert_eq!(variants[1].fields[0].min, Some(2));
+        assert_eq!(variants[1].fields[0].max, Some(5));
+        assert_eq!(variants[1].fields[0].used_bits(), 3);
+    }
+}
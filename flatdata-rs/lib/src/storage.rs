@@ -1,15 +1,19 @@
 use crate::{
+    arrayview::SliceExt,
     error::ResourceStorageError,
     memory::{SizeType, PADDING_SIZE},
     multivector::MultiVector,
-    structs::{Struct, VariadicRefFactory},
+    rawdata::RawDataWriter,
+    structs::{IndexStruct, Struct, VariadicRefFactory},
     vector::ExternalVector,
 };
 
 use std::{
     fmt,
     io::{self, Seek, Write},
-    mem, str,
+    mem,
+    ops::Range,
+    str,
     sync::Arc,
 };
 
@@ -73,6 +77,45 @@ pub trait ResourceStorage: std::fmt::Debug {
     /// writing to it.
     fn create_output_stream(&self, resource_name: &str) -> io::Result<Box<dyn Stream>>;
 
+    /// Reopens an existing resource for writing to its first `len` bytes.
+    ///
+    /// The returned stream is positioned at `len`. The written content
+    /// replaces the resource only when the stream is flushed; if the stream
+    /// is dropped before, the resource stays unchanged. Data previously
+    /// returned by [`read_resource`] is never modified, whereas reading the
+    /// resource after flushing returns the new data.
+    ///
+    /// Used for appending to finalized resources. The default implementation
+    /// fails with an error of kind `Unsupported`.
+    ///
+    /// [`read_resource`]: #tymethod.read_resource
+    fn reopen_output_stream(&self, resource_name: &str, len: u64) -> io::Result<Box<dyn Stream>> {
+        let _ = len;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Appending to {} is not supported", resource_name),
+        ))
+    }
+
+    /// Removes a resource from the storage and frees the memory and disk
+    /// space used by its data.
+    ///
+    /// Used for removing temporary resources. The default implementation fails
+    /// with an error of kind `Unsupported`.
+    ///
+    /// # Safety
+    ///
+    /// Data of the resource previously returned by [`read_resource`] must not
+    /// be used anymore.
+    ///
+    /// [`read_resource`]: #tymethod.read_resource
+    unsafe fn remove(&self, resource_name: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("Removing {} is not supported", resource_name),
+        ))
+    }
+
     //
     // Implementation helper
     //
@@ -107,27 +150,40 @@ pub trait ResourceStorage: std::fmt::Debug {
             .read_resource(&schema_name)
             .map_err(|e| ResourceStorageError::from_io_error(e, resource_name.into()))?;
 
-        if data.len() < mem::size_of::<SizeType>() + PADDING_SIZE {
-            return Err(ResourceStorageError::UnexpectedDataSize);
-        }
+        let range = check_resource_data(resource_name, data, schema, expected_schema)?;
+        Ok(&data[range])
+    }
+}
 
-        let size = flatdata_read_bytes!(SizeType, data.as_ptr()) as usize;
-        if size + mem::size_of::<SizeType>() + PADDING_SIZE != data.len() {
-            return Err(ResourceStorageError::UnexpectedDataSize);
-        }
+/// Checks the header of the raw `data` of a resource and its stored `schema`
+/// against the expected schema.
+///
+/// Returns the position of the resource's payload in `data`.
+pub(crate) fn check_resource_data(
+    resource_name: &str,
+    data: &[u8],
+    schema: &[u8],
+    expected_schema: &str,
+) -> Result<Range<usize>, ResourceStorageError> {
+    if data.len() < mem::size_of::<SizeType>() + PADDING_SIZE {
+        return Err(ResourceStorageError::UnexpectedDataSize);
+    }
 
-        let stored_schema_slice: &[u8] = schema;
-        let stored_schema =
-            str::from_utf8(stored_schema_slice).map_err(ResourceStorageError::Utf8Error)?;
-        if stored_schema != expected_schema {
-            return Err(ResourceStorageError::WrongSignature {
-                resource_name: resource_name.into(),
-                diff: compute_diff(stored_schema, expected_schema),
-            });
-        }
+    let size = flatdata_read_bytes!(SizeType, data.as_ptr()) as usize;
+    if size + mem::size_of::<SizeType>() + PADDING_SIZE != data.len() {
+        return Err(ResourceStorageError::UnexpectedDataSize);
+    }
 
-        Ok(&data[mem::size_of::<SizeType>()..][..size])
+    let stored_schema = str::from_utf8(schema).map_err(ResourceStorageError::Utf8Error)?;
+    if stored_schema != expected_schema {
+        return Err(ResourceStorageError::WrongSignature {
+            resource_name: resource_name.into(),
+            diff: compute_diff(stored_schema, expected_schema),
+        });
     }
+
+    let start = mem::size_of::<SizeType>();
+    Ok(start..start + size)
 }
 
 //
@@ -212,6 +268,40 @@ where
     Ok(ExternalVector::new(handle))
 }
 
+/// Helper for reopening a finalized vector resource in the given resource
+/// storage for appending to it.
+///
+/// The resource and its schema are checked like when reading it. For structs
+/// with ranges, the stored sentinel is removed, so it has to be added again
+/// before closing the vector, exactly like for a new vector. If nothing is
+/// appended, the stored sentinel is written back when closing the vector.
+#[doc(hidden)]
+pub fn append_external_vector<'a, T>(
+    storage: &'a (dyn ResourceStorage + Sync + Send),
+    resource_name: &str,
+    schema: &str,
+) -> Result<ExternalVector<'a, T>, ResourceStorageError>
+where
+    T: Struct,
+{
+    let data = <&[T]>::from_bytes(storage.read(resource_name, schema)?)?;
+    let len = data.len();
+    let sentinel = if T::IS_OVERLAPPING_WITH_NEXT {
+        // Safety: slices of overlapping structs are always followed by a sentinel
+        unsafe { std::slice::from_raw_parts(data.as_ptr(), len + 1) }.last()
+    } else {
+        None
+    };
+    let handle =
+        ResourceHandle::try_reopen(storage, resource_name, schema, len * T::SIZE_IN_BYTES)?;
+    Ok(ExternalVector::new_appending(
+        handle,
+        len,
+        data.last(),
+        sentinel,
+    ))
+}
+
 /// Helper for creating a multivector in the given resource storage.
 ///
 /// Creates a new resource with given name and schema in storage, and returns
@@ -243,6 +333,71 @@ where
     Ok(MultiVector::new(index, handle))
 }
 
+/// Helper for reopening a finalized multivector resource in the given
+/// resource storage for appending to it.
+///
+/// The data, the index and their schemas are checked like when reading them.
+/// The sentinel of the index is removed and added again when the multivector
+/// is closed.
+#[doc(hidden)]
+pub fn append_multi_vector<'a, Ts>(
+    storage: &'a (dyn ResourceStorage + Sync + Send),
+    resource_name: &str,
+    schema: &str,
+) -> Result<MultiVector<'a, Ts>, ResourceStorageError>
+where
+    Ts: VariadicRefFactory,
+{
+    let index_name = format!("{}_index", resource_name);
+    let index_schema = format!("index({})", schema);
+    let index_data = storage.read(&index_name, &index_schema)?;
+    let data = storage.read(resource_name, schema)?;
+
+    // the sentinel of the index has to point to the end of the data
+    let index = <&[Ts::Index]>::from_bytes(index_data)?;
+    if index.last().map_or(0, |item| item.range().end) != data.len() {
+        return Err(ResourceStorageError::UnexpectedDataSize);
+    }
+
+    // Both resources are validated before any of them is reopened. Reopened
+    // resources are only replaced when they are closed, so a failure to reopen
+    // the data leaves the index intact.
+    let len = index.len();
+    let index_handle = ResourceHandle::try_reopen(
+        storage,
+        &index_name,
+        &index_schema,
+        len * <Ts::Index as Struct>::SIZE_IN_BYTES,
+    )?;
+    let data_handle = ResourceHandle::try_reopen(storage, resource_name, schema, data.len())?;
+    // the multivector adds a new sentinel to the index when closed
+    let index = ExternalVector::new_appending(index_handle, len, index.last(), None);
+    Ok(MultiVector::new_appending(index, data_handle))
+}
+
+/// Helper for creating a raw data writer in the given resource storage.
+///
+/// Creates a new resource with given name and schema in storage, and returns
+/// a [`RawDataWriter`] using this resource for writing and flushing data to
+/// storage.
+#[doc(hidden)]
+pub fn create_raw_data_writer<'a>(
+    storage: &'a (dyn ResourceStorage + Sync + Send),
+    resource_name: &str,
+    schema: &str,
+) -> io::Result<RawDataWriter<'a>> {
+    // write schema
+    let schema_name = format!("{}.schema", resource_name);
+    let mut stream = storage.create_output_stream(&schema_name)?;
+    stream.write_all(schema.as_bytes())?;
+
+    // create raw data writer
+    let data_writer = storage.create_output_stream(resource_name)?;
+    let handle =
+        ResourceHandle::try_new(storage, resource_name.into(), schema.into(), data_writer)?;
+    Ok(RawDataWriter::new(handle))
+}
+
 /// Creates a new archive in resource storage.
 ///
 /// A resource with name `T::NAME` is created in the storage. Its content is
@@ -279,6 +434,20 @@ pub fn create_archive(
     Ok(())
 }
 
+/// Opens an existing archive in resource storage for adding data to it.
+///
+/// Checks the signature of the archive like when reading it.
+#[doc(hidden)]
+pub fn open_archive(
+    name: &str,
+    schema: &str,
+    storage: &StorageHandle,
+) -> Result<(), ResourceStorageError> {
+    let signature_name = format!("{}.archive", name);
+    storage.read(&signature_name, schema)?;
+    Ok(())
+}
+
 /// A handle to a resource for writing to it.
 ///
 /// Wraps a `Stream` returned by [`create_output_stream`].
@@ -321,6 +490,31 @@ impl<'a> ResourceHandle<'a> {
         })
     }
 
+    /// Reopens a finalized resource for appending to its first `size_in_bytes`
+    /// bytes of data.
+    ///
+    /// Data following them, including the padding, is removed. The header is
+    /// updated when the resource is closed again.
+    pub(crate) fn try_reopen(
+        storage: &'a (dyn ResourceStorage + Sync + Send),
+        name: &str,
+        schema: &str,
+        size_in_bytes: usize,
+    ) -> Result<Self, ResourceStorageError> {
+        let len = (mem::size_of::<SizeType>() + size_in_bytes) as u64;
+        let stream = storage
+            .reopen_output_stream(name, len)
+            .map_err(|e| ResourceStorageError::from_io_error(e, name.into()))?;
+        Ok(Self {
+            stream,
+            size_in_bytes,
+            storage,
+            name: name.into(),
+            schema: schema.into(),
+            finalized: false,
+        })
+    }
+
     /// Writes data to the underlying stream.
     pub(crate) fn write(&mut self, data: &[u8]) -> io::Result<()> {
         let res = self.stream.write_all(data);
@@ -343,6 +537,10 @@ impl<'a> ResourceHandle<'a> {
         &self.name
     }
 
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
     fn finalize(&mut self) -> Result<(), ResourceStorageError> {
         assert!(!self.finalized);
         self.finalized = true;
@@ -358,6 +556,9 @@ impl<'a> ResourceHandle<'a> {
             .map_err(into_storage_error)?;
         write_size(self.size_in_bytes as u64, &mut self.stream).map_err(into_storage_error)?;
 
+        // Reopened resources are only replaced when flushed
+        self.stream.flush().map_err(into_storage_error)?;
+
         Ok(())
     }
 }
@@ -31,6 +31,44 @@ use std::fmt::Debug;
 #[doc(hidden)]
 pub use std::marker;
 
+/// Expands the given items only if the `serde` feature is enabled.
+///
+/// Used by the generated code to implement `serde::Serialize`, so that it
+/// does not depend on the features of the crate including it.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! flatdata_serde {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands the given items only if the `serde` feature is enabled.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! flatdata_serde {
+    ($($item:item)*) => {};
+}
+
+/// Expands the given items only if the `ord` feature is enabled.
+///
+/// Used by the generated code to implement `Ord` and `PartialOrd`, so that it
+/// does not depend on the features of the crate including it.
+#[cfg(feature = "ord")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! flatdata_ord {
+    ($($item:item)*) => { $($item)* };
+}
+
+/// Expands the given items only if the `ord` feature is enabled.
+#[cfg(not(feature = "ord"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! flatdata_ord {
+    ($($item:item)*) => {};
+}
+
 /// A factory trait used to bind lifetime to Ref implementations.
 ///
 /// Vector/ArrayView-like classes cannot be directly implemented over the
@@ -49,6 +87,141 @@ pub trait Struct: Debug {
     const SIZE_IN_BYTES: usize;
     /// Whether this structs requires data of the next instance
     const IS_OVERLAPPING_WITH_NEXT: bool;
+
+    /// Returns `false` if a range of this struct ends before it starts, i.e.
+    /// if the next struct starts its range with a smaller value.
+    ///
+    /// Used to detect truncated range values. Structs without ranges always
+    /// return `true`.
+    #[inline]
+    fn has_valid_ranges(&self) -> bool {
+        true
+    }
+
+    /// Copies `source` into this struct.
+    ///
+    /// For structs with ranges, the starts of the ranges of this struct are
+    /// kept, and the starts of the ranges of `next` are set, such that the
+    /// ranges of this struct have the same lengths as the ones of `source`.
+    /// Used by [`Vector`] to keep ranges consistent when copying structs.
+    ///
+    /// [`Vector`]: struct.Vector.html
+    #[doc(hidden)]
+    #[inline]
+    fn copy_rebased(&mut self, next: &mut Self, source: &Self)
+    where
+        Self: Sized,
+    {
+        let _ = next;
+        crate::vector::copy_struct(source, self);
+    }
+}
+
+/// Static description of a field of a struct, cf. [`StructReflect`].
+///
+/// [`StructReflect`]: trait.StructReflect.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// Name of the field as defined in the schema.
+    pub name: &'static str,
+    /// Primitive type the field is stored as, e.g. `"u32"` or `"bool"`. For
+    /// enum fields, this is the underlying type of the enum.
+    pub primitive_type: &'static str,
+    /// Offset of the field in bits from the start of the struct.
+    pub offset: usize,
+    /// Width of the field in bits.
+    pub width: usize,
+    /// Whether the primitive type is signed.
+    pub is_signed: bool,
+    /// Enum type of the field, if any.
+    pub enum_type: Option<&'static EnumInfo>,
+    /// Value marking the field as missing (`@optional`).
+    pub invalid_value: Option<i128>,
+    /// Name of the range defined by the field (`@range`).
+    pub range: Option<&'static str>,
+}
+
+/// Static description of an enum, cf. [`FieldInfo`].
+///
+/// [`FieldInfo`]: struct.FieldInfo.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EnumInfo {
+    /// Fully qualified name of the enum, e.g. `".n.E"`.
+    pub name: &'static str,
+    /// Names and values of the variants as defined in the schema.
+    pub variants: &'static [(&'static str, i128)],
+}
+
+impl EnumInfo {
+    /// Returns the name of the variant with the given value, if any.
+    pub fn variant_name(&self, value: i128) -> Option<&'static str> {
+        self.variants
+            .iter()
+            .find(|(_, x)| *x == value)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// Runtime reflection of a struct.
+///
+/// Implemented by the generated code for each flatdata struct. Allows to
+/// write generic tools, like exporters or validators, which work on structs
+/// of any schema.
+pub trait StructReflect: Struct {
+    /// Name of the struct as defined in the schema.
+    const NAME: &'static str;
+    /// All fields of the struct in the order of their definition.
+    const FIELDS: &'static [FieldInfo];
+
+    /// Returns the raw value of the field `name`, or `None` if there is no
+    /// such field.
+    ///
+    /// The value is not interpreted, i.e. enums are returned as their
+    /// underlying value, and missing optional values as their invalid value.
+    /// Signed values are sign-extended to 64 bits, so they can be restored by
+    /// casting the result to `i64`.
+    fn get_field_u64(&self, name: &str) -> Option<u64>;
+
+    /// Returns the description of the field `name`, or `None` if there is no
+    /// such field.
+    fn field_info(name: &str) -> Option<&'static FieldInfo> {
+        Self::FIELDS.iter().find(|field| field.name == name)
+    }
+}
+
+/// Kind of a resource of an archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    /// A single struct.
+    Instance,
+    /// A vector of structs.
+    Vector,
+    /// A multivector of structs of different types.
+    MultiVector,
+    /// Raw bytes.
+    RawData,
+    /// A subarchive.
+    Archive,
+}
+
+/// Static description of a resource of an archive.
+///
+/// The generated code provides a constant `RESOURCES` for each archive, which
+/// lists all resources of the archive in the order of their definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResourceInfo {
+    /// Name of the resource as defined in the schema.
+    pub name: &'static str,
+    /// Kind of the resource.
+    pub kind: ResourceKind,
+    /// Fully qualified names of the types of the elements of the resource,
+    /// e.g. `[".n.S"]`. Multivectors list all of their variants, subarchives
+    /// their archive type, and raw data has no element types.
+    pub element_types: &'static [&'static str],
+    /// Whether the resource is optional (`@optional`).
+    pub is_optional: bool,
+    /// Schema of the resource.
+    pub schema: &'static str,
 }
 
 /// Marks structs that can be used stand-alone, e.g. no range
@@ -69,6 +242,10 @@ pub trait IndexStruct: Struct {
 
     /// Provide setter for index
     fn set_index(&mut self, value: usize);
+
+    /// Provide setter for index, which fails if `value` does not fit into
+    /// the index
+    fn try_set_index(&mut self, value: usize) -> Result<(), crate::FieldOverflowError>;
 }
 
 /// Index specifying a variadic type of `MultiArrayView`.
@@ -118,6 +295,42 @@ pub trait VariadicStruct<'a>: Clone {
     fn create_mut(data: &'a mut Vec<u8>) -> Self::ItemMut;
 }
 
+/// Static description of a variant of a multivector, cf. [`VariadicReflect`].
+///
+/// [`VariadicReflect`]: trait.VariadicReflect.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantInfo {
+    /// Fully qualified name of the struct of the variant, e.g. `".n.S"`.
+    pub name: &'static str,
+    /// Size of the struct in bytes.
+    pub size_in_bytes: usize,
+    /// All fields of the struct in the order of their definition.
+    pub fields: &'static [FieldInfo],
+}
+
+/// Runtime reflection of the variants of a multivector.
+///
+/// Implemented by the generated code for each multivector resource.
+pub trait VariadicReflect: VariadicRefFactory {
+    /// All variants in the order of their type index.
+    const VARIANTS: &'static [VariantInfo];
+}
+
+/// A struct which is one of the variants of the variadic type `Ts`.
+///
+/// Implemented by the generated code for each struct of a multivector, so
+/// that elements of a bucket can be filtered by their type, cf.
+/// [`MultiArrayViewItemIter::iter_variant`].
+///
+/// [`MultiArrayViewItemIter::iter_variant`]: struct.MultiArrayViewItemIter.html#method.iter_variant
+pub trait VariantOf<Ts>: Struct
+where
+    Ts: VariadicRefFactory,
+{
+    /// Returns the referenced struct if `item` is of this variant.
+    fn from_variant<'a>(item: <Ts as VariadicStruct<'a>>::Item) -> Option<&'a Self>;
+}
+
 /// Shortcut trait for VariadicStructs that are able to produce references of
 /// any given lifetime
 ///
@@ -143,6 +356,154 @@ mod test {
         assert_eq!(output, "A { x: 0, y: 0, e: Value }");
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serialize() {
+        let mut a = A::new();
+        a.set_x(1);
+        a.set_y(2);
+        let output = serde_json::to_string(&a).expect("failed to serialize");
+        assert_eq!(output, r#"{"x":1,"y":2,"e":"Value"}"#);
+    }
+
+    #[test]
+    fn test_value() {
+        use crate::test::{AValue, RValue, E};
+        use std::convert::TryFrom;
+
+        let value = AValue {
+            x: 1,
+            y: 0xffff,
+            e: E::Value,
+        };
+        let a = A::try_from(value).expect("value out of range");
+        assert_eq!((a.x(), a.y(), a.e()), (1, 0xffff, E::Value));
+        assert_eq!(AValue::from(&a), value);
+
+        let error = A::try_from(AValue {
+            y: 0x10000,
+            ..value
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "value 65536 of A::y does not fit into 16 bits"
+        );
+
+        let mut vector = crate::Vector::<R>::new();
+        let r = vector.grow();
+        r.set_first_x(1);
+        r.set_y(2);
+        assert_eq!(RValue::from(&*r), RValue { first_x: 1, y: 2 });
+    }
+
+    #[test]
+    fn test_eq_and_hash_ignore_padding() {
+        use crate::SliceExt;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |a: &A| {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // A has 33 bits, the remaining 7 bits of the last byte are padding
+        let bytes = [1, 0, 2, 0, 0b1111_1110, 1, 0, 2, 0, 0, 1, 0, 3, 0, 0];
+        let data = <&[A]>::from_bytes(&bytes).expect("invalid data");
+        assert_eq!(data[0], data[1]);
+        assert_eq!(hash(&data[0]), hash(&data[1]));
+        assert_ne!(data[0], data[2]);
+
+        let set: std::collections::HashSet<_> = data.iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "ord")]
+    fn test_ord() {
+        use crate::test::E;
+
+        let a = |x, y| {
+            let mut a = A::new();
+            a.set_x(x);
+            a.set_y(y);
+            a.set_e(E::Value);
+            a
+        };
+        assert!(a(1, 5) < a(2, 0));
+        assert!(a(1, 5) > a(1, 4));
+        assert_eq!(a(1, 5).cmp(&a(1, 5)), std::cmp::Ordering::Equal);
+
+        let mut values = vec![a(3, 0), a(1, 1), a(1, 0)];
+        values.sort();
+        assert_eq!(values, [a(1, 0), a(1, 1), a(3, 0)]);
+    }
+
+    #[test]
+    fn test_try_set() {
+        let mut a = A::new();
+        a.try_set_x(0xffff).expect("value out of range");
+        assert_eq!(a.x(), 0xffff);
+
+        let error = a.try_set_x(0x10000).unwrap_err();
+        assert_eq!(
+            (
+                error.struct_name,
+                error.field_name,
+                error.value,
+                error.num_bits
+            ),
+            ("A", "x", 0x10000, 16)
+        );
+        assert_eq!(a.x(), 0xffff);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "value 65536 of A::x does not fit into 16 bits")]
+    fn test_set_overflow() {
+        let mut a = A::new();
+        a.set_x(0x10000);
+    }
+
+    #[test]
+    fn test_reflect() {
+        assert_eq!(<A as StructReflect>::NAME, "A");
+        let names: Vec<_> = A::FIELDS.iter().map(|field| field.name).collect();
+        assert_eq!(names, ["x", "y", "e"]);
+        assert_eq!(
+            A::field_info("e"),
+            Some(&FieldInfo {
+                name: "e",
+                primitive_type: "u32",
+                offset: 32,
+                width: 1,
+                is_signed: false,
+                enum_type: Some(&EnumInfo {
+                    name: ".test.E",
+                    variants: &[("Value", 0)],
+                }),
+                invalid_value: None,
+                range: None,
+            })
+        );
+        assert_eq!(A::field_info("z"), None);
+        let e = A::field_info("e").and_then(|x| x.enum_type).unwrap();
+        assert_eq!(e.variant_name(0), Some("Value"));
+        assert_eq!(e.variant_name(1), None);
+        assert_eq!(R::field_info("first_x").and_then(|x| x.range), Some("x"));
+
+        let mut a = A::new();
+        a.set_x(1);
+        a.set_y(0xffff);
+        assert_eq!(a.get_field_u64("x"), Some(1));
+        assert_eq!(a.get_field_u64("y"), Some(0xffff));
+        assert_eq!(a.get_field_u64("e"), Some(0));
+        assert_eq!(a.get_field_u64("z"), None);
+    }
+
     #[test]
     fn test_range() {
         assert_eq!(<R as Struct>::IS_OVERLAPPING_WITH_NEXT, true);
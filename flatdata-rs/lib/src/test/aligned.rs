@@ -0,0 +1,115 @@
+//! The generator emits plain little-endian loads and stores for fields of
+//! native width at byte offsets instead of using `flatdata_read_bytes` and
+//! `flatdata_write_bytes`. Both have to agree for every native type at every
+//! byte offset, and the surrounding fields must neither influence reading nor
+//! be touched by writing. Decoding fields in bulk has to agree with the
+//! getters.
+
+use super::*;
+use crate::{SliceExt, Struct, StructReflect};
+
+/// Contents of structs of `size` bytes used as starting point for reading and
+/// writing.
+fn patterns(size: usize) -> Vec<Vec<u8>> {
+    let ascending = (0..size).map(|index| (index * 17 + 1) as u8).collect();
+    let alternating = (0..size).map(|index| [0x80, 0x7f][index % 2]).collect();
+    vec![vec![0; size], vec![0xff; size], ascending, alternating]
+}
+
+/// Offset and width in bits of a field as stored in the schema.
+fn field<T: StructReflect>(name: &str) -> (usize, usize) {
+    let info = T::FIELDS
+        .iter()
+        .find(|info| info.name == name)
+        .expect("unknown field");
+    (info.offset, info.width)
+}
+
+macro_rules! define_aligned_sweep_test {
+    ($test_name:ident, $struct:ident, $type:tt) => {
+        #[test]
+        fn $test_name() {
+            type Getter = fn(&$struct) -> $type;
+            type Setter = fn(&mut $struct, $type);
+            let fields: [(&str, Getter, Setter); 8] = [
+                ("f0", $struct::f0, $struct::set_f0),
+                ("f1", $struct::f1, $struct::set_f1),
+                ("f2", $struct::f2, $struct::set_f2),
+                ("f3", $struct::f3, $struct::set_f3),
+                ("f4", $struct::f4, $struct::set_f4),
+                ("f5", $struct::f5, $struct::set_f5),
+                ("f6", $struct::f6, $struct::set_f6),
+                ("f7", $struct::f7, $struct::set_f7),
+            ];
+            let values = [
+                $type::MIN,
+                $type::MAX,
+                0,
+                1,
+                $type::MAX / 3,
+                $type::MIN.wrapping_add(1),
+            ];
+            let mut byte_offsets = Vec::new();
+            for &(name, get, set) in fields.iter() {
+                let (offset, width) = field::<$struct>(name);
+                assert_eq!((offset % 8, width), (0, $type::BITS as usize));
+                byte_offsets.push(offset / 8 % 8);
+                for data in patterns($struct::SIZE_IN_BYTES) {
+                    let item = $struct::from_bytes_slice(&data).expect("invalid size");
+                    assert_eq!(
+                        get(item),
+                        flatdata_read_bytes!($type, data.as_ptr(), offset, width),
+                        "field: {}, data: {:?}",
+                        name,
+                        data
+                    );
+                    for &value in values.iter() {
+                        let mut expected = data.clone();
+                        flatdata_write_bytes!($type; value, expected, offset, width);
+                        let mut actual = data.clone();
+                        set(
+                            $struct::from_bytes_slice_mut(&mut actual).expect("invalid size"),
+                            value,
+                        );
+                        assert_eq!(
+                            actual, expected,
+                            "field: {}, value: {}, data: {:?}",
+                            name, value, data
+                        );
+                    }
+                }
+            }
+            byte_offsets.sort_unstable();
+            assert_eq!(byte_offsets, (0..8).collect::<Vec<_>>());
+        }
+    };
+}
+
+define_aligned_sweep_test!(test_aligned_u8, AlignedU8, u8);
+define_aligned_sweep_test!(test_aligned_i8, AlignedI8, i8);
+define_aligned_sweep_test!(test_aligned_u16, AlignedU16, u16);
+define_aligned_sweep_test!(test_aligned_i16, AlignedI16, i16);
+define_aligned_sweep_test!(test_aligned_u32, AlignedU32, u32);
+define_aligned_sweep_test!(test_aligned_i32, AlignedI32, i32);
+define_aligned_sweep_test!(test_aligned_u64, AlignedU64, u64);
+define_aligned_sweep_test!(test_aligned_i64, AlignedI64, i64);
+
+#[test]
+fn test_aligned_decode() {
+    let bytes: Vec<u8> = patterns(Aligned::SIZE_IN_BYTES).concat();
+    let data = <&[Aligned]>::from_bytes(&bytes).expect("failed to read structs");
+    macro_rules! check {
+        ($name:ident, $decode:ident) => {
+            let expected: Vec<_> = data.iter().map(|item| item.$name()).collect();
+            assert_eq!(Aligned::$decode(data), expected);
+        };
+    }
+    check!(a, decode_a);
+    check!(b, decode_b);
+    check!(c, decode_c);
+    check!(d, decode_d);
+    check!(e, decode_e);
+    check!(f, decode_f);
+    check!(g, decode_g);
+    check!(h, decode_h);
+}
@@ -21,4 +21,7 @@
 
 include!("test_generated.rs");
 
+#[cfg(test)]
+mod aligned;
+
 pub use test::*;
@@ -23,6 +23,57 @@ impl crate::Struct for A {
     const IS_OVERLAPPING_WITH_NEXT : bool = false;
 }
 
+impl crate::StructReflect for A {
+    const NAME: &'static str = "A";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "x",
+            primitive_type: "u32",
+            offset: 0,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "y",
+            primitive_type: "u32",
+            offset: 16,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "e",
+            primitive_type: "u32",
+            offset: 32,
+            width: 1,
+            is_signed: false,
+            enum_type: Some(&crate::EnumInfo {
+                name: ".test.E",
+                variants: &[
+                    ("Value", 0),
+                ],
+            }),
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "x" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16) as u64),
+            "y" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16) as u64),
+            "e" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 32, 1) as u64),
+            _ => None,
+        }
+    }
+}
+
 impl A {
     pub fn new( ) -> Self {
         Self{data : [0; 5]}
@@ -80,14 +131,12 @@ unsafe impl crate::NoOverlap for A {}
 impl A {
     #[inline]
     pub fn x(&self) -> u32 {
-        let value = flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16);
-        unsafe { std::mem::transmute::<u32, u32>(value) }
+        flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16)
     }
 
     #[inline]
     pub fn y(&self) -> u32 {
-        let value = flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16);
-        unsafe { std::mem::transmute::<u32, u32>(value) }
+        flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16)
     }
 
     #[inline]
@@ -98,6 +147,165 @@ impl A {
 
 }
 
+impl A {
+    /// Decodes the raw values of the field [`x`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`x`]: #method.x
+    #[inline]
+    pub fn decode_x_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: A is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(5).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[0..2]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0xffff) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`x`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_x_into`].
+    ///
+    /// [`x`]: #method.x
+    /// [`decode_x_into`]: #method.decode_x_into
+    pub fn decode_x(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_x_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`x`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`x`]: #method.x
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_x(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`y`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`y`]: #method.y
+    #[inline]
+    pub fn decode_y_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: A is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(5).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[2..4]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0xffff) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`y`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_y_into`].
+    ///
+    /// [`y`]: #method.y
+    /// [`decode_y_into`]: #method.decode_y_into
+    pub fn decode_y(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_y_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`y`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`y`]: #method.y
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_y(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 16, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`e`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`e`]: #method.e
+    #[inline]
+    pub fn decode_e_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: A is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(5).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[4..5]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0x1) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`e`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_e_into`].
+    ///
+    /// [`e`]: #method.e
+    /// [`decode_e_into`]: #method.decode_e_into
+    pub fn decode_e(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_e_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`e`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`e`]: #method.e
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_e(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 32, 1)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
 impl std::fmt::Debug for A {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.debug_struct("A")
@@ -114,25 +322,167 @@ impl std::cmp::PartialEq for A {
         self.x() == other.x() &&        self.y() == other.y() &&        self.e() == other.e()     }
 }
 
+impl std::cmp::Eq for A {}
+
+impl std::hash::Hash for A {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..4]);
+        state.write_u8(self.data[4] & 0x01);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for A {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_x = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        let raw_y = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 16, 16)
+        };
+        let raw_e = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 32, 1)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_x(self).cmp(&raw_x(other)))
+            .then_with(|| raw_y(self).cmp(&raw_y(other)))
+            .then_with(|| raw_e(self).cmp(&raw_e(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for A {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for A {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("A", 3)?;
+        s.serialize_field("x", &self.x())?;
+        s.serialize_field("y", &self.y())?;
+        s.serialize_field("e", &self.e())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for A {
+    const NAME: &'static str = "A";
+    const FIELDS: &'static [&'static str] = &["x", "y", "e", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_x(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_y(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_e(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for A {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
 impl A {
+    /// Sets the value of [`x`].
+    ///
+    /// [`x`]: #method.x
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_x`] to check it in release builds.
+    ///
+    /// [`try_set_x`]: #method.try_set_x
     #[inline]
     #[allow(missing_docs)]
     pub fn set_x(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of A::x does not fit into 16 bits", value
+        );
         flatdata_write_bytes!(u32; value, self.data, 0, 16)
     }
 
+    /// Sets [`x`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`x`]: #method.x
+    #[inline]
+    pub fn try_set_x(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("A", "x", value, 16)?;
+        self.set_x(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`y`].
+    ///
+    /// [`y`]: #method.y
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_y`] to check it in release builds.
+    ///
+    /// [`try_set_y`]: #method.try_set_y
     #[inline]
     #[allow(missing_docs)]
     pub fn set_y(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of A::y does not fit into 16 bits", value
+        );
         flatdata_write_bytes!(u32; value, self.data, 16, 16)
     }
 
+    /// Sets [`y`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`y`]: #method.y
+    #[inline]
+    pub fn try_set_y(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("A", "y", value, 16)?;
+        self.set_y(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`e`].
+    ///
+    /// [`e`]: #method.e
+    ///
+    /// The value is truncated to 1 bits, which is checked by a debug
+    /// assertion. Use [`try_set_e`] to check it in release builds.
+    ///
+    /// [`try_set_e`]: #method.try_set_e
     #[inline]
     #[allow(missing_docs)]
     pub fn set_e(&mut self, value: super::test::E) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value as u32, 1),
+            "value {} of A::e does not fit into 1 bits", value as u32
+        );
         flatdata_write_bytes!(u32; value, self.data, 32, 1)
     }
 
+    /// Sets [`e`] if the value fits into 1 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`e`]: #method.e
+    #[inline]
+    pub fn try_set_e(&mut self, value: super::test::E) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("A", "e", value as u32, 1)?;
+        self.set_e(value);
+        Ok(())
+    }
 
     /// Copies the data from `other` into this struct.
     #[inline]
@@ -142,6 +492,49 @@ impl A {
         self.set_e(other.e());
     }
 }
+
+/// Owned plain value of [`A`] with a public member per field.
+///
+/// Converted from [`A`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`A`]: struct.A.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AValue {
+    #[allow(missing_docs)]
+    pub x: u32,
+    #[allow(missing_docs)]
+    pub y: u32,
+    #[allow(missing_docs)]
+    pub e: super::test::E,
+}
+
+impl From<&A> for AValue {
+    fn from(other: &A) -> Self {
+        Self {
+            x: other.x(),
+            y: other.y(),
+            e: other.e(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AValue> for A {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AValue) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("A", "x", value.x, 16)?;
+        check_bits("A", "y", value.y, 16)?;
+        check_bits("A", "e", value.e as u32, 1)?;
+        let mut result = Self::new();
+        result.set_x(value.x);
+        result.set_y(value.y);
+        result.set_e(value.e);
+        Ok(result)
+    }
+}
+
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct B {
@@ -164,6 +557,30 @@ impl crate::Struct for B {
     const IS_OVERLAPPING_WITH_NEXT : bool = false;
 }
 
+impl crate::StructReflect for B {
+    const NAME: &'static str = "B";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "id",
+            primitive_type: "u32",
+            offset: 0,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "id" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16) as u64),
+            _ => None,
+        }
+    }
+}
+
 impl B {
     pub fn new( ) -> Self {
         Self{data : [0; 2]}
@@ -221,8 +638,62 @@ unsafe impl crate::NoOverlap for B {}
 impl B {
     #[inline]
     pub fn id(&self) -> u32 {
-        let value = flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16);
-        unsafe { std::mem::transmute::<u32, u32>(value) }
+        flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16)
+    }
+
+}
+
+impl B {
+    /// Decodes the raw values of the field [`id`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`id`]: #method.id
+    #[inline]
+    pub fn decode_id_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: B is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(2).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[0..2]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0xffff) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`id`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_id_into`].
+    ///
+    /// [`id`]: #method.id
+    /// [`decode_id_into`]: #method.decode_id_into
+    pub fn decode_id(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_id_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`id`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`id`]: #method.id
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_id(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
     }
 
 }
@@ -241,108 +712,15180 @@ impl std::cmp::PartialEq for B {
         self.id() == other.id()     }
 }
 
-impl B {
+impl std::cmp::Eq for B {}
+
+impl std::hash::Hash for B {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
     #[inline]
-    #[allow(missing_docs)]
-    pub fn set_id(&mut self, value: u32) {
-        flatdata_write_bytes!(u32; value, self.data, 0, 16)
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..1]);
+        state.write_u8(self.data[1]);
     }
+}
 
+crate::flatdata_ord! {
+impl std::cmp::Ord for B {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_id = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_id(self).cmp(&raw_id(other)))
+    }
+}
 
-    /// Copies the data from `other` into this struct.
+impl std::cmp::PartialOrd for B {
     #[inline]
-    pub fn fill_from(&mut self, other: &B) {
-        self.set_id(other.id());
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
-#[repr(transparent)]
-pub struct R {
-    data: [u8; 4],
 }
 
-impl R {
-    /// Unsafe since the struct might not be self-contained
-    pub unsafe fn new_unchecked( ) -> Self {
-        Self{data : [0; 4]}
+crate::flatdata_serde! {
+impl crate::serde::Serialize for B {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("B", 1)?;
+        s.serialize_field("id", &self.id())?;
+        s.end()
     }
 }
 
-impl crate::Struct for R {
-    unsafe fn create_unchecked( ) -> Self {
-        Self{data : [0; 4]}
-    }
+impl crate::de::DeserializeFields for B {
+    const NAME: &'static str = "B";
+    const FIELDS: &'static [&'static str] = &["id", ];
 
-    const SIZE_IN_BYTES: usize = 4;
-    const IS_OVERLAPPING_WITH_NEXT : bool = true;
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_id(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
 }
 
-impl crate::Overlap for R {}
+impl<'de> crate::serde::Deserialize<'de> for B {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
 
-impl R {
-    /// First element of the range [`x`].
+impl B {
+    /// Sets the value of [`id`].
     ///
-    /// [`x`]: #method.x
+    /// [`id`]: #method.id
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_id`] to check it in release builds.
+    ///
+    /// [`try_set_id`]: #method.try_set_id
     #[inline]
-    pub fn first_x(&self) -> u32 {
-        let value = flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16);
-        unsafe { std::mem::transmute::<u32, u32>(value) }
+    #[allow(missing_docs)]
+    pub fn set_id(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of B::id does not fit into 16 bits", value
+        );
+        flatdata_write_bytes!(u32; value, self.data, 0, 16)
     }
 
+    /// Sets [`id`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`id`]: #method.id
     #[inline]
-    pub fn x(&self) -> std::ops::Range<u32> {
-        let start = flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16);
-        let end = flatdata_read_bytes!(u32, self.data.as_ptr(), 0 + 4 * 8, 16);
-        start..end
+    pub fn try_set_id(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("B", "id", value, 16)?;
+        self.set_id(value);
+        Ok(())
     }
 
+    /// Copies the data from `other` into this struct.
     #[inline]
-    pub fn y(&self) -> u32 {
-        let value = flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16);
-        unsafe { std::mem::transmute::<u32, u32>(value) }
+    pub fn fill_from(&mut self, other: &B) {
+        self.set_id(other.id());
     }
+}
 
+/// Owned plain value of [`B`] with a public member per field.
+///
+/// Converted from [`B`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`B`]: struct.B.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BValue {
+    #[allow(missing_docs)]
+    pub id: u32,
 }
 
-impl std::fmt::Debug for R {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        f.debug_struct("R")
-            .field("first_x", &self.first_x())
-            .field("y", &self.y())
-            .finish()
+impl From<&B> for BValue {
+    fn from(other: &B) -> Self {
+        Self {
+            id: other.id(),
+        }
     }
 }
 
-impl std::cmp::PartialEq for R {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.first_x() == other.first_x() &&        self.y() == other.y()     }
+impl std::convert::TryFrom<BValue> for B {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: BValue) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("B", "id", value.id, 16)?;
+        let mut result = Self::new();
+        result.set_id(value.id);
+        Ok(result)
+    }
 }
 
-impl R {
-    /// First element of the range [`x`].
-    ///
-    /// [`x`]: struct.RRef.html#method.x
-    #[inline]
-    #[allow(missing_docs)]
-    pub fn set_first_x(&mut self, value: u32) {
-        flatdata_write_bytes!(u32; value, self.data, 0, 16)
+/// bit manipulation, followed by fields which are not
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct Aligned {
+    data: [u8; 29],
+}
+
+impl Aligned {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 29]}
     }
+}
 
-    #[inline]
-    #[allow(missing_docs)]
-    pub fn set_y(&mut self, value: u32) {
-        flatdata_write_bytes!(u32; value, self.data, 16, 16)
+impl crate::Struct for Aligned {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 29]}
     }
 
+    const SIZE_IN_BYTES: usize = 29;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for Aligned {
+    const NAME: &'static str = "Aligned";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "a",
+            primitive_type: "u8",
+            offset: 0,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "b",
+            primitive_type: "i16",
+            offset: 8,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "c",
+            primitive_type: "u32",
+            offset: 24,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "d",
+            primitive_type: "i64",
+            offset: 56,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "e",
+            primitive_type: "u16",
+            offset: 120,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f",
+            primitive_type: "bool",
+            offset: 136,
+            width: 1,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "g",
+            primitive_type: "i32",
+            offset: 137,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "h",
+            primitive_type: "u64",
+            offset: 169,
+            width: 63,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
 
-    /// Copies the data from `other` into this struct.
     #[inline]
-    pub fn fill_from(&mut self, other: &R) {
-        self.set_first_x(other.first_x());
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "a" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 0, 8) as u64),
+            "b" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 8, 16) as u64),
+            "c" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 24, 32) as u64),
+            "d" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 56, 64) as u64),
+            "e" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 120, 16) as u64),
+            "f" => Some(flatdata_read_bytes!(bool, self.data.as_ptr(), 136, 1) as u64),
+            "g" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 137, 32) as u64),
+            "h" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 169, 63)),
+            _ => None,
+        }
+    }
+}
+
+impl Aligned {
+    pub fn new( ) -> Self {
+        Self{data : [0; 29]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 29]) -> &Self {
+        // Safety: This is safe since Aligned is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 29]) -> &mut Self {
+        // Safety: This is safe since Aligned is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 29 {
+            assert_eq!(data.len(), 29);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 29];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 29 {
+            assert_eq!(data.len(), 29);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 29];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 29] {
+        &self.data
+    }
+}
+
+impl Default for Aligned {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for Aligned {}
+
+impl Aligned {
+    #[inline]
+    pub fn a(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[0..1]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn b(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[1..3]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn c(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[3..7]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn d(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[7..15]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn e(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[15..17]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f(&self) -> bool {
+        flatdata_read_bytes!(bool, self.data.as_ptr(), 136, 1)
+    }
+
+    #[inline]
+    pub fn g(&self) -> i32 {
+        flatdata_read_bytes!(i32, self.data.as_ptr(), 137, 32)
+    }
+
+    #[inline]
+    pub fn h(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 169, 63)
+    }
+
+}
+
+impl Aligned {
+    /// Decodes the raw values of the field [`a`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`a`]: #method.a
+    #[inline]
+    pub fn decode_a_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[0..1]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`a`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_a_into`].
+    ///
+    /// [`a`]: #method.a
+    /// [`decode_a_into`]: #method.decode_a_into
+    pub fn decode_a(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_a_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`a`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`a`]: #method.a
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_a(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`b`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`b`]: #method.b
+    #[inline]
+    pub fn decode_b_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[1..3]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`b`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_b_into`].
+    ///
+    /// [`b`]: #method.b
+    /// [`decode_b_into`]: #method.decode_b_into
+    pub fn decode_b(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_b_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`b`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`b`]: #method.b
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_b(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[1..3]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`c`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`c`]: #method.c
+    #[inline]
+    pub fn decode_c_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[3..7]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`c`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_c_into`].
+    ///
+    /// [`c`]: #method.c
+    /// [`decode_c_into`]: #method.decode_c_into
+    pub fn decode_c(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_c_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`c`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`c`]: #method.c
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_c(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[3..7]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`d`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`d`]: #method.d
+    #[inline]
+    pub fn decode_d_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[7..15]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`d`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_d_into`].
+    ///
+    /// [`d`]: #method.d
+    /// [`decode_d_into`]: #method.decode_d_into
+    pub fn decode_d(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_d_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`d`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`d`]: #method.d
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_d(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[7..15]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`e`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`e`]: #method.e
+    #[inline]
+    pub fn decode_e_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[15..17]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`e`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_e_into`].
+    ///
+    /// [`e`]: #method.e
+    /// [`decode_e_into`]: #method.decode_e_into
+    pub fn decode_e(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_e_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`e`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`e`]: #method.e
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_e(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[15..17]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f`]: #method.f
+    #[inline]
+    pub fn decode_f_into(data: &[Self], out: &mut [bool]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            *value = chunk[17] & 1 != 0;
+        }
+    }
+
+    /// Decodes the raw values of the field [`f`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f_into`].
+    ///
+    /// [`f`]: #method.f
+    /// [`decode_f_into`]: #method.decode_f_into
+    pub fn decode_f(data: &[Self]) -> Vec<bool> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f`]: #method.f
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f(data: &[Self]) -> bool {
+        let value = |item: &Self| -> bool {
+            flatdata_read_bytes!(bool, item.data.as_ptr(), 136, 1)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`g`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`g`]: #method.g
+    #[inline]
+    pub fn decode_g_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[17..22]);
+            let raw = u64::from_le_bytes(window) >> 1;
+            *value = raw as i32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`g`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_g_into`].
+    ///
+    /// [`g`]: #method.g
+    /// [`decode_g_into`]: #method.decode_g_into
+    pub fn decode_g(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_g_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`g`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`g`]: #method.g
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_g(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            flatdata_read_bytes!(i32, item.data.as_ptr(), 137, 32)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`h`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`h`]: #method.h
+    #[inline]
+    pub fn decode_h_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: Aligned is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(29).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..8].copy_from_slice(&chunk[21..29]);
+            let raw = u64::from_le_bytes(window) >> 1;
+            *value = raw & 0x7fffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`h`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_h_into`].
+    ///
+    /// [`h`]: #method.h
+    /// [`decode_h_into`]: #method.decode_h_into
+    pub fn decode_h(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_h_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`h`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`h`]: #method.h
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_h(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 169, 63)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for Aligned {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Aligned")
+            .field("a", &self.a())
+            .field("b", &self.b())
+            .field("c", &self.c())
+            .field("d", &self.d())
+            .field("e", &self.e())
+            .field("f", &self.f())
+            .field("g", &self.g())
+            .field("h", &self.h())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for Aligned {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.a() == other.a() &&        self.b() == other.b() &&        self.c() == other.c() &&        self.d() == other.d() &&        self.e() == other.e() &&        self.f() == other.f() &&        self.g() == other.g() &&        self.h() == other.h()     }
+}
+
+impl std::cmp::Eq for Aligned {}
+
+impl std::hash::Hash for Aligned {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..28]);
+        state.write_u8(self.data[28]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for Aligned {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_a = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_b = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[1..3]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_c = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[3..7]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_d = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[7..15]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_e = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[15..17]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_f = |item: &Self| -> bool {
+            flatdata_read_bytes!(bool, item.data.as_ptr(), 136, 1)
+        };
+        let raw_g = |item: &Self| -> i32 {
+            flatdata_read_bytes!(i32, item.data.as_ptr(), 137, 32)
+        };
+        let raw_h = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 169, 63)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_a(self).cmp(&raw_a(other)))
+            .then_with(|| raw_b(self).cmp(&raw_b(other)))
+            .then_with(|| raw_c(self).cmp(&raw_c(other)))
+            .then_with(|| raw_d(self).cmp(&raw_d(other)))
+            .then_with(|| raw_e(self).cmp(&raw_e(other)))
+            .then_with(|| raw_f(self).cmp(&raw_f(other)))
+            .then_with(|| raw_g(self).cmp(&raw_g(other)))
+            .then_with(|| raw_h(self).cmp(&raw_h(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for Aligned {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for Aligned {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("Aligned", 8)?;
+        s.serialize_field("a", &self.a())?;
+        s.serialize_field("b", &self.b())?;
+        s.serialize_field("c", &self.c())?;
+        s.serialize_field("d", &self.d())?;
+        s.serialize_field("e", &self.e())?;
+        s.serialize_field("f", &self.f())?;
+        s.serialize_field("g", &self.g())?;
+        s.serialize_field("h", &self.h())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for Aligned {
+    const NAME: &'static str = "Aligned";
+    const FIELDS: &'static [&'static str] = &["a", "b", "c", "d", "e", "f", "g", "h", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_a(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_b(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_c(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_d(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_e(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_f(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_g(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_h(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for Aligned {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl Aligned {
+    /// Sets the value of [`a`].
+    ///
+    /// [`a`]: #method.a
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_a`] to check it in release builds.
+    ///
+    /// [`try_set_a`]: #method.try_set_a
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_a(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of Aligned::a does not fit into 8 bits", value
+        );
+        self.data[0..1].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`a`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`a`]: #method.a
+    #[inline]
+    pub fn try_set_a(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "a", value, 8)?;
+        self.set_a(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`b`].
+    ///
+    /// [`b`]: #method.b
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_b`] to check it in release builds.
+    ///
+    /// [`try_set_b`]: #method.try_set_b
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_b(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of Aligned::b does not fit into 16 bits", value
+        );
+        self.data[1..3].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`b`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`b`]: #method.b
+    #[inline]
+    pub fn try_set_b(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "b", value, 16)?;
+        self.set_b(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`c`].
+    ///
+    /// [`c`]: #method.c
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_c`] to check it in release builds.
+    ///
+    /// [`try_set_c`]: #method.try_set_c
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_c(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of Aligned::c does not fit into 32 bits", value
+        );
+        self.data[3..7].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`c`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`c`]: #method.c
+    #[inline]
+    pub fn try_set_c(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "c", value, 32)?;
+        self.set_c(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`d`].
+    ///
+    /// [`d`]: #method.d
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_d`] to check it in release builds.
+    ///
+    /// [`try_set_d`]: #method.try_set_d
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_d(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of Aligned::d does not fit into 64 bits", value
+        );
+        self.data[7..15].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`d`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`d`]: #method.d
+    #[inline]
+    pub fn try_set_d(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "d", value, 64)?;
+        self.set_d(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`e`].
+    ///
+    /// [`e`]: #method.e
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_e`] to check it in release builds.
+    ///
+    /// [`try_set_e`]: #method.try_set_e
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_e(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of Aligned::e does not fit into 16 bits", value
+        );
+        self.data[15..17].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`e`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`e`]: #method.e
+    #[inline]
+    pub fn try_set_e(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "e", value, 16)?;
+        self.set_e(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f`].
+    ///
+    /// [`f`]: #method.f
+    ///
+    /// The value is truncated to 1 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f`] to check it in release builds.
+    ///
+    /// [`try_set_f`]: #method.try_set_f
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f(&mut self, value: bool) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 1),
+            "value {} of Aligned::f does not fit into 1 bits", value
+        );
+        flatdata_write_bytes!(bool; value, self.data, 136, 1)
+    }
+
+    /// Sets [`f`] if the value fits into 1 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f`]: #method.f
+    #[inline]
+    pub fn try_set_f(&mut self, value: bool) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "f", value, 1)?;
+        self.set_f(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`g`].
+    ///
+    /// [`g`]: #method.g
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_g`] to check it in release builds.
+    ///
+    /// [`try_set_g`]: #method.try_set_g
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_g(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of Aligned::g does not fit into 32 bits", value
+        );
+        flatdata_write_bytes!(i32; value, self.data, 137, 32)
+    }
+
+    /// Sets [`g`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`g`]: #method.g
+    #[inline]
+    pub fn try_set_g(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "g", value, 32)?;
+        self.set_g(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`h`].
+    ///
+    /// [`h`]: #method.h
+    ///
+    /// The value is truncated to 63 bits, which is checked by a debug
+    /// assertion. Use [`try_set_h`] to check it in release builds.
+    ///
+    /// [`try_set_h`]: #method.try_set_h
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_h(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 63),
+            "value {} of Aligned::h does not fit into 63 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 169, 63)
+    }
+
+    /// Sets [`h`] if the value fits into 63 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`h`]: #method.h
+    #[inline]
+    pub fn try_set_h(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("Aligned", "h", value, 63)?;
+        self.set_h(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &Aligned) {
+        self.set_a(other.a());
+        self.set_b(other.b());
+        self.set_c(other.c());
+        self.set_d(other.d());
+        self.set_e(other.e());
+        self.set_f(other.f());
+        self.set_g(other.g());
+        self.set_h(other.h());
+    }
+}
+
+/// Owned plain value of [`Aligned`] with a public member per field.
+///
+/// Converted from [`Aligned`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`Aligned`]: struct.Aligned.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedValue {
+    #[allow(missing_docs)]
+    pub a: u8,
+    #[allow(missing_docs)]
+    pub b: i16,
+    #[allow(missing_docs)]
+    pub c: u32,
+    #[allow(missing_docs)]
+    pub d: i64,
+    #[allow(missing_docs)]
+    pub e: u16,
+    #[allow(missing_docs)]
+    pub f: bool,
+    #[allow(missing_docs)]
+    pub g: i32,
+    #[allow(missing_docs)]
+    pub h: u64,
+}
+
+impl From<&Aligned> for AlignedValue {
+    fn from(other: &Aligned) -> Self {
+        Self {
+            a: other.a(),
+            b: other.b(),
+            c: other.c(),
+            d: other.d(),
+            e: other.e(),
+            f: other.f(),
+            g: other.g(),
+            h: other.h(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedValue> for Aligned {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedValue) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("Aligned", "a", value.a, 8)?;
+        check_bits("Aligned", "b", value.b, 16)?;
+        check_bits("Aligned", "c", value.c, 32)?;
+        check_bits("Aligned", "d", value.d, 64)?;
+        check_bits("Aligned", "e", value.e, 16)?;
+        check_bits("Aligned", "f", value.f, 1)?;
+        check_bits("Aligned", "g", value.g, 32)?;
+        check_bits("Aligned", "h", value.h, 63)?;
+        let mut result = Self::new();
+        result.set_a(value.a);
+        result.set_b(value.b);
+        result.set_c(value.c);
+        result.set_d(value.d);
+        result.set_e(value.e);
+        result.set_f(value.f);
+        result.set_g(value.g);
+        result.set_h(value.h);
+        Ok(result)
+    }
+}
+
+/// `u8` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedU8 {
+    data: [u8; 8],
+}
+
+impl AlignedU8 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 8]}
+    }
+}
+
+impl crate::Struct for AlignedU8 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 8]}
+    }
+
+    const SIZE_IN_BYTES: usize = 8;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedU8 {
+    const NAME: &'static str = "AlignedU8";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "u8",
+            offset: 0,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "u8",
+            offset: 8,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "u8",
+            offset: 16,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "u8",
+            offset: 24,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "u8",
+            offset: 32,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "u8",
+            offset: 40,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "u8",
+            offset: 48,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "u8",
+            offset: 56,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 0, 8) as u64),
+            "f1" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 8, 8) as u64),
+            "f2" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 16, 8) as u64),
+            "f3" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 24, 8) as u64),
+            "f4" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 32, 8) as u64),
+            "f5" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 40, 8) as u64),
+            "f6" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 48, 8) as u64),
+            "f7" => Some(flatdata_read_bytes!(u8, self.data.as_ptr(), 56, 8) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedU8 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 8]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 8]) -> &Self {
+        // Safety: This is safe since AlignedU8 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 8]) -> &mut Self {
+        // Safety: This is safe since AlignedU8 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 8 {
+            assert_eq!(data.len(), 8);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 8];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 8 {
+            assert_eq!(data.len(), 8);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 8];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 8] {
+        &self.data
+    }
+}
+
+impl Default for AlignedU8 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedU8 {}
+
+impl AlignedU8 {
+    #[inline]
+    pub fn f0(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[0..1]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[1..2]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[2..3]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[3..4]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[4..5]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[5..6]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[6..7]);
+        u8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> u8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[7..8]);
+        u8::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedU8 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[0..1]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[1..2]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[1..2]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[2..3]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[2..3]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[3..4]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[3..4]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[4..5]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[4..5]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[5..6]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[5..6]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[6..7]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[6..7]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [u8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[7..8]);
+            *value = u8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<u8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[7..8]);
+            u8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedU8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedU8")
+            .field("f0", &self.f0())
+            .field("f1", &self.f1())
+            .field("f2", &self.f2())
+            .field("f3", &self.f3())
+            .field("f4", &self.f4())
+            .field("f5", &self.f5())
+            .field("f6", &self.f6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedU8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.f1() == other.f1() &&        self.f2() == other.f2() &&        self.f3() == other.f3() &&        self.f4() == other.f4() &&        self.f5() == other.f5() &&        self.f6() == other.f6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedU8 {}
+
+impl std::hash::Hash for AlignedU8 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..7]);
+        state.write_u8(self.data[7]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedU8 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f1 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[1..2]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f2 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[2..3]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f3 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[3..4]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f4 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[4..5]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f5 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[5..6]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f6 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[6..7]);
+            u8::from_le_bytes(bytes)
+        };
+        let raw_f7 = |item: &Self| -> u8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[7..8]);
+            u8::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedU8 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedU8 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedU8", 8)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedU8 {
+    const NAME: &'static str = "AlignedU8";
+    const FIELDS: &'static [&'static str] = &["f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedU8 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedU8 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f0 does not fit into 8 bits", value
+        );
+        self.data[0..1].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f0", value, 8)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f1 does not fit into 8 bits", value
+        );
+        self.data[1..2].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f1", value, 8)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f2 does not fit into 8 bits", value
+        );
+        self.data[2..3].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f2", value, 8)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f3 does not fit into 8 bits", value
+        );
+        self.data[3..4].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f3", value, 8)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f4 does not fit into 8 bits", value
+        );
+        self.data[4..5].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f4", value, 8)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f5 does not fit into 8 bits", value
+        );
+        self.data[5..6].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f5", value, 8)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f6 does not fit into 8 bits", value
+        );
+        self.data[6..7].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f6", value, 8)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: u8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU8::f7 does not fit into 8 bits", value
+        );
+        self.data[7..8].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: u8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU8", "f7", value, 8)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedU8) {
+        self.set_f0(other.f0());
+        self.set_f1(other.f1());
+        self.set_f2(other.f2());
+        self.set_f3(other.f3());
+        self.set_f4(other.f4());
+        self.set_f5(other.f5());
+        self.set_f6(other.f6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedU8`] with a public member per field.
+///
+/// Converted from [`AlignedU8`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedU8`]: struct.AlignedU8.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedU8Value {
+    #[allow(missing_docs)]
+    pub f0: u8,
+    #[allow(missing_docs)]
+    pub f1: u8,
+    #[allow(missing_docs)]
+    pub f2: u8,
+    #[allow(missing_docs)]
+    pub f3: u8,
+    #[allow(missing_docs)]
+    pub f4: u8,
+    #[allow(missing_docs)]
+    pub f5: u8,
+    #[allow(missing_docs)]
+    pub f6: u8,
+    #[allow(missing_docs)]
+    pub f7: u8,
+}
+
+impl From<&AlignedU8> for AlignedU8Value {
+    fn from(other: &AlignedU8) -> Self {
+        Self {
+            f0: other.f0(),
+            f1: other.f1(),
+            f2: other.f2(),
+            f3: other.f3(),
+            f4: other.f4(),
+            f5: other.f5(),
+            f6: other.f6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedU8Value> for AlignedU8 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedU8Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedU8", "f0", value.f0, 8)?;
+        check_bits("AlignedU8", "f1", value.f1, 8)?;
+        check_bits("AlignedU8", "f2", value.f2, 8)?;
+        check_bits("AlignedU8", "f3", value.f3, 8)?;
+        check_bits("AlignedU8", "f4", value.f4, 8)?;
+        check_bits("AlignedU8", "f5", value.f5, 8)?;
+        check_bits("AlignedU8", "f6", value.f6, 8)?;
+        check_bits("AlignedU8", "f7", value.f7, 8)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_f1(value.f1);
+        result.set_f2(value.f2);
+        result.set_f3(value.f3);
+        result.set_f4(value.f4);
+        result.set_f5(value.f5);
+        result.set_f6(value.f6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `i8` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedI8 {
+    data: [u8; 8],
+}
+
+impl AlignedI8 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 8]}
+    }
+}
+
+impl crate::Struct for AlignedI8 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 8]}
+    }
+
+    const SIZE_IN_BYTES: usize = 8;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedI8 {
+    const NAME: &'static str = "AlignedI8";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "i8",
+            offset: 0,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "i8",
+            offset: 8,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "i8",
+            offset: 16,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "i8",
+            offset: 24,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "i8",
+            offset: 32,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "i8",
+            offset: 40,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "i8",
+            offset: 48,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "i8",
+            offset: 56,
+            width: 8,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 0, 8) as u64),
+            "f1" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 8, 8) as u64),
+            "f2" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 16, 8) as u64),
+            "f3" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 24, 8) as u64),
+            "f4" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 32, 8) as u64),
+            "f5" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 40, 8) as u64),
+            "f6" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 48, 8) as u64),
+            "f7" => Some(flatdata_read_bytes!(i8, self.data.as_ptr(), 56, 8) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedI8 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 8]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 8]) -> &Self {
+        // Safety: This is safe since AlignedI8 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 8]) -> &mut Self {
+        // Safety: This is safe since AlignedI8 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 8 {
+            assert_eq!(data.len(), 8);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 8];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 8 {
+            assert_eq!(data.len(), 8);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 8];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 8] {
+        &self.data
+    }
+}
+
+impl Default for AlignedI8 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedI8 {}
+
+impl AlignedI8 {
+    #[inline]
+    pub fn f0(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[0..1]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[1..2]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[2..3]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[3..4]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[4..5]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[5..6]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[6..7]);
+        i8::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> i8 {
+        let mut bytes = [0; 1];
+        bytes.copy_from_slice(&self.data[7..8]);
+        i8::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedI8 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[0..1]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[1..2]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[1..2]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[2..3]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[2..3]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[3..4]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[3..4]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[4..5]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[4..5]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[5..6]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[5..6]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[6..7]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[6..7]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [i8]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI8 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(8).zip(out.iter_mut()) {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&chunk[7..8]);
+            *value = i8::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<i8> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[7..8]);
+            i8::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedI8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedI8")
+            .field("f0", &self.f0())
+            .field("f1", &self.f1())
+            .field("f2", &self.f2())
+            .field("f3", &self.f3())
+            .field("f4", &self.f4())
+            .field("f5", &self.f5())
+            .field("f6", &self.f6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedI8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.f1() == other.f1() &&        self.f2() == other.f2() &&        self.f3() == other.f3() &&        self.f4() == other.f4() &&        self.f5() == other.f5() &&        self.f6() == other.f6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedI8 {}
+
+impl std::hash::Hash for AlignedI8 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..7]);
+        state.write_u8(self.data[7]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedI8 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[0..1]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f1 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[1..2]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f2 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[2..3]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f3 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[3..4]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f4 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[4..5]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f5 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[5..6]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f6 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[6..7]);
+            i8::from_le_bytes(bytes)
+        };
+        let raw_f7 = |item: &Self| -> i8 {
+            let mut bytes = [0; 1];
+            bytes.copy_from_slice(&item.data[7..8]);
+            i8::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedI8 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedI8 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedI8", 8)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedI8 {
+    const NAME: &'static str = "AlignedI8";
+    const FIELDS: &'static [&'static str] = &["f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedI8 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedI8 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f0 does not fit into 8 bits", value
+        );
+        self.data[0..1].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f0", value, 8)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f1 does not fit into 8 bits", value
+        );
+        self.data[1..2].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f1", value, 8)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f2 does not fit into 8 bits", value
+        );
+        self.data[2..3].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f2", value, 8)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f3 does not fit into 8 bits", value
+        );
+        self.data[3..4].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f3", value, 8)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f4 does not fit into 8 bits", value
+        );
+        self.data[4..5].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f4", value, 8)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f5 does not fit into 8 bits", value
+        );
+        self.data[5..6].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f5", value, 8)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f6 does not fit into 8 bits", value
+        );
+        self.data[6..7].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f6", value, 8)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: i8) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI8::f7 does not fit into 8 bits", value
+        );
+        self.data[7..8].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: i8) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI8", "f7", value, 8)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedI8) {
+        self.set_f0(other.f0());
+        self.set_f1(other.f1());
+        self.set_f2(other.f2());
+        self.set_f3(other.f3());
+        self.set_f4(other.f4());
+        self.set_f5(other.f5());
+        self.set_f6(other.f6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedI8`] with a public member per field.
+///
+/// Converted from [`AlignedI8`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedI8`]: struct.AlignedI8.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedI8Value {
+    #[allow(missing_docs)]
+    pub f0: i8,
+    #[allow(missing_docs)]
+    pub f1: i8,
+    #[allow(missing_docs)]
+    pub f2: i8,
+    #[allow(missing_docs)]
+    pub f3: i8,
+    #[allow(missing_docs)]
+    pub f4: i8,
+    #[allow(missing_docs)]
+    pub f5: i8,
+    #[allow(missing_docs)]
+    pub f6: i8,
+    #[allow(missing_docs)]
+    pub f7: i8,
+}
+
+impl From<&AlignedI8> for AlignedI8Value {
+    fn from(other: &AlignedI8) -> Self {
+        Self {
+            f0: other.f0(),
+            f1: other.f1(),
+            f2: other.f2(),
+            f3: other.f3(),
+            f4: other.f4(),
+            f5: other.f5(),
+            f6: other.f6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedI8Value> for AlignedI8 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedI8Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedI8", "f0", value.f0, 8)?;
+        check_bits("AlignedI8", "f1", value.f1, 8)?;
+        check_bits("AlignedI8", "f2", value.f2, 8)?;
+        check_bits("AlignedI8", "f3", value.f3, 8)?;
+        check_bits("AlignedI8", "f4", value.f4, 8)?;
+        check_bits("AlignedI8", "f5", value.f5, 8)?;
+        check_bits("AlignedI8", "f6", value.f6, 8)?;
+        check_bits("AlignedI8", "f7", value.f7, 8)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_f1(value.f1);
+        result.set_f2(value.f2);
+        result.set_f3(value.f3);
+        result.set_f4(value.f4);
+        result.set_f5(value.f5);
+        result.set_f6(value.f6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `u16` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedU16 {
+    data: [u8; 65],
+}
+
+impl AlignedU16 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 65]}
+    }
+}
+
+impl crate::Struct for AlignedU16 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 65]}
+    }
+
+    const SIZE_IN_BYTES: usize = 65;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedU16 {
+    const NAME: &'static str = "AlignedU16";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "u16",
+            offset: 0,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 16,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "u16",
+            offset: 72,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 88,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "u16",
+            offset: 144,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 160,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "u16",
+            offset: 216,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 232,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "u16",
+            offset: 288,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 304,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "u16",
+            offset: 360,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 376,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "u16",
+            offset: 432,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 448,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "u16",
+            offset: 504,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 0, 16) as u64),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 16, 56)),
+            "f1" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 72, 16) as u64),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 88, 56)),
+            "f2" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 144, 16) as u64),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 160, 56)),
+            "f3" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 216, 16) as u64),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 232, 56)),
+            "f4" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 288, 16) as u64),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 304, 56)),
+            "f5" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 360, 16) as u64),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 376, 56)),
+            "f6" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 432, 16) as u64),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 448, 56)),
+            "f7" => Some(flatdata_read_bytes!(u16, self.data.as_ptr(), 504, 16) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedU16 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 65]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 65]) -> &Self {
+        // Safety: This is safe since AlignedU16 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 65]) -> &mut Self {
+        // Safety: This is safe since AlignedU16 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 65 {
+            assert_eq!(data.len(), 65);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 65];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 65 {
+            assert_eq!(data.len(), 65);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 65];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 65] {
+        &self.data
+    }
+}
+
+impl Default for AlignedU16 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedU16 {}
+
+impl AlignedU16 {
+    #[inline]
+    pub fn f0(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[0..2]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 16, 56)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[9..11]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 88, 56)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[18..20]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 160, 56)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[27..29]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 232, 56)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[36..38]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 304, 56)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[45..47]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 376, 56)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[54..56]);
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 448, 56)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> u16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[63..65]);
+        u16::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedU16 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[0..2]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[0..2]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[2..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 16, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[9..11]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[9..11]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[11..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 88, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[18..20]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[18..20]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[20..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 160, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[27..29]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[27..29]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[29..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 232, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[36..38]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[36..38]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[38..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 304, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[45..47]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[45..47]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[47..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 376, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[54..56]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[54..56]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[56..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 448, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [u16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[63..65]);
+            *value = u16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<u16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[63..65]);
+            u16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedU16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedU16")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedU16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedU16 {}
+
+impl std::hash::Hash for AlignedU16 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..64]);
+        state.write_u8(self.data[64]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedU16 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[0..2]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 16, 56)
+        };
+        let raw_f1 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[9..11]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 88, 56)
+        };
+        let raw_f2 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[18..20]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 160, 56)
+        };
+        let raw_f3 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[27..29]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 232, 56)
+        };
+        let raw_f4 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[36..38]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 304, 56)
+        };
+        let raw_f5 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[45..47]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 376, 56)
+        };
+        let raw_f6 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[54..56]);
+            u16::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 448, 56)
+        };
+        let raw_f7 = |item: &Self| -> u16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[63..65]);
+            u16::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedU16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedU16 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedU16", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedU16 {
+    const NAME: &'static str = "AlignedU16";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedU16 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedU16 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f0 does not fit into 16 bits", value
+        );
+        self.data[0..2].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f0", value, 16)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p0 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 16, 56)
+    }
+
+    /// Sets [`p0`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p0", value, 56)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f1 does not fit into 16 bits", value
+        );
+        self.data[9..11].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f1", value, 16)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p1 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 88, 56)
+    }
+
+    /// Sets [`p1`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p1", value, 56)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f2 does not fit into 16 bits", value
+        );
+        self.data[18..20].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f2", value, 16)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p2 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 160, 56)
+    }
+
+    /// Sets [`p2`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p2", value, 56)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f3 does not fit into 16 bits", value
+        );
+        self.data[27..29].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f3", value, 16)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p3 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 232, 56)
+    }
+
+    /// Sets [`p3`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p3", value, 56)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f4 does not fit into 16 bits", value
+        );
+        self.data[36..38].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f4", value, 16)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p4 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 304, 56)
+    }
+
+    /// Sets [`p4`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p4", value, 56)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f5 does not fit into 16 bits", value
+        );
+        self.data[45..47].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f5", value, 16)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p5 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 376, 56)
+    }
+
+    /// Sets [`p5`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p5", value, 56)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f6 does not fit into 16 bits", value
+        );
+        self.data[54..56].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f6", value, 16)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedU16::p6 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 448, 56)
+    }
+
+    /// Sets [`p6`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "p6", value, 56)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: u16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedU16::f7 does not fit into 16 bits", value
+        );
+        self.data[63..65].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: u16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU16", "f7", value, 16)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedU16) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedU16`] with a public member per field.
+///
+/// Converted from [`AlignedU16`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedU16`]: struct.AlignedU16.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedU16Value {
+    #[allow(missing_docs)]
+    pub f0: u16,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: u16,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: u16,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: u16,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: u16,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: u16,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: u16,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: u16,
+}
+
+impl From<&AlignedU16> for AlignedU16Value {
+    fn from(other: &AlignedU16) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedU16Value> for AlignedU16 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedU16Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedU16", "f0", value.f0, 16)?;
+        check_bits("AlignedU16", "p0", value.p0, 56)?;
+        check_bits("AlignedU16", "f1", value.f1, 16)?;
+        check_bits("AlignedU16", "p1", value.p1, 56)?;
+        check_bits("AlignedU16", "f2", value.f2, 16)?;
+        check_bits("AlignedU16", "p2", value.p2, 56)?;
+        check_bits("AlignedU16", "f3", value.f3, 16)?;
+        check_bits("AlignedU16", "p3", value.p3, 56)?;
+        check_bits("AlignedU16", "f4", value.f4, 16)?;
+        check_bits("AlignedU16", "p4", value.p4, 56)?;
+        check_bits("AlignedU16", "f5", value.f5, 16)?;
+        check_bits("AlignedU16", "p5", value.p5, 56)?;
+        check_bits("AlignedU16", "f6", value.f6, 16)?;
+        check_bits("AlignedU16", "p6", value.p6, 56)?;
+        check_bits("AlignedU16", "f7", value.f7, 16)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `i16` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedI16 {
+    data: [u8; 65],
+}
+
+impl AlignedI16 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 65]}
+    }
+}
+
+impl crate::Struct for AlignedI16 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 65]}
+    }
+
+    const SIZE_IN_BYTES: usize = 65;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedI16 {
+    const NAME: &'static str = "AlignedI16";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "i16",
+            offset: 0,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 16,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "i16",
+            offset: 72,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 88,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "i16",
+            offset: 144,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 160,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "i16",
+            offset: 216,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 232,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "i16",
+            offset: 288,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 304,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "i16",
+            offset: 360,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 376,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "i16",
+            offset: 432,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 448,
+            width: 56,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "i16",
+            offset: 504,
+            width: 16,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 0, 16) as u64),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 16, 56)),
+            "f1" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 72, 16) as u64),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 88, 56)),
+            "f2" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 144, 16) as u64),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 160, 56)),
+            "f3" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 216, 16) as u64),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 232, 56)),
+            "f4" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 288, 16) as u64),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 304, 56)),
+            "f5" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 360, 16) as u64),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 376, 56)),
+            "f6" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 432, 16) as u64),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 448, 56)),
+            "f7" => Some(flatdata_read_bytes!(i16, self.data.as_ptr(), 504, 16) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedI16 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 65]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 65]) -> &Self {
+        // Safety: This is safe since AlignedI16 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 65]) -> &mut Self {
+        // Safety: This is safe since AlignedI16 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 65 {
+            assert_eq!(data.len(), 65);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 65];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 65 {
+            assert_eq!(data.len(), 65);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 65];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 65] {
+        &self.data
+    }
+}
+
+impl Default for AlignedI16 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedI16 {}
+
+impl AlignedI16 {
+    #[inline]
+    pub fn f0(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[0..2]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 16, 56)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[9..11]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 88, 56)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[18..20]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 160, 56)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[27..29]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 232, 56)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[36..38]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 304, 56)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[45..47]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 376, 56)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[54..56]);
+        i16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 448, 56)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> i16 {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(&self.data[63..65]);
+        i16::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedI16 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[0..2]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[0..2]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[2..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 16, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[9..11]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[9..11]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[11..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 88, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[18..20]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[18..20]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[20..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 160, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[27..29]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[27..29]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[29..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 232, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[36..38]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[36..38]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[38..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 304, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[45..47]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[45..47]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[47..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 376, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[54..56]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[54..56]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..7].copy_from_slice(&chunk[56..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 448, 56)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [i16]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(65).zip(out.iter_mut()) {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&chunk[63..65]);
+            *value = i16::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<i16> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[63..65]);
+            i16::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedI16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedI16")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedI16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedI16 {}
+
+impl std::hash::Hash for AlignedI16 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..64]);
+        state.write_u8(self.data[64]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedI16 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[0..2]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 16, 56)
+        };
+        let raw_f1 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[9..11]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 88, 56)
+        };
+        let raw_f2 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[18..20]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 160, 56)
+        };
+        let raw_f3 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[27..29]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 232, 56)
+        };
+        let raw_f4 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[36..38]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 304, 56)
+        };
+        let raw_f5 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[45..47]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 376, 56)
+        };
+        let raw_f6 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[54..56]);
+            i16::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 448, 56)
+        };
+        let raw_f7 = |item: &Self| -> i16 {
+            let mut bytes = [0; 2];
+            bytes.copy_from_slice(&item.data[63..65]);
+            i16::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedI16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedI16 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedI16", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedI16 {
+    const NAME: &'static str = "AlignedI16";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedI16 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedI16 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f0 does not fit into 16 bits", value
+        );
+        self.data[0..2].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f0", value, 16)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p0 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 16, 56)
+    }
+
+    /// Sets [`p0`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p0", value, 56)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f1 does not fit into 16 bits", value
+        );
+        self.data[9..11].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f1", value, 16)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p1 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 88, 56)
+    }
+
+    /// Sets [`p1`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p1", value, 56)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f2 does not fit into 16 bits", value
+        );
+        self.data[18..20].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f2", value, 16)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p2 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 160, 56)
+    }
+
+    /// Sets [`p2`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p2", value, 56)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f3 does not fit into 16 bits", value
+        );
+        self.data[27..29].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f3", value, 16)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p3 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 232, 56)
+    }
+
+    /// Sets [`p3`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p3", value, 56)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f4 does not fit into 16 bits", value
+        );
+        self.data[36..38].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f4", value, 16)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p4 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 304, 56)
+    }
+
+    /// Sets [`p4`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p4", value, 56)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f5 does not fit into 16 bits", value
+        );
+        self.data[45..47].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f5", value, 16)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p5 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 376, 56)
+    }
+
+    /// Sets [`p5`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p5", value, 56)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f6 does not fit into 16 bits", value
+        );
+        self.data[54..56].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f6", value, 16)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 56 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 56),
+            "value {} of AlignedI16::p6 does not fit into 56 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 448, 56)
+    }
+
+    /// Sets [`p6`] if the value fits into 56 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "p6", value, 56)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: i16) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of AlignedI16::f7 does not fit into 16 bits", value
+        );
+        self.data[63..65].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: i16) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI16", "f7", value, 16)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedI16) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedI16`] with a public member per field.
+///
+/// Converted from [`AlignedI16`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedI16`]: struct.AlignedI16.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedI16Value {
+    #[allow(missing_docs)]
+    pub f0: i16,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: i16,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: i16,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: i16,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: i16,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: i16,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: i16,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: i16,
+}
+
+impl From<&AlignedI16> for AlignedI16Value {
+    fn from(other: &AlignedI16) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedI16Value> for AlignedI16 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedI16Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedI16", "f0", value.f0, 16)?;
+        check_bits("AlignedI16", "p0", value.p0, 56)?;
+        check_bits("AlignedI16", "f1", value.f1, 16)?;
+        check_bits("AlignedI16", "p1", value.p1, 56)?;
+        check_bits("AlignedI16", "f2", value.f2, 16)?;
+        check_bits("AlignedI16", "p2", value.p2, 56)?;
+        check_bits("AlignedI16", "f3", value.f3, 16)?;
+        check_bits("AlignedI16", "p3", value.p3, 56)?;
+        check_bits("AlignedI16", "f4", value.f4, 16)?;
+        check_bits("AlignedI16", "p4", value.p4, 56)?;
+        check_bits("AlignedI16", "f5", value.f5, 16)?;
+        check_bits("AlignedI16", "p5", value.p5, 56)?;
+        check_bits("AlignedI16", "f6", value.f6, 16)?;
+        check_bits("AlignedI16", "p6", value.p6, 56)?;
+        check_bits("AlignedI16", "f7", value.f7, 16)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `u32` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedU32 {
+    data: [u8; 67],
+}
+
+impl AlignedU32 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 67]}
+    }
+}
+
+impl crate::Struct for AlignedU32 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 67]}
+    }
+
+    const SIZE_IN_BYTES: usize = 67;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedU32 {
+    const NAME: &'static str = "AlignedU32";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "u32",
+            offset: 0,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 32,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "u32",
+            offset: 72,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 104,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "u32",
+            offset: 144,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 176,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "u32",
+            offset: 216,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 248,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "u32",
+            offset: 288,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 320,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "u32",
+            offset: 360,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 392,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "u32",
+            offset: 432,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 464,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "u32",
+            offset: 504,
+            width: 32,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 32) as u64),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 32, 40)),
+            "f1" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 72, 32) as u64),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 104, 40)),
+            "f2" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 144, 32) as u64),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 176, 40)),
+            "f3" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 216, 32) as u64),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 248, 40)),
+            "f4" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 288, 32) as u64),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 320, 40)),
+            "f5" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 360, 32) as u64),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 392, 40)),
+            "f6" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 432, 32) as u64),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 464, 40)),
+            "f7" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 504, 32) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedU32 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 67]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 67]) -> &Self {
+        // Safety: This is safe since AlignedU32 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 67]) -> &mut Self {
+        // Safety: This is safe since AlignedU32 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 67 {
+            assert_eq!(data.len(), 67);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 67];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 67 {
+            assert_eq!(data.len(), 67);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 67];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 67] {
+        &self.data
+    }
+}
+
+impl Default for AlignedU32 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedU32 {}
+
+impl AlignedU32 {
+    #[inline]
+    pub fn f0(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[0..4]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 32, 40)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[9..13]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 104, 40)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[18..22]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 176, 40)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[27..31]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 248, 40)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[36..40]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 320, 40)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[45..49]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 392, 40)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[54..58]);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 464, 40)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> u32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[63..67]);
+        u32::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedU32 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[0..4]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[0..4]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[4..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 32, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[9..13]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[9..13]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[13..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 104, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[18..22]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[18..22]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[22..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 176, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[27..31]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[27..31]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[31..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 248, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[36..40]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[36..40]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[40..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 320, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[45..49]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[45..49]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[49..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 392, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[54..58]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[54..58]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[58..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 464, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[63..67]);
+            *value = u32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[63..67]);
+            u32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedU32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedU32")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedU32 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedU32 {}
+
+impl std::hash::Hash for AlignedU32 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..66]);
+        state.write_u8(self.data[66]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedU32 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[0..4]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 32, 40)
+        };
+        let raw_f1 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[9..13]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 104, 40)
+        };
+        let raw_f2 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[18..22]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 176, 40)
+        };
+        let raw_f3 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[27..31]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 248, 40)
+        };
+        let raw_f4 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[36..40]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 320, 40)
+        };
+        let raw_f5 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[45..49]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 392, 40)
+        };
+        let raw_f6 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[54..58]);
+            u32::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 464, 40)
+        };
+        let raw_f7 = |item: &Self| -> u32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[63..67]);
+            u32::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedU32 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedU32 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedU32", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedU32 {
+    const NAME: &'static str = "AlignedU32";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedU32 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedU32 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f0 does not fit into 32 bits", value
+        );
+        self.data[0..4].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f0", value, 32)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p0 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 32, 40)
+    }
+
+    /// Sets [`p0`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p0", value, 40)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f1 does not fit into 32 bits", value
+        );
+        self.data[9..13].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f1", value, 32)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p1 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 104, 40)
+    }
+
+    /// Sets [`p1`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p1", value, 40)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f2 does not fit into 32 bits", value
+        );
+        self.data[18..22].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f2", value, 32)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p2 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 176, 40)
+    }
+
+    /// Sets [`p2`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p2", value, 40)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f3 does not fit into 32 bits", value
+        );
+        self.data[27..31].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f3", value, 32)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p3 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 248, 40)
+    }
+
+    /// Sets [`p3`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p3", value, 40)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f4 does not fit into 32 bits", value
+        );
+        self.data[36..40].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f4", value, 32)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p4 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 320, 40)
+    }
+
+    /// Sets [`p4`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p4", value, 40)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f5 does not fit into 32 bits", value
+        );
+        self.data[45..49].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f5", value, 32)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p5 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 392, 40)
+    }
+
+    /// Sets [`p5`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p5", value, 40)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f6 does not fit into 32 bits", value
+        );
+        self.data[54..58].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f6", value, 32)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedU32::p6 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 464, 40)
+    }
+
+    /// Sets [`p6`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "p6", value, 40)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedU32::f7 does not fit into 32 bits", value
+        );
+        self.data[63..67].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU32", "f7", value, 32)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedU32) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedU32`] with a public member per field.
+///
+/// Converted from [`AlignedU32`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedU32`]: struct.AlignedU32.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedU32Value {
+    #[allow(missing_docs)]
+    pub f0: u32,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: u32,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: u32,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: u32,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: u32,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: u32,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: u32,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: u32,
+}
+
+impl From<&AlignedU32> for AlignedU32Value {
+    fn from(other: &AlignedU32) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedU32Value> for AlignedU32 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedU32Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedU32", "f0", value.f0, 32)?;
+        check_bits("AlignedU32", "p0", value.p0, 40)?;
+        check_bits("AlignedU32", "f1", value.f1, 32)?;
+        check_bits("AlignedU32", "p1", value.p1, 40)?;
+        check_bits("AlignedU32", "f2", value.f2, 32)?;
+        check_bits("AlignedU32", "p2", value.p2, 40)?;
+        check_bits("AlignedU32", "f3", value.f3, 32)?;
+        check_bits("AlignedU32", "p3", value.p3, 40)?;
+        check_bits("AlignedU32", "f4", value.f4, 32)?;
+        check_bits("AlignedU32", "p4", value.p4, 40)?;
+        check_bits("AlignedU32", "f5", value.f5, 32)?;
+        check_bits("AlignedU32", "p5", value.p5, 40)?;
+        check_bits("AlignedU32", "f6", value.f6, 32)?;
+        check_bits("AlignedU32", "p6", value.p6, 40)?;
+        check_bits("AlignedU32", "f7", value.f7, 32)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `i32` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedI32 {
+    data: [u8; 67],
+}
+
+impl AlignedI32 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 67]}
+    }
+}
+
+impl crate::Struct for AlignedI32 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 67]}
+    }
+
+    const SIZE_IN_BYTES: usize = 67;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedI32 {
+    const NAME: &'static str = "AlignedI32";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "i32",
+            offset: 0,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 32,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "i32",
+            offset: 72,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 104,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "i32",
+            offset: 144,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 176,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "i32",
+            offset: 216,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 248,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "i32",
+            offset: 288,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 320,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "i32",
+            offset: 360,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 392,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "i32",
+            offset: 432,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 464,
+            width: 40,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "i32",
+            offset: 504,
+            width: 32,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 0, 32) as u64),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 32, 40)),
+            "f1" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 72, 32) as u64),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 104, 40)),
+            "f2" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 144, 32) as u64),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 176, 40)),
+            "f3" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 216, 32) as u64),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 248, 40)),
+            "f4" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 288, 32) as u64),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 320, 40)),
+            "f5" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 360, 32) as u64),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 392, 40)),
+            "f6" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 432, 32) as u64),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 464, 40)),
+            "f7" => Some(flatdata_read_bytes!(i32, self.data.as_ptr(), 504, 32) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedI32 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 67]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 67]) -> &Self {
+        // Safety: This is safe since AlignedI32 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 67]) -> &mut Self {
+        // Safety: This is safe since AlignedI32 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 67 {
+            assert_eq!(data.len(), 67);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 67];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 67 {
+            assert_eq!(data.len(), 67);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 67];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 67] {
+        &self.data
+    }
+}
+
+impl Default for AlignedI32 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedI32 {}
+
+impl AlignedI32 {
+    #[inline]
+    pub fn f0(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[0..4]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 32, 40)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[9..13]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 104, 40)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[18..22]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 176, 40)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[27..31]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 248, 40)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[36..40]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 320, 40)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[45..49]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 392, 40)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[54..58]);
+        i32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 464, 40)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> i32 {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(&self.data[63..67]);
+        i32::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedI32 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[0..4]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[0..4]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[4..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 32, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[9..13]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[9..13]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[13..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 104, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[18..22]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[18..22]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[22..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 176, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[27..31]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[27..31]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[31..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 248, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[36..40]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[36..40]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[40..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 320, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[45..49]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[45..49]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[49..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 392, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[54..58]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[54..58]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..5].copy_from_slice(&chunk[58..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffffffffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 464, 40)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [i32]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI32 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(67).zip(out.iter_mut()) {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&chunk[63..67]);
+            *value = i32::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<i32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[63..67]);
+            i32::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedI32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedI32")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedI32 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedI32 {}
+
+impl std::hash::Hash for AlignedI32 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..66]);
+        state.write_u8(self.data[66]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedI32 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[0..4]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 32, 40)
+        };
+        let raw_f1 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[9..13]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 104, 40)
+        };
+        let raw_f2 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[18..22]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 176, 40)
+        };
+        let raw_f3 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[27..31]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 248, 40)
+        };
+        let raw_f4 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[36..40]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 320, 40)
+        };
+        let raw_f5 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[45..49]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 392, 40)
+        };
+        let raw_f6 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[54..58]);
+            i32::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 464, 40)
+        };
+        let raw_f7 = |item: &Self| -> i32 {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&item.data[63..67]);
+            i32::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedI32 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedI32 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedI32", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedI32 {
+    const NAME: &'static str = "AlignedI32";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedI32 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedI32 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f0 does not fit into 32 bits", value
+        );
+        self.data[0..4].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f0", value, 32)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p0 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 32, 40)
+    }
+
+    /// Sets [`p0`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p0", value, 40)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f1 does not fit into 32 bits", value
+        );
+        self.data[9..13].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f1", value, 32)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p1 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 104, 40)
+    }
+
+    /// Sets [`p1`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p1", value, 40)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f2 does not fit into 32 bits", value
+        );
+        self.data[18..22].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f2", value, 32)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p2 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 176, 40)
+    }
+
+    /// Sets [`p2`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p2", value, 40)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f3 does not fit into 32 bits", value
+        );
+        self.data[27..31].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f3", value, 32)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p3 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 248, 40)
+    }
+
+    /// Sets [`p3`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p3", value, 40)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f4 does not fit into 32 bits", value
+        );
+        self.data[36..40].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f4", value, 32)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p4 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 320, 40)
+    }
+
+    /// Sets [`p4`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p4", value, 40)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f5 does not fit into 32 bits", value
+        );
+        self.data[45..49].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f5", value, 32)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p5 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 392, 40)
+    }
+
+    /// Sets [`p5`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p5", value, 40)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f6 does not fit into 32 bits", value
+        );
+        self.data[54..58].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f6", value, 32)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 40 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 40),
+            "value {} of AlignedI32::p6 does not fit into 40 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 464, 40)
+    }
+
+    /// Sets [`p6`] if the value fits into 40 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "p6", value, 40)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 32 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: i32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 32),
+            "value {} of AlignedI32::f7 does not fit into 32 bits", value
+        );
+        self.data[63..67].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 32 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: i32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI32", "f7", value, 32)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedI32) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedI32`] with a public member per field.
+///
+/// Converted from [`AlignedI32`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedI32`]: struct.AlignedI32.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedI32Value {
+    #[allow(missing_docs)]
+    pub f0: i32,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: i32,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: i32,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: i32,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: i32,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: i32,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: i32,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: i32,
+}
+
+impl From<&AlignedI32> for AlignedI32Value {
+    fn from(other: &AlignedI32) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedI32Value> for AlignedI32 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedI32Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedI32", "f0", value.f0, 32)?;
+        check_bits("AlignedI32", "p0", value.p0, 40)?;
+        check_bits("AlignedI32", "f1", value.f1, 32)?;
+        check_bits("AlignedI32", "p1", value.p1, 40)?;
+        check_bits("AlignedI32", "f2", value.f2, 32)?;
+        check_bits("AlignedI32", "p2", value.p2, 40)?;
+        check_bits("AlignedI32", "f3", value.f3, 32)?;
+        check_bits("AlignedI32", "p3", value.p3, 40)?;
+        check_bits("AlignedI32", "f4", value.f4, 32)?;
+        check_bits("AlignedI32", "p4", value.p4, 40)?;
+        check_bits("AlignedI32", "f5", value.f5, 32)?;
+        check_bits("AlignedI32", "p5", value.p5, 40)?;
+        check_bits("AlignedI32", "f6", value.f6, 32)?;
+        check_bits("AlignedI32", "p6", value.p6, 40)?;
+        check_bits("AlignedI32", "f7", value.f7, 32)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `u64` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedU64 {
+    data: [u8; 71],
+}
+
+impl AlignedU64 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 71]}
+    }
+}
+
+impl crate::Struct for AlignedU64 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 71]}
+    }
+
+    const SIZE_IN_BYTES: usize = 71;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedU64 {
+    const NAME: &'static str = "AlignedU64";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "u64",
+            offset: 0,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 64,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "u64",
+            offset: 72,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 136,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "u64",
+            offset: 144,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 208,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "u64",
+            offset: 216,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 280,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "u64",
+            offset: 288,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 352,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "u64",
+            offset: 360,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 424,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "u64",
+            offset: 432,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 496,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "u64",
+            offset: 504,
+            width: 64,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 0, 64)),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 64, 8)),
+            "f1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 72, 64)),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 136, 8)),
+            "f2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 144, 64)),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 208, 8)),
+            "f3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 216, 64)),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 280, 8)),
+            "f4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 288, 64)),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 352, 8)),
+            "f5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 360, 64)),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 424, 8)),
+            "f6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 432, 64)),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 496, 8)),
+            "f7" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 504, 64)),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedU64 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 71]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 71]) -> &Self {
+        // Safety: This is safe since AlignedU64 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 71]) -> &mut Self {
+        // Safety: This is safe since AlignedU64 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 71 {
+            assert_eq!(data.len(), 71);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 71];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 71 {
+            assert_eq!(data.len(), 71);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 71];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 71] {
+        &self.data
+    }
+}
+
+impl Default for AlignedU64 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedU64 {}
+
+impl AlignedU64 {
+    #[inline]
+    pub fn f0(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[0..8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 64, 8)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[9..17]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 136, 8)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[18..26]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 208, 8)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[27..35]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 280, 8)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[36..44]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 352, 8)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[45..53]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 424, 8)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[54..62]);
+        u64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 496, 8)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> u64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[63..71]);
+        u64::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedU64 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[0..8]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[0..8]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[8..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 64, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[9..17]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[9..17]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[17..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 136, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[18..26]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[18..26]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[26..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 208, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[27..35]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[27..35]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[35..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 280, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[36..44]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[36..44]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[44..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 352, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[45..53]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[45..53]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[53..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 424, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[54..62]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[54..62]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[62..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 496, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedU64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[63..71]);
+            *value = u64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[63..71]);
+            u64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedU64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedU64")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedU64 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedU64 {}
+
+impl std::hash::Hash for AlignedU64 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..70]);
+        state.write_u8(self.data[70]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedU64 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[0..8]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 64, 8)
+        };
+        let raw_f1 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[9..17]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 136, 8)
+        };
+        let raw_f2 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[18..26]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 208, 8)
+        };
+        let raw_f3 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[27..35]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 280, 8)
+        };
+        let raw_f4 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[36..44]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 352, 8)
+        };
+        let raw_f5 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[45..53]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 424, 8)
+        };
+        let raw_f6 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[54..62]);
+            u64::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 496, 8)
+        };
+        let raw_f7 = |item: &Self| -> u64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[63..71]);
+            u64::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedU64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedU64 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedU64", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedU64 {
+    const NAME: &'static str = "AlignedU64";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedU64 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedU64 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f0 does not fit into 64 bits", value
+        );
+        self.data[0..8].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f0", value, 64)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p0 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 64, 8)
+    }
+
+    /// Sets [`p0`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p0", value, 8)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f1 does not fit into 64 bits", value
+        );
+        self.data[9..17].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f1", value, 64)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p1 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 136, 8)
+    }
+
+    /// Sets [`p1`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p1", value, 8)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f2 does not fit into 64 bits", value
+        );
+        self.data[18..26].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f2", value, 64)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p2 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 208, 8)
+    }
+
+    /// Sets [`p2`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p2", value, 8)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f3 does not fit into 64 bits", value
+        );
+        self.data[27..35].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f3", value, 64)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p3 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 280, 8)
+    }
+
+    /// Sets [`p3`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p3", value, 8)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f4 does not fit into 64 bits", value
+        );
+        self.data[36..44].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f4", value, 64)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p4 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 352, 8)
+    }
+
+    /// Sets [`p4`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p4", value, 8)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f5 does not fit into 64 bits", value
+        );
+        self.data[45..53].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f5", value, 64)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p5 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 424, 8)
+    }
+
+    /// Sets [`p5`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p5", value, 8)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f6 does not fit into 64 bits", value
+        );
+        self.data[54..62].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f6", value, 64)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedU64::p6 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 496, 8)
+    }
+
+    /// Sets [`p6`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "p6", value, 8)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedU64::f7 does not fit into 64 bits", value
+        );
+        self.data[63..71].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedU64", "f7", value, 64)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedU64) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedU64`] with a public member per field.
+///
+/// Converted from [`AlignedU64`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedU64`]: struct.AlignedU64.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedU64Value {
+    #[allow(missing_docs)]
+    pub f0: u64,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: u64,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: u64,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: u64,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: u64,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: u64,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: u64,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: u64,
+}
+
+impl From<&AlignedU64> for AlignedU64Value {
+    fn from(other: &AlignedU64) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedU64Value> for AlignedU64 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedU64Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedU64", "f0", value.f0, 64)?;
+        check_bits("AlignedU64", "p0", value.p0, 8)?;
+        check_bits("AlignedU64", "f1", value.f1, 64)?;
+        check_bits("AlignedU64", "p1", value.p1, 8)?;
+        check_bits("AlignedU64", "f2", value.f2, 64)?;
+        check_bits("AlignedU64", "p2", value.p2, 8)?;
+        check_bits("AlignedU64", "f3", value.f3, 64)?;
+        check_bits("AlignedU64", "p3", value.p3, 8)?;
+        check_bits("AlignedU64", "f4", value.f4, 64)?;
+        check_bits("AlignedU64", "p4", value.p4, 8)?;
+        check_bits("AlignedU64", "f5", value.f5, 64)?;
+        check_bits("AlignedU64", "p5", value.p5, 8)?;
+        check_bits("AlignedU64", "f6", value.f6, 64)?;
+        check_bits("AlignedU64", "p6", value.p6, 8)?;
+        check_bits("AlignedU64", "f7", value.f7, 64)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+/// `i64` fields at every byte offset modulo 8
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct AlignedI64 {
+    data: [u8; 71],
+}
+
+impl AlignedI64 {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 71]}
+    }
+}
+
+impl crate::Struct for AlignedI64 {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 71]}
+    }
+
+    const SIZE_IN_BYTES: usize = 71;
+    const IS_OVERLAPPING_WITH_NEXT : bool = false;
+}
+
+impl crate::StructReflect for AlignedI64 {
+    const NAME: &'static str = "AlignedI64";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "f0",
+            primitive_type: "i64",
+            offset: 0,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p0",
+            primitive_type: "u64",
+            offset: 64,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f1",
+            primitive_type: "i64",
+            offset: 72,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p1",
+            primitive_type: "u64",
+            offset: 136,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f2",
+            primitive_type: "i64",
+            offset: 144,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p2",
+            primitive_type: "u64",
+            offset: 208,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f3",
+            primitive_type: "i64",
+            offset: 216,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p3",
+            primitive_type: "u64",
+            offset: 280,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f4",
+            primitive_type: "i64",
+            offset: 288,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p4",
+            primitive_type: "u64",
+            offset: 352,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f5",
+            primitive_type: "i64",
+            offset: 360,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p5",
+            primitive_type: "u64",
+            offset: 424,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f6",
+            primitive_type: "i64",
+            offset: 432,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "p6",
+            primitive_type: "u64",
+            offset: 496,
+            width: 8,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+        crate::FieldInfo {
+            name: "f7",
+            primitive_type: "i64",
+            offset: 504,
+            width: 64,
+            is_signed: true,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "f0" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 0, 64) as u64),
+            "p0" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 64, 8)),
+            "f1" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 72, 64) as u64),
+            "p1" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 136, 8)),
+            "f2" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 144, 64) as u64),
+            "p2" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 208, 8)),
+            "f3" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 216, 64) as u64),
+            "p3" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 280, 8)),
+            "f4" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 288, 64) as u64),
+            "p4" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 352, 8)),
+            "f5" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 360, 64) as u64),
+            "p5" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 424, 8)),
+            "f6" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 432, 64) as u64),
+            "p6" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 496, 8)),
+            "f7" => Some(flatdata_read_bytes!(i64, self.data.as_ptr(), 504, 64) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl AlignedI64 {
+    pub fn new( ) -> Self {
+        Self{data : [0; 71]}
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes(data: &[u8; 71]) -> &Self {
+        // Safety: This is safe since AlignedI64 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array of matching size
+    pub fn from_bytes_mut(data: &mut [u8; 71]) -> &mut Self {
+        // Safety: This is safe since AlignedI64 is repr(transparent)
+        unsafe{ std::mem::transmute( data ) }
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice(data: &[u8]) -> Result<&Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 71 {
+            assert_eq!(data.len(), 71);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *const [u8; 71];
+        // Safety: We checked length before
+        Ok(Self::from_bytes(unsafe { &*ptr }))
+    }
+
+    /// Create reference from byte array
+    pub fn from_bytes_slice_mut(data: &mut [u8]) -> Result<&mut Self, crate::ResourceStorageError> {
+        // We cannot rely on TryFrom here, since it does not yet support > 33 bytes
+        if data.len() < 71 {
+            assert_eq!(data.len(), 71);
+            return Err(crate::ResourceStorageError::UnexpectedDataSize);
+        }
+        let ptr = data.as_ptr() as *mut [u8; 71];
+        // Safety: We checked length before
+        Ok(Self::from_bytes_mut(unsafe { &mut *ptr }))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 71] {
+        &self.data
+    }
+}
+
+impl Default for AlignedI64 {
+    fn default( ) -> Self {
+        Self::new( )
+    }
+}
+
+unsafe impl crate::NoOverlap for AlignedI64 {}
+
+impl AlignedI64 {
+    #[inline]
+    pub fn f0(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[0..8]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p0(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 64, 8)
+    }
+
+    #[inline]
+    pub fn f1(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[9..17]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p1(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 136, 8)
+    }
+
+    #[inline]
+    pub fn f2(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[18..26]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p2(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 208, 8)
+    }
+
+    #[inline]
+    pub fn f3(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[27..35]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p3(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 280, 8)
+    }
+
+    #[inline]
+    pub fn f4(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[36..44]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p4(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 352, 8)
+    }
+
+    #[inline]
+    pub fn f5(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[45..53]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p5(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 424, 8)
+    }
+
+    #[inline]
+    pub fn f6(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[54..62]);
+        i64::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub fn p6(&self) -> u64 {
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 496, 8)
+    }
+
+    #[inline]
+    pub fn f7(&self) -> i64 {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(&self.data[63..71]);
+        i64::from_le_bytes(bytes)
+    }
+
+}
+
+impl AlignedI64 {
+    /// Decodes the raw values of the field [`f0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn decode_f0_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[0..8]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f0_into`].
+    ///
+    /// [`f0`]: #method.f0
+    /// [`decode_f0_into`]: #method.decode_f0_into
+    pub fn decode_f0(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f0`]: #method.f0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[0..8]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn decode_p0_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[8..9]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p0`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p0_into`].
+    ///
+    /// [`p0`]: #method.p0
+    /// [`decode_p0_into`]: #method.decode_p0_into
+    pub fn decode_p0(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p0_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p0`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p0`]: #method.p0
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p0(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 64, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn decode_f1_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[9..17]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f1_into`].
+    ///
+    /// [`f1`]: #method.f1
+    /// [`decode_f1_into`]: #method.decode_f1_into
+    pub fn decode_f1(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f1`]: #method.f1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[9..17]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn decode_p1_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[17..18]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p1`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p1_into`].
+    ///
+    /// [`p1`]: #method.p1
+    /// [`decode_p1_into`]: #method.decode_p1_into
+    pub fn decode_p1(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p1_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p1`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p1`]: #method.p1
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p1(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 136, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn decode_f2_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[18..26]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f2_into`].
+    ///
+    /// [`f2`]: #method.f2
+    /// [`decode_f2_into`]: #method.decode_f2_into
+    pub fn decode_f2(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f2`]: #method.f2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[18..26]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn decode_p2_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[26..27]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p2`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p2_into`].
+    ///
+    /// [`p2`]: #method.p2
+    /// [`decode_p2_into`]: #method.decode_p2_into
+    pub fn decode_p2(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p2_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p2`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p2`]: #method.p2
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p2(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 208, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn decode_f3_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[27..35]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f3_into`].
+    ///
+    /// [`f3`]: #method.f3
+    /// [`decode_f3_into`]: #method.decode_f3_into
+    pub fn decode_f3(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f3`]: #method.f3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[27..35]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn decode_p3_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[35..36]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p3`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p3_into`].
+    ///
+    /// [`p3`]: #method.p3
+    /// [`decode_p3_into`]: #method.decode_p3_into
+    pub fn decode_p3(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p3_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p3`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p3`]: #method.p3
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p3(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 280, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn decode_f4_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[36..44]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f4_into`].
+    ///
+    /// [`f4`]: #method.f4
+    /// [`decode_f4_into`]: #method.decode_f4_into
+    pub fn decode_f4(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f4`]: #method.f4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[36..44]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn decode_p4_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[44..45]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p4`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p4_into`].
+    ///
+    /// [`p4`]: #method.p4
+    /// [`decode_p4_into`]: #method.decode_p4_into
+    pub fn decode_p4(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p4_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p4`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p4`]: #method.p4
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p4(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 352, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn decode_f5_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[45..53]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f5_into`].
+    ///
+    /// [`f5`]: #method.f5
+    /// [`decode_f5_into`]: #method.decode_f5_into
+    pub fn decode_f5(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f5`]: #method.f5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[45..53]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn decode_p5_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[53..54]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p5`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p5_into`].
+    ///
+    /// [`p5`]: #method.p5
+    /// [`decode_p5_into`]: #method.decode_p5_into
+    pub fn decode_p5(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p5_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p5`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p5`]: #method.p5
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p5(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 424, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn decode_f6_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[54..62]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f6_into`].
+    ///
+    /// [`f6`]: #method.f6
+    /// [`decode_f6_into`]: #method.decode_f6_into
+    pub fn decode_f6(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f6`]: #method.f6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[54..62]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn decode_p6_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..1].copy_from_slice(&chunk[62..63]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`p6`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_p6_into`].
+    ///
+    /// [`p6`]: #method.p6
+    /// [`decode_p6_into`]: #method.decode_p6_into
+    pub fn decode_p6(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_p6_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`p6`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`p6`]: #method.p6
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_p6(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 496, 8)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn decode_f7_into(data: &[Self], out: &mut [i64]) {
+        let out = &mut out[..data.len()];
+        // Safety: AlignedI64 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(71).zip(out.iter_mut()) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&chunk[63..71]);
+            *value = i64::from_le_bytes(bytes);
+        }
+    }
+
+    /// Decodes the raw values of the field [`f7`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_f7_into`].
+    ///
+    /// [`f7`]: #method.f7
+    /// [`decode_f7_into`]: #method.decode_f7_into
+    pub fn decode_f7(data: &[Self]) -> Vec<i64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_f7_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`f7`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`f7`]: #method.f7
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_f7(data: &[Self]) -> bool {
+        let value = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[63..71]);
+            i64::from_le_bytes(bytes)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for AlignedI64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AlignedI64")
+            .field("f0", &self.f0())
+            .field("p0", &self.p0())
+            .field("f1", &self.f1())
+            .field("p1", &self.p1())
+            .field("f2", &self.f2())
+            .field("p2", &self.p2())
+            .field("f3", &self.f3())
+            .field("p3", &self.p3())
+            .field("f4", &self.f4())
+            .field("p4", &self.p4())
+            .field("f5", &self.f5())
+            .field("p5", &self.p5())
+            .field("f6", &self.f6())
+            .field("p6", &self.p6())
+            .field("f7", &self.f7())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for AlignedI64 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.f0() == other.f0() &&        self.p0() == other.p0() &&        self.f1() == other.f1() &&        self.p1() == other.p1() &&        self.f2() == other.f2() &&        self.p2() == other.p2() &&        self.f3() == other.f3() &&        self.p3() == other.p3() &&        self.f4() == other.f4() &&        self.p4() == other.p4() &&        self.f5() == other.f5() &&        self.p5() == other.p5() &&        self.f6() == other.f6() &&        self.p6() == other.p6() &&        self.f7() == other.f7()     }
+}
+
+impl std::cmp::Eq for AlignedI64 {}
+
+impl std::hash::Hash for AlignedI64 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..70]);
+        state.write_u8(self.data[70]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for AlignedI64 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_f0 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[0..8]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p0 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 64, 8)
+        };
+        let raw_f1 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[9..17]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p1 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 136, 8)
+        };
+        let raw_f2 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[18..26]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p2 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 208, 8)
+        };
+        let raw_f3 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[27..35]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p3 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 280, 8)
+        };
+        let raw_f4 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[36..44]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p4 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 352, 8)
+        };
+        let raw_f5 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[45..53]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p5 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 424, 8)
+        };
+        let raw_f6 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[54..62]);
+            i64::from_le_bytes(bytes)
+        };
+        let raw_p6 = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 496, 8)
+        };
+        let raw_f7 = |item: &Self| -> i64 {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&item.data[63..71]);
+            i64::from_le_bytes(bytes)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_f0(self).cmp(&raw_f0(other)))
+            .then_with(|| raw_p0(self).cmp(&raw_p0(other)))
+            .then_with(|| raw_f1(self).cmp(&raw_f1(other)))
+            .then_with(|| raw_p1(self).cmp(&raw_p1(other)))
+            .then_with(|| raw_f2(self).cmp(&raw_f2(other)))
+            .then_with(|| raw_p2(self).cmp(&raw_p2(other)))
+            .then_with(|| raw_f3(self).cmp(&raw_f3(other)))
+            .then_with(|| raw_p3(self).cmp(&raw_p3(other)))
+            .then_with(|| raw_f4(self).cmp(&raw_f4(other)))
+            .then_with(|| raw_p4(self).cmp(&raw_p4(other)))
+            .then_with(|| raw_f5(self).cmp(&raw_f5(other)))
+            .then_with(|| raw_p5(self).cmp(&raw_p5(other)))
+            .then_with(|| raw_f6(self).cmp(&raw_f6(other)))
+            .then_with(|| raw_p6(self).cmp(&raw_p6(other)))
+            .then_with(|| raw_f7(self).cmp(&raw_f7(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for AlignedI64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for AlignedI64 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("AlignedI64", 15)?;
+        s.serialize_field("f0", &self.f0())?;
+        s.serialize_field("p0", &self.p0())?;
+        s.serialize_field("f1", &self.f1())?;
+        s.serialize_field("p1", &self.p1())?;
+        s.serialize_field("f2", &self.f2())?;
+        s.serialize_field("p2", &self.p2())?;
+        s.serialize_field("f3", &self.f3())?;
+        s.serialize_field("p3", &self.p3())?;
+        s.serialize_field("f4", &self.f4())?;
+        s.serialize_field("p4", &self.p4())?;
+        s.serialize_field("f5", &self.f5())?;
+        s.serialize_field("p5", &self.p5())?;
+        s.serialize_field("f6", &self.f6())?;
+        s.serialize_field("p6", &self.p6())?;
+        s.serialize_field("f7", &self.f7())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for AlignedI64 {
+    const NAME: &'static str = "AlignedI64";
+    const FIELDS: &'static [&'static str] = &["f0", "p0", "f1", "p1", "f2", "p2", "f3", "p3", "f4", "p4", "f5", "p5", "f6", "p6", "f7", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_f0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_p0(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            2 => self.try_set_f1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            3 => self.try_set_p1(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            4 => self.try_set_f2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            5 => self.try_set_p2(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            6 => self.try_set_f3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            7 => self.try_set_p3(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            8 => self.try_set_f4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            9 => self.try_set_p4(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            10 => self.try_set_f5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            11 => self.try_set_p5(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            12 => self.try_set_f6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            13 => self.try_set_p6(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            14 => self.try_set_f7(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for AlignedI64 {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        crate::de::deserialize_struct(deserializer)
+    }
+}
+}
+
+impl AlignedI64 {
+    /// Sets the value of [`f0`].
+    ///
+    /// [`f0`]: #method.f0
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f0`] to check it in release builds.
+    ///
+    /// [`try_set_f0`]: #method.try_set_f0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f0(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f0 does not fit into 64 bits", value
+        );
+        self.data[0..8].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f0`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f0`]: #method.f0
+    #[inline]
+    pub fn try_set_f0(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f0", value, 64)?;
+        self.set_f0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p0`].
+    ///
+    /// [`p0`]: #method.p0
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p0`] to check it in release builds.
+    ///
+    /// [`try_set_p0`]: #method.try_set_p0
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p0(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p0 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 64, 8)
+    }
+
+    /// Sets [`p0`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p0`]: #method.p0
+    #[inline]
+    pub fn try_set_p0(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p0", value, 8)?;
+        self.set_p0(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f1`].
+    ///
+    /// [`f1`]: #method.f1
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f1`] to check it in release builds.
+    ///
+    /// [`try_set_f1`]: #method.try_set_f1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f1(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f1 does not fit into 64 bits", value
+        );
+        self.data[9..17].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f1`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f1`]: #method.f1
+    #[inline]
+    pub fn try_set_f1(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f1", value, 64)?;
+        self.set_f1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p1`].
+    ///
+    /// [`p1`]: #method.p1
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p1`] to check it in release builds.
+    ///
+    /// [`try_set_p1`]: #method.try_set_p1
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p1(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p1 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 136, 8)
+    }
+
+    /// Sets [`p1`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p1`]: #method.p1
+    #[inline]
+    pub fn try_set_p1(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p1", value, 8)?;
+        self.set_p1(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f2`].
+    ///
+    /// [`f2`]: #method.f2
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f2`] to check it in release builds.
+    ///
+    /// [`try_set_f2`]: #method.try_set_f2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f2(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f2 does not fit into 64 bits", value
+        );
+        self.data[18..26].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f2`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f2`]: #method.f2
+    #[inline]
+    pub fn try_set_f2(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f2", value, 64)?;
+        self.set_f2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p2`].
+    ///
+    /// [`p2`]: #method.p2
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p2`] to check it in release builds.
+    ///
+    /// [`try_set_p2`]: #method.try_set_p2
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p2(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p2 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 208, 8)
+    }
+
+    /// Sets [`p2`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p2`]: #method.p2
+    #[inline]
+    pub fn try_set_p2(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p2", value, 8)?;
+        self.set_p2(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f3`].
+    ///
+    /// [`f3`]: #method.f3
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f3`] to check it in release builds.
+    ///
+    /// [`try_set_f3`]: #method.try_set_f3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f3(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f3 does not fit into 64 bits", value
+        );
+        self.data[27..35].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f3`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f3`]: #method.f3
+    #[inline]
+    pub fn try_set_f3(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f3", value, 64)?;
+        self.set_f3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p3`].
+    ///
+    /// [`p3`]: #method.p3
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p3`] to check it in release builds.
+    ///
+    /// [`try_set_p3`]: #method.try_set_p3
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p3(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p3 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 280, 8)
+    }
+
+    /// Sets [`p3`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p3`]: #method.p3
+    #[inline]
+    pub fn try_set_p3(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p3", value, 8)?;
+        self.set_p3(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f4`].
+    ///
+    /// [`f4`]: #method.f4
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f4`] to check it in release builds.
+    ///
+    /// [`try_set_f4`]: #method.try_set_f4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f4(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f4 does not fit into 64 bits", value
+        );
+        self.data[36..44].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f4`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f4`]: #method.f4
+    #[inline]
+    pub fn try_set_f4(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f4", value, 64)?;
+        self.set_f4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p4`].
+    ///
+    /// [`p4`]: #method.p4
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p4`] to check it in release builds.
+    ///
+    /// [`try_set_p4`]: #method.try_set_p4
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p4(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p4 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 352, 8)
+    }
+
+    /// Sets [`p4`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p4`]: #method.p4
+    #[inline]
+    pub fn try_set_p4(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p4", value, 8)?;
+        self.set_p4(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f5`].
+    ///
+    /// [`f5`]: #method.f5
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f5`] to check it in release builds.
+    ///
+    /// [`try_set_f5`]: #method.try_set_f5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f5(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f5 does not fit into 64 bits", value
+        );
+        self.data[45..53].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f5`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f5`]: #method.f5
+    #[inline]
+    pub fn try_set_f5(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f5", value, 64)?;
+        self.set_f5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p5`].
+    ///
+    /// [`p5`]: #method.p5
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p5`] to check it in release builds.
+    ///
+    /// [`try_set_p5`]: #method.try_set_p5
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p5(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p5 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 424, 8)
+    }
+
+    /// Sets [`p5`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p5`]: #method.p5
+    #[inline]
+    pub fn try_set_p5(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p5", value, 8)?;
+        self.set_p5(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f6`].
+    ///
+    /// [`f6`]: #method.f6
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f6`] to check it in release builds.
+    ///
+    /// [`try_set_f6`]: #method.try_set_f6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f6(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f6 does not fit into 64 bits", value
+        );
+        self.data[54..62].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f6`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f6`]: #method.f6
+    #[inline]
+    pub fn try_set_f6(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f6", value, 64)?;
+        self.set_f6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`p6`].
+    ///
+    /// [`p6`]: #method.p6
+    ///
+    /// The value is truncated to 8 bits, which is checked by a debug
+    /// assertion. Use [`try_set_p6`] to check it in release builds.
+    ///
+    /// [`try_set_p6`]: #method.try_set_p6
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_p6(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 8),
+            "value {} of AlignedI64::p6 does not fit into 8 bits", value
+        );
+        flatdata_write_bytes!(u64; value, self.data, 496, 8)
+    }
+
+    /// Sets [`p6`] if the value fits into 8 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`p6`]: #method.p6
+    #[inline]
+    pub fn try_set_p6(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "p6", value, 8)?;
+        self.set_p6(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`f7`].
+    ///
+    /// [`f7`]: #method.f7
+    ///
+    /// The value is truncated to 64 bits, which is checked by a debug
+    /// assertion. Use [`try_set_f7`] to check it in release builds.
+    ///
+    /// [`try_set_f7`]: #method.try_set_f7
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_f7(&mut self, value: i64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 64),
+            "value {} of AlignedI64::f7 does not fit into 64 bits", value
+        );
+        self.data[63..71].copy_from_slice(&(value).to_le_bytes());
+    }
+
+    /// Sets [`f7`] if the value fits into 64 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`f7`]: #method.f7
+    #[inline]
+    pub fn try_set_f7(&mut self, value: i64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("AlignedI64", "f7", value, 64)?;
+        self.set_f7(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &AlignedI64) {
+        self.set_f0(other.f0());
+        self.set_p0(other.p0());
+        self.set_f1(other.f1());
+        self.set_p1(other.p1());
+        self.set_f2(other.f2());
+        self.set_p2(other.p2());
+        self.set_f3(other.f3());
+        self.set_p3(other.p3());
+        self.set_f4(other.f4());
+        self.set_p4(other.p4());
+        self.set_f5(other.f5());
+        self.set_p5(other.p5());
+        self.set_f6(other.f6());
+        self.set_p6(other.p6());
+        self.set_f7(other.f7());
+    }
+}
+
+/// Owned plain value of [`AlignedI64`] with a public member per field.
+///
+/// Converted from [`AlignedI64`] with `From`, and back with
+/// `TryFrom`, which checks that each value fits into the bit width of its field.
+///
+/// [`AlignedI64`]: struct.AlignedI64.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlignedI64Value {
+    #[allow(missing_docs)]
+    pub f0: i64,
+    #[allow(missing_docs)]
+    pub p0: u64,
+    #[allow(missing_docs)]
+    pub f1: i64,
+    #[allow(missing_docs)]
+    pub p1: u64,
+    #[allow(missing_docs)]
+    pub f2: i64,
+    #[allow(missing_docs)]
+    pub p2: u64,
+    #[allow(missing_docs)]
+    pub f3: i64,
+    #[allow(missing_docs)]
+    pub p3: u64,
+    #[allow(missing_docs)]
+    pub f4: i64,
+    #[allow(missing_docs)]
+    pub p4: u64,
+    #[allow(missing_docs)]
+    pub f5: i64,
+    #[allow(missing_docs)]
+    pub p5: u64,
+    #[allow(missing_docs)]
+    pub f6: i64,
+    #[allow(missing_docs)]
+    pub p6: u64,
+    #[allow(missing_docs)]
+    pub f7: i64,
+}
+
+impl From<&AlignedI64> for AlignedI64Value {
+    fn from(other: &AlignedI64) -> Self {
+        Self {
+            f0: other.f0(),
+            p0: other.p0(),
+            f1: other.f1(),
+            p1: other.p1(),
+            f2: other.f2(),
+            p2: other.p2(),
+            f3: other.f3(),
+            p3: other.p3(),
+            f4: other.f4(),
+            p4: other.p4(),
+            f5: other.f5(),
+            p5: other.p5(),
+            f6: other.f6(),
+            p6: other.p6(),
+            f7: other.f7(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<AlignedI64Value> for AlignedI64 {
+    type Error = crate::FieldOverflowError;
+
+    fn try_from(value: AlignedI64Value) -> Result<Self, Self::Error> {
+        use crate::helper::check_bits;
+        check_bits("AlignedI64", "f0", value.f0, 64)?;
+        check_bits("AlignedI64", "p0", value.p0, 8)?;
+        check_bits("AlignedI64", "f1", value.f1, 64)?;
+        check_bits("AlignedI64", "p1", value.p1, 8)?;
+        check_bits("AlignedI64", "f2", value.f2, 64)?;
+        check_bits("AlignedI64", "p2", value.p2, 8)?;
+        check_bits("AlignedI64", "f3", value.f3, 64)?;
+        check_bits("AlignedI64", "p3", value.p3, 8)?;
+        check_bits("AlignedI64", "f4", value.f4, 64)?;
+        check_bits("AlignedI64", "p4", value.p4, 8)?;
+        check_bits("AlignedI64", "f5", value.f5, 64)?;
+        check_bits("AlignedI64", "p5", value.p5, 8)?;
+        check_bits("AlignedI64", "f6", value.f6, 64)?;
+        check_bits("AlignedI64", "p6", value.p6, 8)?;
+        check_bits("AlignedI64", "f7", value.f7, 64)?;
+        let mut result = Self::new();
+        result.set_f0(value.f0);
+        result.set_p0(value.p0);
+        result.set_f1(value.f1);
+        result.set_p1(value.p1);
+        result.set_f2(value.f2);
+        result.set_p2(value.p2);
+        result.set_f3(value.f3);
+        result.set_p3(value.p3);
+        result.set_f4(value.f4);
+        result.set_p4(value.p4);
+        result.set_f5(value.f5);
+        result.set_p5(value.p5);
+        result.set_f6(value.f6);
+        result.set_p6(value.p6);
+        result.set_f7(value.f7);
+        Ok(result)
+    }
+}
+
+#[repr(transparent)]
+pub struct R {
+    data: [u8; 4],
+}
+
+impl R {
+    /// Unsafe since the struct might not be self-contained
+    pub unsafe fn new_unchecked( ) -> Self {
+        Self{data : [0; 4]}
+    }
+}
+
+impl crate::Struct for R {
+    unsafe fn create_unchecked( ) -> Self {
+        Self{data : [0; 4]}
+    }
+
+    const SIZE_IN_BYTES: usize = 4;
+    const IS_OVERLAPPING_WITH_NEXT : bool = true;
+
+    #[inline]
+    fn has_valid_ranges(&self) -> bool {
+        let x = self.x();
+        x.start <= x.end
+    }
+
+    #[inline]
+    fn copy_rebased(&mut self, next: &mut Self, source: &Self) {
+        let ranges_0 = (self.first_x(), source.x());
+        self.data.copy_from_slice(&source.data);
+        let (start, range) = ranges_0;
+        self.set_first_x(start);
+        next.set_first_x(start.wrapping_add(range.end.wrapping_sub(range.start)));
+    }
+}
+
+impl crate::StructReflect for R {
+    const NAME: &'static str = "R";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "first_x",
+            primitive_type: "u32",
+            offset: 0,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: Some("x"),
+        },
+        crate::FieldInfo {
+            name: "y",
+            primitive_type: "u32",
+            offset: 16,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: None,
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "first_x" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16) as u64),
+            "y" => Some(flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16) as u64),
+            _ => None,
+        }
+    }
+}
+
+impl crate::Overlap for R {}
+
+impl R {
+    /// First element of the range [`x`].
+    ///
+    /// [`x`]: #method.x
+    #[inline]
+    pub fn first_x(&self) -> u32 {
+        flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16)
+    }
+
+    #[inline]
+    pub fn x(&self) -> std::ops::Range<u32> {
+        let start = flatdata_read_bytes!(u32, self.data.as_ptr(), 0, 16);
+        let end = flatdata_read_bytes!(u32, self.data.as_ptr(), 0 + 4 * 8, 16);
+        start..end
+    }
+
+    #[inline]
+    pub fn y(&self) -> u32 {
+        flatdata_read_bytes!(u32, self.data.as_ptr(), 16, 16)
+    }
+
+}
+
+impl R {
+    /// Decodes the raw values of the field [`first_x`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`first_x`]: #method.first_x
+    #[inline]
+    pub fn decode_first_x_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: R is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(4).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[0..2]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0xffff) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`first_x`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_first_x_into`].
+    ///
+    /// [`first_x`]: #method.first_x
+    /// [`decode_first_x_into`]: #method.decode_first_x_into
+    pub fn decode_first_x(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_first_x_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`first_x`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`first_x`]: #method.first_x
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_first_x(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+    /// Decodes the raw values of the field [`y`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`y`]: #method.y
+    #[inline]
+    pub fn decode_y_into(data: &[Self], out: &mut [u32]) {
+        let out = &mut out[..data.len()];
+        // Safety: R is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(4).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[2..4]);
+            let raw = u64::from_le_bytes(window);
+            *value = (raw & 0xffff) as u32;
+        }
+    }
+
+    /// Decodes the raw values of the field [`y`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_y_into`].
+    ///
+    /// [`y`]: #method.y
+    /// [`decode_y_into`]: #method.decode_y_into
+    pub fn decode_y(data: &[Self]) -> Vec<u32> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_y_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`y`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`y`]: #method.y
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_y(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 16, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
+impl std::fmt::Debug for R {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("R")
+            .field("first_x", &self.first_x())
+            .field("y", &self.y())
+            .finish()
+    }
+}
+
+impl std::cmp::PartialEq for R {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.first_x() == other.first_x() &&        self.y() == other.y()     }
+}
+
+impl std::cmp::Eq for R {}
+
+impl std::hash::Hash for R {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..3]);
+        state.write_u8(self.data[3]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for R {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_first_x = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 0, 16)
+        };
+        let raw_y = |item: &Self| -> u32 {
+            flatdata_read_bytes!(u32, item.data.as_ptr(), 16, 16)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_first_x(self).cmp(&raw_first_x(other)))
+            .then_with(|| raw_y(self).cmp(&raw_y(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for R {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for R {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("R", 2)?;
+        s.serialize_field("first_x", &self.first_x())?;
+        s.serialize_field("y", &self.y())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for R {
+    const NAME: &'static str = "R";
+    const FIELDS: &'static [&'static str] = &["first_x", "y", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_first_x(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            1 => self.try_set_y(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+}
+
+impl R {
+    /// First element of the range [`x`].
+    ///
+    /// [`x`]: struct.RRef.html#method.x
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_first_x`] to check it in release builds.
+    ///
+    /// [`try_set_first_x`]: #method.try_set_first_x
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_first_x(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of R::first_x does not fit into 16 bits", value
+        );
+        flatdata_write_bytes!(u32; value, self.data, 0, 16)
+    }
+
+    /// Sets [`first_x`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`first_x`]: #method.first_x
+    #[inline]
+    pub fn try_set_first_x(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("R", "first_x", value, 16)?;
+        self.set_first_x(value);
+        Ok(())
+    }
+
+    /// Sets the value of [`y`].
+    ///
+    /// [`y`]: #method.y
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_y`] to check it in release builds.
+    ///
+    /// [`try_set_y`]: #method.try_set_y
+    #[inline]
+    #[allow(missing_docs)]
+    pub fn set_y(&mut self, value: u32) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of R::y does not fit into 16 bits", value
+        );
+        flatdata_write_bytes!(u32; value, self.data, 16, 16)
+    }
+
+    /// Sets [`y`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`y`]: #method.y
+    #[inline]
+    pub fn try_set_y(&mut self, value: u32) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("R", "y", value, 16)?;
+        self.set_y(value);
+        Ok(())
+    }
+
+    /// Copies the data from `other` into this struct.
+    #[inline]
+    pub fn fill_from(&mut self, other: &R) {
+        self.set_first_x(other.first_x());
         self.set_y(other.y());
     }
 }
-#[derive(Debug, PartialEq, Eq)]
+
+/// Owned plain value of [`R`] with a public member per field.
+///
+/// Converted from [`R`] with `From`.
+///
+/// [`R`]: struct.R.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RValue {
+    #[allow(missing_docs)]
+    pub first_x: u32,
+    #[allow(missing_docs)]
+    pub y: u32,
+}
+
+impl From<&R> for RValue {
+    fn from(other: &R) -> Self {
+        Self {
+            first_x: other.first_x(),
+            y: other.y(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum E {
     Value = 0,
@@ -355,6 +15898,27 @@ impl crate::helper::Int for E {
 }
 
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for E {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        match *self {
+            E::Value => serializer.serialize_unit_variant("E", 0, "Value"),
+            E::UnknownValue1 => serializer.serialize_unit_variant("E", 1, "UnknownValue1"),
+        }
+    }
+}
+
+impl<'de> crate::serde::Deserialize<'de> for E {
+    fn deserialize<__D: crate::serde::Deserializer<'de>>(deserializer: __D) -> Result<Self, __D::Error> {
+        const VARIANTS: &[&str] = &["Value", "UnknownValue1", ];
+        match crate::de::deserialize_unit_variant(deserializer, "E", VARIANTS)? {
+            0 => Ok(E::Value),
+            1 => Ok(E::UnknownValue1),
+            _ => unreachable!(),
+        }
+    }
+}
+}
 
 #[derive(Clone)]
 pub struct S {
@@ -363,6 +15927,17 @@ pub struct S {
 }
 
 impl S {
+    /// Descriptions of all resources of this archive in the order of their definition.
+    pub const RESOURCES: &'static [crate::ResourceInfo] = &[
+        crate::ResourceInfo {
+            name: "data",
+            kind: crate::ResourceKind::Instance,
+            element_types: &[".test.A"],
+            is_optional: false,
+            schema: schema::s::resources::DATA,
+        },
+    ];
+
     fn signature_name(archive_name: &str) -> String {
         format!("{}.archive", archive_name)
     }
@@ -382,6 +15957,17 @@ impl ::std::fmt::Debug for S {
     }
 }
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for S {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("data", &self.data())?;
+        map.end()
+    }
+}
+}
+
 impl S {
     pub fn open(storage: crate::StorageHandle)
         -> ::std::result::Result<Self, crate::ResourceStorageError>
@@ -410,12 +15996,80 @@ impl S {
     }
 }
 
+/// Mutable view of [`S`] archives on disk for modifying their data in place.
+///
+/// Gives mutable access to the vectors of structs without ranges and to the
+/// subarchives. Other resources cannot be modified. Changes are written to disk at
+/// the latest when the view is dropped, cf. [`flush`].
+///
+/// [`S`]: struct.S.html
+/// [`flush`]: #method.flush
+pub struct SMut {
+    _storage: ::std::sync::Arc<crate::FileResourceStorageMut>,
+}
+
+impl SMut {
+    /// Opens the archive for modification.
+    ///
+    /// The archive and its resources are checked exactly as by [`S::open`].
+    ///
+    /// [`S::open`]: struct.S.html#method.open
+    pub fn open(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        // check the signature and all resources, including those which cannot be modified
+        S::open(crate::FileResourceStorage::new(storage.path()))?;
+        Self::open_checked(storage)
+    }
+
+    /// Opens an archive for modification which was already checked by [`S::open`].
+    ///
+    /// Used for opening subarchives, which are checked together with their parent.
+    ///
+    /// [`S::open`]: struct.S.html#method.open
+    #[doc(hidden)]
+    pub fn open_checked(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        #[allow(unused_imports)]
+        use crate::SliceExtMut;
+        use crate::ResourceStorageError as Error;
+        // extend lifetime since Rust cannot know that we reference a mapping owned by the storage
+        #[allow(unused_variables)]
+        let extend = |x : Result<&mut [u8], Error>| -> Result<&'static mut [u8], Error> {x.map(|x| unsafe{std::mem::transmute(x)})};
+
+        // missing optional subarchives were accepted by the check
+        if !storage.exists("S.archive") {
+            return Err(Error::Missing);
+        }
+
+        Ok(Self {
+            _storage: storage,
+        })
+    }
+
+    /// Synchronously writes all modifications of the archive and its subarchives to disk.
+    pub fn flush(&self) -> ::std::io::Result<()> {
+        self._storage.flush()?;
+        Ok(())
+    }
+}
+
+impl ::std::fmt::Debug for SMut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("SMut")
+            .finish()
+    }
+}
+
 /// Builder for creating [`S`] archives.
 ///
 ///[`S`]: struct.S.html
 #[derive(Clone, Debug)]
 pub struct SBuilder {
-    storage: crate::StorageHandle
+    storage: crate::StorageHandle,
+    strict: bool,
+    buffering: Option<crate::WriteBuffering>,
 }
 
 impl SBuilder {
@@ -431,12 +16085,86 @@ impl SBuilder {
 
 }
 
+crate::flatdata_serde! {
+impl crate::de::DeserializeResources for SBuilder {
+    const NAME: &'static str = "S";
+    const RESOURCES: &'static [&'static str] = &["data", ];
+
+    fn deserialize_resource<'de, __D: crate::serde::Deserializer<'de>>(&self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        match index {
+            0 => self.set_data(&crate::serde::Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl SBuilder {
+    /// Writes all resources of the archive from the given deserializer.
+    ///
+    /// The expected input has the same layout as the output of the `Serialize`
+    /// implementation of [`S`]: a map from resource names to their
+    /// data. Resources which are not present or `null` are not written.
+    ///
+    /// [`S`]: struct.S.html
+    pub fn deserialize<'de, __D: crate::serde::Deserializer<'de>>(&self, deserializer: __D) -> Result<(), __D::Error> {
+        crate::de::deserialize_archive(self, deserializer)
+    }
+}
+}
+
 impl SBuilder {
     pub fn new(
         storage: crate::StorageHandle,
     ) -> Result<Self, crate::ResourceStorageError> {
         crate::create_archive("S", schema::s::S, &storage)?;
-        Ok(Self { storage })
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Opens an existing archive for adding data to it, e.g. by appending to its
+    /// vectors and multivectors.
+    ///
+    /// The stored signature of the archive is checked like by [`S::open`].
+    ///
+    /// [`S::open`]: struct.S.html#method.open
+    pub fn open(
+        storage: crate::StorageHandle,
+    ) -> Result<Self, crate::ResourceStorageError> {
+        crate::open_archive("S", schema::s::S, &storage)?;
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Enables or disables the strict mode of this builder.
+    ///
+    /// In strict mode, vector resources of structs with ranges and the indexes of
+    /// multivector resources are checked for ranges ending before they start before
+    /// they are written, which detects values truncated to the width of their fields.
+    /// Offsets stored in the indexes of multivectors are checked to fit into the
+    /// width of the index. A resource failing the checks is not finalized. The mode
+    /// is inherited by the builders of subarchives opened afterwards.
+    ///
+    /// Range fields set by the user are not checked against the resources they refer
+    /// to; use the `try_set_*` setters to detect values exceeding the width of a field.
+    ///
+    /// See also [`check_ranges`].
+    ///
+    /// [`check_ranges`]: flatdata/fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets how much data the vectors and multivectors started afterwards buffer
+    /// before flushing it to storage. The buffering is inherited by the builders of
+    /// subarchives opened afterwards, so a [`MemoryBudget`] set here limits the
+    /// buffers of the whole archive. Without explicit buffering, the default at the
+    /// time a resource is started is used (cf. [`WriteBuffering::set_default`]).
+    ///
+    /// See also [`WriteBuffering`].
+    ///
+    /// [`MemoryBudget`]: flatdata/struct.MemoryBudget.html
+    /// [`WriteBuffering`]: flatdata/struct.WriteBuffering.html
+    /// [`WriteBuffering::set_default`]: flatdata/struct.WriteBuffering.html#method.set_default
+    pub fn set_buffering(&mut self, buffering: crate::WriteBuffering) {
+        self.buffering = Some(buffering);
     }
 }
 
@@ -450,6 +16178,17 @@ pub struct X {
 }
 
 impl X {
+    /// Descriptions of all resources of this archive in the order of their definition.
+    pub const RESOURCES: &'static [crate::ResourceInfo] = &[
+        crate::ResourceInfo {
+            name: "data",
+            kind: crate::ResourceKind::Vector,
+            element_types: &[".test.A"],
+            is_optional: false,
+            schema: schema::x::resources::DATA,
+        },
+    ];
+
     fn signature_name(archive_name: &str) -> String {
         format!("{}.archive", archive_name)
     }
@@ -469,6 +16208,17 @@ impl ::std::fmt::Debug for X {
     }
 }
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for X {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("data", &self.data())?;
+        map.end()
+    }
+}
+}
+
 impl X {
     pub fn open(storage: crate::StorageHandle)
         -> ::std::result::Result<Self, crate::ResourceStorageError>
@@ -497,12 +16247,95 @@ impl X {
     }
 }
 
+/// Mutable view of [`X`] archives on disk for modifying their data in place.
+///
+/// Gives mutable access to the vectors of structs without ranges and to the
+/// subarchives. Other resources cannot be modified. Changes are written to disk at
+/// the latest when the view is dropped, cf. [`flush`].
+///
+/// [`X`]: struct.X.html
+/// [`flush`]: #method.flush
+pub struct XMut {
+    _storage: ::std::sync::Arc<crate::FileResourceStorageMut>,
+    data : &'static mut [super::test::A],
+}
+
+impl XMut {
+    /// Opens the archive for modification.
+    ///
+    /// The archive and its resources are checked exactly as by [`X::open`].
+    ///
+    /// [`X::open`]: struct.X.html#method.open
+    pub fn open(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        // check the signature and all resources, including those which cannot be modified
+        X::open(crate::FileResourceStorage::new(storage.path()))?;
+        Self::open_checked(storage)
+    }
+
+    /// Opens an archive for modification which was already checked by [`X::open`].
+    ///
+    /// Used for opening subarchives, which are checked together with their parent.
+    ///
+    /// [`X::open`]: struct.X.html#method.open
+    #[doc(hidden)]
+    pub fn open_checked(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        #[allow(unused_imports)]
+        use crate::SliceExtMut;
+        use crate::ResourceStorageError as Error;
+        // extend lifetime since Rust cannot know that we reference a mapping owned by the storage
+        #[allow(unused_variables)]
+        let extend = |x : Result<&mut [u8], Error>| -> Result<&'static mut [u8], Error> {x.map(|x| unsafe{std::mem::transmute(x)})};
+
+        // missing optional subarchives were accepted by the check
+        if !storage.exists("X.archive") {
+            return Err(Error::Missing);
+        }
+
+        let data = {
+            use crate::check_resource as check;
+            let max_size = None;
+            let resource = extend(storage.read_mut("data", schema::x::resources::DATA));
+            check("data", |r| r.len(), max_size, resource.and_then(<&mut [super::test::A]>::from_bytes_mut))?
+        };
+
+        Ok(Self {
+            _storage: storage,
+            data,
+        })
+    }
+
+    #[inline]
+    pub fn data(&mut self) -> &mut [super::test::A] {
+        self.data
+    }
+
+    /// Synchronously writes all modifications of the archive and its subarchives to disk.
+    pub fn flush(&self) -> ::std::io::Result<()> {
+        self._storage.flush()?;
+        Ok(())
+    }
+}
+
+impl ::std::fmt::Debug for XMut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("XMut")
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
 /// Builder for creating [`X`] archives.
 ///
 ///[`X`]: struct.X.html
 #[derive(Clone, Debug)]
 pub struct XBuilder {
-    storage: crate::StorageHandle
+    storage: crate::StorageHandle,
+    strict: bool,
+    buffering: Option<crate::WriteBuffering>,
 }
 
 impl XBuilder {
@@ -525,9 +16358,58 @@ impl XBuilder {
     /// [`ExternalVector::close`]: flatdata/struct.ExternalVector.html#method.close
     #[inline]
     pub fn start_data(&self) -> ::std::io::Result<crate::ExternalVector<'_, super::test::A>> {
-        crate::create_external_vector(&*self.storage, "data", schema::x::resources::DATA)
+        let mut vector = crate::create_external_vector(&*self.storage, "data", schema::x::resources::DATA)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
+    }
+
+    /// Reopens [`data`] in the archive for appending to it.
+    ///
+    /// The stored schema of the resource is checked before. Elements can be added to
+    /// the vector like after [`start_data`].
+    ///
+    /// [`data`]: struct.X.html#method.data
+    /// [`start_data`]: #method.start_data
+    #[inline]
+    pub fn append_data(&self) -> Result<crate::ExternalVector<'_, super::test::A>, crate::ResourceStorageError> {
+        let mut vector = crate::append_external_vector(&*self.storage, "data", schema::x::resources::DATA)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
+    }
+
+}
+
+crate::flatdata_serde! {
+impl crate::de::DeserializeResources for XBuilder {
+    const NAME: &'static str = "X";
+    const RESOURCES: &'static [&'static str] = &["data", ];
+
+    fn deserialize_resource<'de, __D: crate::serde::Deserializer<'de>>(&self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        match index {
+            0 => crate::de::deserialize_vector(self.start_data().map_err(crate::serde::de::Error::custom)?, deserializer),
+            _ => unreachable!(),
+        }
     }
+}
 
+impl XBuilder {
+    /// Writes all resources of the archive from the given deserializer.
+    ///
+    /// The expected input has the same layout as the output of the `Serialize`
+    /// implementation of [`X`]: a map from resource names to their
+    /// data. Resources which are not present or `null` are not written.
+    ///
+    /// [`X`]: struct.X.html
+    pub fn deserialize<'de, __D: crate::serde::Deserializer<'de>>(&self, deserializer: __D) -> Result<(), __D::Error> {
+        crate::de::deserialize_archive(self, deserializer)
+    }
+}
 }
 
 impl XBuilder {
@@ -535,7 +16417,54 @@ impl XBuilder {
         storage: crate::StorageHandle,
     ) -> Result<Self, crate::ResourceStorageError> {
         crate::create_archive("X", schema::x::X, &storage)?;
-        Ok(Self { storage })
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Opens an existing archive for adding data to it, e.g. by appending to its
+    /// vectors and multivectors.
+    ///
+    /// The stored signature of the archive is checked like by [`X::open`].
+    ///
+    /// [`X::open`]: struct.X.html#method.open
+    pub fn open(
+        storage: crate::StorageHandle,
+    ) -> Result<Self, crate::ResourceStorageError> {
+        crate::open_archive("X", schema::x::X, &storage)?;
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Enables or disables the strict mode of this builder.
+    ///
+    /// In strict mode, vector resources of structs with ranges and the indexes of
+    /// multivector resources are checked for ranges ending before they start before
+    /// they are written, which detects values truncated to the width of their fields.
+    /// Offsets stored in the indexes of multivectors are checked to fit into the
+    /// width of the index. A resource failing the checks is not finalized. The mode
+    /// is inherited by the builders of subarchives opened afterwards.
+    ///
+    /// Range fields set by the user are not checked against the resources they refer
+    /// to; use the `try_set_*` setters to detect values exceeding the width of a field.
+    ///
+    /// See also [`check_ranges`].
+    ///
+    /// [`check_ranges`]: flatdata/fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets how much data the vectors and multivectors started afterwards buffer
+    /// before flushing it to storage. The buffering is inherited by the builders of
+    /// subarchives opened afterwards, so a [`MemoryBudget`] set here limits the
+    /// buffers of the whole archive. Without explicit buffering, the default at the
+    /// time a resource is started is used (cf. [`WriteBuffering::set_default`]).
+    ///
+    /// See also [`WriteBuffering`].
+    ///
+    /// [`MemoryBudget`]: flatdata/struct.MemoryBudget.html
+    /// [`WriteBuffering`]: flatdata/struct.WriteBuffering.html
+    /// [`WriteBuffering::set_default`]: flatdata/struct.WriteBuffering.html#method.set_default
+    pub fn set_buffering(&mut self, buffering: crate::WriteBuffering) {
+        self.buffering = Some(buffering);
     }
 }
 
@@ -549,6 +16478,17 @@ pub struct Y {
 }
 
 impl Y {
+    /// Descriptions of all resources of this archive in the order of their definition.
+    pub const RESOURCES: &'static [crate::ResourceInfo] = &[
+        crate::ResourceInfo {
+            name: "data",
+            kind: crate::ResourceKind::Vector,
+            element_types: &[".test.R"],
+            is_optional: false,
+            schema: schema::y::resources::DATA,
+        },
+    ];
+
     fn signature_name(archive_name: &str) -> String {
         format!("{}.archive", archive_name)
     }
@@ -568,6 +16508,17 @@ impl ::std::fmt::Debug for Y {
     }
 }
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for Y {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("data", &crate::WithSentinel(self.data()))?;
+        map.end()
+    }
+}
+}
+
 impl Y {
     pub fn open(storage: crate::StorageHandle)
         -> ::std::result::Result<Self, crate::ResourceStorageError>
@@ -596,12 +16547,80 @@ impl Y {
     }
 }
 
+/// Mutable view of [`Y`] archives on disk for modifying their data in place.
+///
+/// Gives mutable access to the vectors of structs without ranges and to the
+/// subarchives. Other resources cannot be modified. Changes are written to disk at
+/// the latest when the view is dropped, cf. [`flush`].
+///
+/// [`Y`]: struct.Y.html
+/// [`flush`]: #method.flush
+pub struct YMut {
+    _storage: ::std::sync::Arc<crate::FileResourceStorageMut>,
+}
+
+impl YMut {
+    /// Opens the archive for modification.
+    ///
+    /// The archive and its resources are checked exactly as by [`Y::open`].
+    ///
+    /// [`Y::open`]: struct.Y.html#method.open
+    pub fn open(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        // check the signature and all resources, including those which cannot be modified
+        Y::open(crate::FileResourceStorage::new(storage.path()))?;
+        Self::open_checked(storage)
+    }
+
+    /// Opens an archive for modification which was already checked by [`Y::open`].
+    ///
+    /// Used for opening subarchives, which are checked together with their parent.
+    ///
+    /// [`Y::open`]: struct.Y.html#method.open
+    #[doc(hidden)]
+    pub fn open_checked(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        #[allow(unused_imports)]
+        use crate::SliceExtMut;
+        use crate::ResourceStorageError as Error;
+        // extend lifetime since Rust cannot know that we reference a mapping owned by the storage
+        #[allow(unused_variables)]
+        let extend = |x : Result<&mut [u8], Error>| -> Result<&'static mut [u8], Error> {x.map(|x| unsafe{std::mem::transmute(x)})};
+
+        // missing optional subarchives were accepted by the check
+        if !storage.exists("Y.archive") {
+            return Err(Error::Missing);
+        }
+
+        Ok(Self {
+            _storage: storage,
+        })
+    }
+
+    /// Synchronously writes all modifications of the archive and its subarchives to disk.
+    pub fn flush(&self) -> ::std::io::Result<()> {
+        self._storage.flush()?;
+        Ok(())
+    }
+}
+
+impl ::std::fmt::Debug for YMut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("YMut")
+            .finish()
+    }
+}
+
 /// Builder for creating [`Y`] archives.
 ///
 ///[`Y`]: struct.Y.html
 #[derive(Clone, Debug)]
 pub struct YBuilder {
-    storage: crate::StorageHandle
+    storage: crate::StorageHandle,
+    strict: bool,
+    buffering: Option<crate::WriteBuffering>,
 }
 
 impl YBuilder {
@@ -611,6 +16630,10 @@ impl YBuilder {
     /// [`data`]: struct.Y.html#method.data
     pub fn set_data(&self, vector: &[super::test::R]) -> ::std::io::Result<()> {
         use crate::SliceExt;
+        if self.strict {
+            crate::check_ranges("data", vector)
+                .map_err(|e| ::std::io::Error::new(::std::io::ErrorKind::InvalidData, e))?;
+        }
         self.storage.write("data", schema::y::resources::DATA, vector.as_bytes())
     }
 
@@ -624,9 +16647,59 @@ impl YBuilder {
     /// [`ExternalVector::close`]: flatdata/struct.ExternalVector.html#method.close
     #[inline]
     pub fn start_data(&self) -> ::std::io::Result<crate::ExternalVector<'_, super::test::R>> {
-        crate::create_external_vector(&*self.storage, "data", schema::y::resources::DATA)
+        let mut vector = crate::create_external_vector(&*self.storage, "data", schema::y::resources::DATA)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
     }
 
+    /// Reopens [`data`] in the archive for appending to it.
+    ///
+    /// The stored schema of the resource is checked before. Elements can be added to
+    /// the vector like after [`start_data`], which includes adding a new sentinel,
+    /// since the stored one is removed.
+    ///
+    /// [`data`]: struct.Y.html#method.data
+    /// [`start_data`]: #method.start_data
+    #[inline]
+    pub fn append_data(&self) -> Result<crate::ExternalVector<'_, super::test::R>, crate::ResourceStorageError> {
+        let mut vector = crate::append_external_vector(&*self.storage, "data", schema::y::resources::DATA)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
+    }
+
+}
+
+crate::flatdata_serde! {
+impl crate::de::DeserializeResources for YBuilder {
+    const NAME: &'static str = "Y";
+    const RESOURCES: &'static [&'static str] = &["data", ];
+
+    fn deserialize_resource<'de, __D: crate::serde::Deserializer<'de>>(&self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        match index {
+            0 => crate::de::deserialize_vector(self.start_data().map_err(crate::serde::de::Error::custom)?, deserializer),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl YBuilder {
+    /// Writes all resources of the archive from the given deserializer.
+    ///
+    /// The expected input has the same layout as the output of the `Serialize`
+    /// implementation of [`Y`]: a map from resource names to their
+    /// data. Resources which are not present or `null` are not written.
+    ///
+    /// [`Y`]: struct.Y.html
+    pub fn deserialize<'de, __D: crate::serde::Deserializer<'de>>(&self, deserializer: __D) -> Result<(), __D::Error> {
+        crate::de::deserialize_archive(self, deserializer)
+    }
+}
 }
 
 impl YBuilder {
@@ -634,7 +16707,54 @@ impl YBuilder {
         storage: crate::StorageHandle,
     ) -> Result<Self, crate::ResourceStorageError> {
         crate::create_archive("Y", schema::y::Y, &storage)?;
-        Ok(Self { storage })
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Opens an existing archive for adding data to it, e.g. by appending to its
+    /// vectors and multivectors.
+    ///
+    /// The stored signature of the archive is checked like by [`Y::open`].
+    ///
+    /// [`Y::open`]: struct.Y.html#method.open
+    pub fn open(
+        storage: crate::StorageHandle,
+    ) -> Result<Self, crate::ResourceStorageError> {
+        crate::open_archive("Y", schema::y::Y, &storage)?;
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Enables or disables the strict mode of this builder.
+    ///
+    /// In strict mode, vector resources of structs with ranges and the indexes of
+    /// multivector resources are checked for ranges ending before they start before
+    /// they are written, which detects values truncated to the width of their fields.
+    /// Offsets stored in the indexes of multivectors are checked to fit into the
+    /// width of the index. A resource failing the checks is not finalized. The mode
+    /// is inherited by the builders of subarchives opened afterwards.
+    ///
+    /// Range fields set by the user are not checked against the resources they refer
+    /// to; use the `try_set_*` setters to detect values exceeding the width of a field.
+    ///
+    /// See also [`check_ranges`].
+    ///
+    /// [`check_ranges`]: flatdata/fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets how much data the vectors and multivectors started afterwards buffer
+    /// before flushing it to storage. The buffering is inherited by the builders of
+    /// subarchives opened afterwards, so a [`MemoryBudget`] set here limits the
+    /// buffers of the whole archive. Without explicit buffering, the default at the
+    /// time a resource is started is used (cf. [`WriteBuffering::set_default`]).
+    ///
+    /// See also [`WriteBuffering`].
+    ///
+    /// [`MemoryBudget`]: flatdata/struct.MemoryBudget.html
+    /// [`WriteBuffering`]: flatdata/struct.WriteBuffering.html
+    /// [`WriteBuffering::set_default`]: flatdata/struct.WriteBuffering.html#method.set_default
+    pub fn set_buffering(&mut self, buffering: crate::WriteBuffering) {
+        self.buffering = Some(buffering);
     }
 }
 
@@ -659,6 +16779,17 @@ impl<'a> ::std::fmt::Debug for AbRef<'a> {
     }
 }
 
+crate::flatdata_serde! {
+impl<'a> crate::serde::Serialize for AbRef<'a> {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        match *self {
+            AbRef::A(inner) => serializer.serialize_newtype_variant("AbRef", 0, "A", inner),
+            AbRef::B(inner) => serializer.serialize_newtype_variant("AbRef", 1, "B", inner),
+        }
+    }
+}
+}
+
 impl<'a> crate::VariadicRef for AbRef<'a> {
     #[inline]
     fn size_in_bytes(&self) -> usize {
@@ -706,6 +16837,21 @@ impl<'a> AbBuilder<'a> {
     }
 }
 
+crate::flatdata_serde! {
+impl<'a> crate::de::DeserializeVariant for AbBuilder<'a> {
+    const NAME: &'static str = "AbRef";
+    const VARIANTS: &'static [&'static str] = &["A", "B", ];
+
+    fn deserialize_variant<'de, __A: crate::serde::de::VariantAccess<'de>>(&mut self, index: usize, variant: __A) -> Result<(), __A::Error> {
+        match index {
+            0 => variant.newtype_variant_seed(crate::de::StructSeed(self.add_a())),
+            1 => variant.newtype_variant_seed(crate::de::StructSeed(self.add_b())),
+            _ => unreachable!(),
+        }
+    }
+}
+}
+
 /// Variadic struct attached to the [`ab`] archive resource.
 ///
 /// It unifies the following data types:
@@ -755,6 +16901,68 @@ impl<'a> crate::VariadicStruct<'a> for Ab {
     }
 }
 
+impl crate::VariadicReflect for Ab {
+    const VARIANTS: &'static [crate::VariantInfo] = &[
+        crate::VariantInfo {
+            name: ".test.A",
+            size_in_bytes: <super::test::A as crate::Struct>::SIZE_IN_BYTES,
+            fields: <super::test::A as crate::StructReflect>::FIELDS,
+        },
+        crate::VariantInfo {
+            name: ".test.B",
+            size_in_bytes: <super::test::B as crate::Struct>::SIZE_IN_BYTES,
+            fields: <super::test::B as crate::StructReflect>::FIELDS,
+        },
+    ];
+}
+
+impl crate::VariantOf<Ab> for super::test::A {
+    #[inline]
+    fn from_variant<'a>(item: <Ab as crate::VariadicStruct<'a>>::Item) -> Option<&'a Self> {
+        match item {
+            AbRef::A(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl crate::VariantOf<Ab> for super::test::B {
+    #[inline]
+    fn from_variant<'a>(item: <Ab as crate::VariadicStruct<'a>>::Item) -> Option<&'a Self> {
+        match item {
+            AbRef::B(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Typed access to the elements of a bucket in the [`ab`] resource.
+///
+/// Implemented for the buckets returned by the [`ab`] multiarray view.
+///
+/// [`ab`]: struct.Archive{.test.Z}.html#method.ab
+pub trait AbBucket<'a> {
+    /// Returns an iterator through the elements of the type [`A`] in the bucket.
+    ///
+    /// [`A`]: struct.A.html
+    fn as_(&self) -> crate::MultiArrayViewVariantIter<'a, Ab, super::test::A>;
+    /// Returns an iterator through the elements of the type [`B`] in the bucket.
+    ///
+    /// [`B`]: struct.B.html
+    fn bs(&self) -> crate::MultiArrayViewVariantIter<'a, Ab, super::test::B>;
+}
+
+impl<'a> AbBucket<'a> for crate::MultiArrayViewItemIter<'a, Ab> {
+    #[inline]
+    fn as_(&self) -> crate::MultiArrayViewVariantIter<'a, Ab, super::test::A> {
+        self.clone().iter_variant()
+    }
+    #[inline]
+    fn bs(&self) -> crate::MultiArrayViewVariantIter<'a, Ab, super::test::B> {
+        self.clone().iter_variant()
+    }
+}
+
 #[derive(Clone)]
 pub struct Z {
     _storage: crate::StorageHandle,
@@ -762,6 +16970,17 @@ pub struct Z {
 }
 
 impl Z {
+    /// Descriptions of all resources of this archive in the order of their definition.
+    pub const RESOURCES: &'static [crate::ResourceInfo] = &[
+        crate::ResourceInfo {
+            name: "ab",
+            kind: crate::ResourceKind::MultiVector,
+            element_types: &[".test.A", ".test.B", ],
+            is_optional: false,
+            schema: schema::z::resources::AB,
+        },
+    ];
+
     fn signature_name(archive_name: &str) -> String {
         format!("{}.archive", archive_name)
     }
@@ -781,6 +17000,17 @@ impl ::std::fmt::Debug for Z {
     }
 }
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for Z {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("ab", &self.ab())?;
+        map.end()
+    }
+}
+}
+
 impl Z {
     pub fn open(storage: crate::StorageHandle)
         -> ::std::result::Result<Self, crate::ResourceStorageError>
@@ -825,12 +17055,80 @@ impl Z {
     }
 }
 
+/// Mutable view of [`Z`] archives on disk for modifying their data in place.
+///
+/// Gives mutable access to the vectors of structs without ranges and to the
+/// subarchives. Other resources cannot be modified. Changes are written to disk at
+/// the latest when the view is dropped, cf. [`flush`].
+///
+/// [`Z`]: struct.Z.html
+/// [`flush`]: #method.flush
+pub struct ZMut {
+    _storage: ::std::sync::Arc<crate::FileResourceStorageMut>,
+}
+
+impl ZMut {
+    /// Opens the archive for modification.
+    ///
+    /// The archive and its resources are checked exactly as by [`Z::open`].
+    ///
+    /// [`Z::open`]: struct.Z.html#method.open
+    pub fn open(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        // check the signature and all resources, including those which cannot be modified
+        Z::open(crate::FileResourceStorage::new(storage.path()))?;
+        Self::open_checked(storage)
+    }
+
+    /// Opens an archive for modification which was already checked by [`Z::open`].
+    ///
+    /// Used for opening subarchives, which are checked together with their parent.
+    ///
+    /// [`Z::open`]: struct.Z.html#method.open
+    #[doc(hidden)]
+    pub fn open_checked(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        #[allow(unused_imports)]
+        use crate::SliceExtMut;
+        use crate::ResourceStorageError as Error;
+        // extend lifetime since Rust cannot know that we reference a mapping owned by the storage
+        #[allow(unused_variables)]
+        let extend = |x : Result<&mut [u8], Error>| -> Result<&'static mut [u8], Error> {x.map(|x| unsafe{std::mem::transmute(x)})};
+
+        // missing optional subarchives were accepted by the check
+        if !storage.exists("Z.archive") {
+            return Err(Error::Missing);
+        }
+
+        Ok(Self {
+            _storage: storage,
+        })
+    }
+
+    /// Synchronously writes all modifications of the archive and its subarchives to disk.
+    pub fn flush(&self) -> ::std::io::Result<()> {
+        self._storage.flush()?;
+        Ok(())
+    }
+}
+
+impl ::std::fmt::Debug for ZMut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("ZMut")
+            .finish()
+    }
+}
+
 /// Builder for creating [`Z`] archives.
 ///
 ///[`Z`]: struct.Z.html
 #[derive(Clone, Debug)]
 pub struct ZBuilder {
-    storage: crate::StorageHandle
+    storage: crate::StorageHandle,
+    strict: bool,
+    buffering: Option<crate::WriteBuffering>,
 }
 
 impl ZBuilder {
@@ -844,9 +17142,58 @@ impl ZBuilder {
     /// [`MultiVector::close`]: flatdata/struct.MultiVector.html#method.close
     #[inline]
     pub fn start_ab(&self) -> ::std::io::Result<crate::MultiVector<'_, Ab>> {
-        crate::create_multi_vector(&*self.storage, "ab", schema::z::resources::AB)
+        let mut vector = crate::create_multi_vector(&*self.storage, "ab", schema::z::resources::AB)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
+    }
+
+    /// Reopens [`ab`] in the archive for appending to it.
+    ///
+    /// The stored schemas of the data and the index are checked before. Items can be
+    /// added to the multivector like after [`start_ab`].
+    ///
+    /// [`ab`]: struct.Z.html#method.ab
+    /// [`start_ab`]: #method.start_ab
+    #[inline]
+    pub fn append_ab(&self) -> Result<crate::MultiVector<'_, Ab>, crate::ResourceStorageError> {
+        let mut vector = crate::append_multi_vector(&*self.storage, "ab", schema::z::resources::AB)?;
+        vector.set_strict(self.strict);
+        if let Some(buffering) = &self.buffering {
+            vector.set_buffering(buffering.clone());
+        }
+        Ok(vector)
+    }
+
+}
+
+crate::flatdata_serde! {
+impl crate::de::DeserializeResources for ZBuilder {
+    const NAME: &'static str = "Z";
+    const RESOURCES: &'static [&'static str] = &["ab", ];
+
+    fn deserialize_resource<'de, __D: crate::serde::Deserializer<'de>>(&self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        match index {
+            0 => crate::de::deserialize_multivector(self.start_ab().map_err(crate::serde::de::Error::custom)?, deserializer),
+            _ => unreachable!(),
+        }
     }
+}
 
+impl ZBuilder {
+    /// Writes all resources of the archive from the given deserializer.
+    ///
+    /// The expected input has the same layout as the output of the `Serialize`
+    /// implementation of [`Z`]: a map from resource names to their
+    /// data. Resources which are not present or `null` are not written.
+    ///
+    /// [`Z`]: struct.Z.html
+    pub fn deserialize<'de, __D: crate::serde::Deserializer<'de>>(&self, deserializer: __D) -> Result<(), __D::Error> {
+        crate::de::deserialize_archive(self, deserializer)
+    }
+}
 }
 
 impl ZBuilder {
@@ -854,7 +17201,54 @@ impl ZBuilder {
         storage: crate::StorageHandle,
     ) -> Result<Self, crate::ResourceStorageError> {
         crate::create_archive("Z", schema::z::Z, &storage)?;
-        Ok(Self { storage })
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Opens an existing archive for adding data to it, e.g. by appending to its
+    /// vectors and multivectors.
+    ///
+    /// The stored signature of the archive is checked like by [`Z::open`].
+    ///
+    /// [`Z::open`]: struct.Z.html#method.open
+    pub fn open(
+        storage: crate::StorageHandle,
+    ) -> Result<Self, crate::ResourceStorageError> {
+        crate::open_archive("Z", schema::z::Z, &storage)?;
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Enables or disables the strict mode of this builder.
+    ///
+    /// In strict mode, vector resources of structs with ranges and the indexes of
+    /// multivector resources are checked for ranges ending before they start before
+    /// they are written, which detects values truncated to the width of their fields.
+    /// Offsets stored in the indexes of multivectors are checked to fit into the
+    /// width of the index. A resource failing the checks is not finalized. The mode
+    /// is inherited by the builders of subarchives opened afterwards.
+    ///
+    /// Range fields set by the user are not checked against the resources they refer
+    /// to; use the `try_set_*` setters to detect values exceeding the width of a field.
+    ///
+    /// See also [`check_ranges`].
+    ///
+    /// [`check_ranges`]: flatdata/fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets how much data the vectors and multivectors started afterwards buffer
+    /// before flushing it to storage. The buffering is inherited by the builders of
+    /// subarchives opened afterwards, so a [`MemoryBudget`] set here limits the
+    /// buffers of the whole archive. Without explicit buffering, the default at the
+    /// time a resource is started is used (cf. [`WriteBuffering::set_default`]).
+    ///
+    /// See also [`WriteBuffering`].
+    ///
+    /// [`MemoryBudget`]: flatdata/struct.MemoryBudget.html
+    /// [`WriteBuffering`]: flatdata/struct.WriteBuffering.html
+    /// [`WriteBuffering::set_default`]: flatdata/struct.WriteBuffering.html#method.set_default
+    pub fn set_buffering(&mut self, buffering: crate::WriteBuffering) {
+        self.buffering = Some(buffering);
     }
 }
 
@@ -868,6 +17262,17 @@ pub struct W {
 }
 
 impl W {
+    /// Descriptions of all resources of this archive in the order of their definition.
+    pub const RESOURCES: &'static [crate::ResourceInfo] = &[
+        crate::ResourceInfo {
+            name: "blob",
+            kind: crate::ResourceKind::RawData,
+            element_types: &[],
+            is_optional: false,
+            schema: schema::w::resources::BLOB,
+        },
+    ];
+
     fn signature_name(archive_name: &str) -> String {
         format!("{}.archive", archive_name)
     }
@@ -887,6 +17292,17 @@ impl ::std::fmt::Debug for W {
     }
 }
 
+crate::flatdata_serde! {
+impl crate::serde::Serialize for W {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("blob", &self.blob())?;
+        map.end()
+    }
+}
+}
+
 impl W {
     pub fn open(storage: crate::StorageHandle)
         -> ::std::result::Result<Self, crate::ResourceStorageError>
@@ -915,12 +17331,80 @@ impl W {
     }
 }
 
+/// Mutable view of [`W`] archives on disk for modifying their data in place.
+///
+/// Gives mutable access to the vectors of structs without ranges and to the
+/// subarchives. Other resources cannot be modified. Changes are written to disk at
+/// the latest when the view is dropped, cf. [`flush`].
+///
+/// [`W`]: struct.W.html
+/// [`flush`]: #method.flush
+pub struct WMut {
+    _storage: ::std::sync::Arc<crate::FileResourceStorageMut>,
+}
+
+impl WMut {
+    /// Opens the archive for modification.
+    ///
+    /// The archive and its resources are checked exactly as by [`W::open`].
+    ///
+    /// [`W::open`]: struct.W.html#method.open
+    pub fn open(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        // check the signature and all resources, including those which cannot be modified
+        W::open(crate::FileResourceStorage::new(storage.path()))?;
+        Self::open_checked(storage)
+    }
+
+    /// Opens an archive for modification which was already checked by [`W::open`].
+    ///
+    /// Used for opening subarchives, which are checked together with their parent.
+    ///
+    /// [`W::open`]: struct.W.html#method.open
+    #[doc(hidden)]
+    pub fn open_checked(storage: ::std::sync::Arc<crate::FileResourceStorageMut>)
+        -> ::std::result::Result<Self, crate::ResourceStorageError>
+    {
+        #[allow(unused_imports)]
+        use crate::SliceExtMut;
+        use crate::ResourceStorageError as Error;
+        // extend lifetime since Rust cannot know that we reference a mapping owned by the storage
+        #[allow(unused_variables)]
+        let extend = |x : Result<&mut [u8], Error>| -> Result<&'static mut [u8], Error> {x.map(|x| unsafe{std::mem::transmute(x)})};
+
+        // missing optional subarchives were accepted by the check
+        if !storage.exists("W.archive") {
+            return Err(Error::Missing);
+        }
+
+        Ok(Self {
+            _storage: storage,
+        })
+    }
+
+    /// Synchronously writes all modifications of the archive and its subarchives to disk.
+    pub fn flush(&self) -> ::std::io::Result<()> {
+        self._storage.flush()?;
+        Ok(())
+    }
+}
+
+impl ::std::fmt::Debug for WMut {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct("WMut")
+            .finish()
+    }
+}
+
 /// Builder for creating [`W`] archives.
 ///
 ///[`W`]: struct.W.html
 #[derive(Clone, Debug)]
 pub struct WBuilder {
-    storage: crate::StorageHandle
+    storage: crate::StorageHandle,
+    strict: bool,
+    buffering: Option<crate::WriteBuffering>,
 }
 
 impl WBuilder {
@@ -932,6 +17416,50 @@ impl WBuilder {
         self.storage.write("blob", schema::w::resources::BLOB, data)
     }
 
+    /// Opens [`blob`] in the archive for buffered writing.
+    ///
+    /// Data can be written to the resource until the [`RawDataWriter::close`] method
+    /// is called. To flush the data fully into the archive, this method must be called
+    /// in the end.
+    ///
+    /// [`blob`]: struct.W.html#method.blob
+    /// [`RawDataWriter::close`]: flatdata/struct.RawDataWriter.html#method.close
+    #[inline]
+    pub fn start_blob(&self) -> ::std::io::Result<crate::RawDataWriter<'_>> {
+        let mut writer = crate::create_raw_data_writer(&*self.storage, "blob", schema::w::resources::BLOB)?;
+        if let Some(buffering) = &self.buffering {
+            writer.set_buffering(buffering.clone());
+        }
+        Ok(writer)
+    }
+
+}
+
+crate::flatdata_serde! {
+impl crate::de::DeserializeResources for WBuilder {
+    const NAME: &'static str = "W";
+    const RESOURCES: &'static [&'static str] = &["blob", ];
+
+    fn deserialize_resource<'de, __D: crate::serde::Deserializer<'de>>(&self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        match index {
+            0 => crate::de::deserialize_raw_data(self.start_blob().map_err(crate::serde::de::Error::custom)?, deserializer),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl WBuilder {
+    /// Writes all resources of the archive from the given deserializer.
+    ///
+    /// The expected input has the same layout as the output of the `Serialize`
+    /// implementation of [`W`]: a map from resource names to their
+    /// data. Resources which are not present or `null` are not written.
+    ///
+    /// [`W`]: struct.W.html
+    pub fn deserialize<'de, __D: crate::serde::Deserializer<'de>>(&self, deserializer: __D) -> Result<(), __D::Error> {
+        crate::de::deserialize_archive(self, deserializer)
+    }
+}
 }
 
 impl WBuilder {
@@ -939,7 +17467,54 @@ impl WBuilder {
         storage: crate::StorageHandle,
     ) -> Result<Self, crate::ResourceStorageError> {
         crate::create_archive("W", schema::w::W, &storage)?;
-        Ok(Self { storage })
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Opens an existing archive for adding data to it, e.g. by appending to its
+    /// vectors and multivectors.
+    ///
+    /// The stored signature of the archive is checked like by [`W::open`].
+    ///
+    /// [`W::open`]: struct.W.html#method.open
+    pub fn open(
+        storage: crate::StorageHandle,
+    ) -> Result<Self, crate::ResourceStorageError> {
+        crate::open_archive("W", schema::w::W, &storage)?;
+        Ok(Self { storage, strict: false, buffering: None })
+    }
+
+    /// Enables or disables the strict mode of this builder.
+    ///
+    /// In strict mode, vector resources of structs with ranges and the indexes of
+    /// multivector resources are checked for ranges ending before they start before
+    /// they are written, which detects values truncated to the width of their fields.
+    /// Offsets stored in the indexes of multivectors are checked to fit into the
+    /// width of the index. A resource failing the checks is not finalized. The mode
+    /// is inherited by the builders of subarchives opened afterwards.
+    ///
+    /// Range fields set by the user are not checked against the resources they refer
+    /// to; use the `try_set_*` setters to detect values exceeding the width of a field.
+    ///
+    /// See also [`check_ranges`].
+    ///
+    /// [`check_ranges`]: flatdata/fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Sets how much data the vectors and multivectors started afterwards buffer
+    /// before flushing it to storage. The buffering is inherited by the builders of
+    /// subarchives opened afterwards, so a [`MemoryBudget`] set here limits the
+    /// buffers of the whole archive. Without explicit buffering, the default at the
+    /// time a resource is started is used (cf. [`WriteBuffering::set_default`]).
+    ///
+    /// See also [`WriteBuffering`].
+    ///
+    /// [`MemoryBudget`]: flatdata/struct.MemoryBudget.html
+    /// [`WriteBuffering`]: flatdata/struct.WriteBuffering.html
+    /// [`WriteBuffering::set_default`]: flatdata/struct.WriteBuffering.html#method.set_default
+    pub fn set_buffering(&mut self, buffering: crate::WriteBuffering) {
+        self.buffering = Some(buffering);
     }
 }
 
@@ -1213,6 +17788,45 @@ impl crate::Struct for IndexType16 {
 
     const SIZE_IN_BYTES: usize = 2;
     const IS_OVERLAPPING_WITH_NEXT : bool = true;
+
+    #[inline]
+    fn has_valid_ranges(&self) -> bool {
+        let range = self.range();
+        range.start <= range.end
+    }
+
+    #[inline]
+    fn copy_rebased(&mut self, next: &mut Self, source: &Self) {
+        let ranges_0 = (self.value(), source.range());
+        self.data.copy_from_slice(&source.data);
+        let (start, range) = ranges_0;
+        self.set_value(start);
+        next.set_value(start.wrapping_add(range.end.wrapping_sub(range.start)));
+    }
+}
+
+impl crate::StructReflect for IndexType16 {
+    const NAME: &'static str = "IndexType16";
+    const FIELDS: &'static [crate::FieldInfo] = &[
+        crate::FieldInfo {
+            name: "value",
+            primitive_type: "u64",
+            offset: 0,
+            width: 16,
+            is_signed: false,
+            enum_type: None,
+            invalid_value: None,
+            range: Some("range"),
+        },
+    ];
+
+    #[inline]
+    fn get_field_u64(&self, name: &str) -> Option<u64> {
+        match name {
+            "value" => Some(flatdata_read_bytes!(u64, self.data.as_ptr(), 0, 16)),
+            _ => None,
+        }
+    }
 }
 
 impl crate::Overlap for IndexType16 {}
@@ -1223,8 +17837,7 @@ impl IndexType16 {
     /// [`range`]: #method.range
     #[inline]
     pub fn value(&self) -> u64 {
-        let value = flatdata_read_bytes!(u64, self.data.as_ptr(), 0, 16);
-        unsafe { std::mem::transmute::<u64, u64>(value) }
+        flatdata_read_bytes!(u64, self.data.as_ptr(), 0, 16)
     }
 
     #[inline]
@@ -1236,6 +17849,61 @@ impl IndexType16 {
 
 }
 
+impl IndexType16 {
+    /// Decodes the raw values of the field [`value`] of all structs in `data` into `out`.
+    ///
+    /// Values are not interpreted, i.e. enums are returned as their underlying value, and
+    /// missing optional values as their invalid value.
+    ///
+    /// The values are decoded directly from the contiguous bytes of `data` with a fixed
+    /// stride, without going through the getter of each struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `data`.
+    ///
+    /// [`value`]: #method.value
+    #[inline]
+    pub fn decode_value_into(data: &[Self], out: &mut [u64]) {
+        let out = &mut out[..data.len()];
+        // Safety: IndexType16 is repr(transparent), so the structs in data are contiguous bytes
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), std::mem::size_of_val(data)) };
+        for (chunk, value) in bytes.chunks_exact(2).zip(out.iter_mut()) {
+            let mut window = [0; 8];
+            window[..2].copy_from_slice(&chunk[0..2]);
+            let raw = u64::from_le_bytes(window);
+            *value = raw & 0xffff;
+        }
+    }
+
+    /// Decodes the raw values of the field [`value`] of all structs in `data`.
+    ///
+    /// Cf. [`decode_value_into`].
+    ///
+    /// [`value`]: #method.value
+    /// [`decode_value_into`]: #method.decode_value_into
+    pub fn decode_value(data: &[Self]) -> Vec<u64> {
+        let mut out = vec![Default::default(); data.len()];
+        Self::decode_value_into(data, &mut out);
+        out
+    }
+
+    /// Returns `true` if the raw values of the field [`value`] of all structs in `data`
+    /// are in non-decreasing order.
+    ///
+    /// Cf. [`SortedSliceExt`] for looking up structs in sorted data.
+    ///
+    /// [`value`]: #method.value
+    /// [`SortedSliceExt`]: flatdata/trait.SortedSliceExt.html
+    pub fn is_sorted_by_value(data: &[Self]) -> bool {
+        let value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 0, 16)
+        };
+        data.windows(2).all(|pair| value(&pair[0]) <= value(&pair[1]))
+    }
+
+}
+
 impl std::fmt::Debug for IndexType16 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.debug_struct("IndexType16")
@@ -1250,16 +17918,91 @@ impl std::cmp::PartialEq for IndexType16 {
         self.value() == other.value()     }
 }
 
+impl std::cmp::Eq for IndexType16 {}
+
+impl std::hash::Hash for IndexType16 {
+    /// Hashes the bytes of the struct, ignoring the padding bits of the last byte.
+    #[inline]
+    fn hash<__H: std::hash::Hasher>(&self, state: &mut __H) {
+        state.write(&self.data[..1]);
+        state.write_u8(self.data[1]);
+    }
+}
+
+crate::flatdata_ord! {
+impl std::cmp::Ord for IndexType16 {
+    /// Compares the raw values of the fields in the order of their declaration.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let raw_value = |item: &Self| -> u64 {
+            flatdata_read_bytes!(u64, item.data.as_ptr(), 0, 16)
+        };
+        std::cmp::Ordering::Equal
+            .then_with(|| raw_value(self).cmp(&raw_value(other)))
+    }
+}
+
+impl std::cmp::PartialOrd for IndexType16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+}
+
+crate::flatdata_serde! {
+impl crate::serde::Serialize for IndexType16 {
+    fn serialize<__S: crate::serde::Serializer>(&self, serializer: __S) -> Result<__S::Ok, __S::Error> {
+        use crate::serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("IndexType16", 1)?;
+        s.serialize_field("value", &self.value())?;
+        s.end()
+    }
+}
+
+impl crate::de::DeserializeFields for IndexType16 {
+    const NAME: &'static str = "IndexType16";
+    const FIELDS: &'static [&'static str] = &["value", ];
+
+    fn deserialize_field<'de, __D: crate::serde::Deserializer<'de>>(&mut self, index: usize, deserializer: __D) -> Result<(), __D::Error> {
+        use crate::serde::Deserialize;
+        match index {
+            0 => self.try_set_value(Deserialize::deserialize(deserializer)?).map_err(crate::serde::de::Error::custom)?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
+}
+
 impl IndexType16 {
     /// First element of the range [`range`].
     ///
     /// [`range`]: struct.IndexType16Ref.html#method.range
+    ///
+    /// The value is truncated to 16 bits, which is checked by a debug
+    /// assertion. Use [`try_set_value`] to check it in release builds.
+    ///
+    /// [`try_set_value`]: #method.try_set_value
     #[inline]
     #[allow(missing_docs)]
     pub fn set_value(&mut self, value: u64) {
+        debug_assert!(
+            crate::helper::fits_in_bits(value, 16),
+            "value {} of IndexType16::value does not fit into 16 bits", value
+        );
         flatdata_write_bytes!(u64; value, self.data, 0, 16)
     }
 
+    /// Sets [`value`] if the value fits into 16 bits, otherwise
+    /// returns an error and leaves the struct unchanged.
+    ///
+    /// [`value`]: #method.value
+    #[inline]
+    pub fn try_set_value(&mut self, value: u64) -> Result<(), crate::FieldOverflowError> {
+        crate::helper::check_bits("IndexType16", "value", value, 16)?;
+        self.set_value(value);
+        Ok(())
+    }
 
     /// Copies the data from `other` into this struct.
     #[inline]
@@ -1268,6 +18011,25 @@ impl IndexType16 {
     }
 }
 
+/// Owned plain value of [`IndexType16`] with a public member per field.
+///
+/// Converted from [`IndexType16`] with `From`.
+///
+/// [`IndexType16`]: struct.IndexType16.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexType16Value {
+    #[allow(missing_docs)]
+    pub value: u64,
+}
+
+impl From<&IndexType16> for IndexType16Value {
+    fn from(other: &IndexType16) -> Self {
+        Self {
+            value: other.value(),
+        }
+    }
+}
+
 impl crate::IndexStruct for IndexType16 {
     #[inline]
     fn range(&self) -> std::ops::Range<usize> {
@@ -1279,6 +18041,11 @@ impl crate::IndexStruct for IndexType16 {
     fn set_index(&mut self, value: usize) {
         self.set_value(value as u64);
     }
+
+    #[inline]
+    fn try_set_index(&mut self, value: usize) -> Result<(), crate::FieldOverflowError> {
+        self.try_set_value(value as u64)
+    }
 }
 
 
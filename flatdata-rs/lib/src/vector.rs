@@ -1,4 +1,5 @@
 use crate::{
+    buffering::{Reservation, WriteBuffering},
     error::ResourceStorageError,
     structs::{NoOverlap, Overlap, Struct},
     SliceExt,
@@ -6,7 +7,13 @@ use crate::{
 
 use crate::storage::ResourceHandle;
 
-use std::{borrow::BorrowMut, fmt, io, slice::SliceIndex};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    fmt, io,
+    iter::FromIterator,
+    ptr,
+    slice::SliceIndex,
+};
 
 /// A container holding a contiguous sequence of flatdata structs of the same
 /// type `T` in memory, and providing read and write access to it.
@@ -18,6 +25,13 @@ use std::{borrow::BorrowMut, fmt, io, slice::SliceIndex};
 /// better choice since it may decrease the memory footprint of serialization
 /// significantly.
 ///
+/// Besides [`grow`], elements can be copied into the vector with [`push`],
+/// [`extend_from_slice`] or by collecting an iterator. For structs with
+/// ranges, copied elements keep the lengths of their ranges, which are moved
+/// to follow the ones of the previous elements. For structs without ranges,
+/// the vector can be edited like a `Vec`, e.g. sorted, deduplicated or
+/// filtered with [`retain`].
+///
 /// An archive builder provides a setter for each vector resource. Use
 /// [`as_view`] and the corresponding setter to write a `Vector` to storage.
 ///
@@ -58,6 +72,10 @@ use std::{borrow::BorrowMut, fmt, io, slice::SliceIndex};
 ///
 /// [`ExternalVector`]: struct.ExternalVector.html
 /// [`as_view`]: #method.as_view
+/// [`grow`]: #method.grow
+/// [`push`]: #method.push
+/// [`extend_from_slice`]: #method.extend_from_slice
+/// [`retain`]: #method.retain
 pub struct Vector<T>
 where
     T: Struct,
@@ -123,6 +141,148 @@ where
         self.data.push(unsafe { T::create_unchecked() });
         &mut self.data[next]
     }
+
+    /// Appends a copy of `element` to the end of this vector.
+    ///
+    /// For structs with ranges, the last element of the vector is its
+    /// sentinel. The copy is inserted before the sentinel: it starts its
+    /// ranges where the previous element ends them, and the sentinel is moved,
+    /// so that the ranges keep the lengths they have in `element`. Pushing to
+    /// an empty vector keeps the ranges' starts of `element`.
+    #[inline]
+    pub fn push(&mut self, element: &T) {
+        if !T::IS_OVERLAPPING_WITH_NEXT {
+            copy_struct(element, self.grow());
+            return;
+        }
+        if self.is_empty() {
+            // the sentinel of an empty vector is at the start of the first element
+            copy_struct(element, self.grow());
+        }
+        let sentinel = self.len() - 1;
+        self.grow();
+        let (head, tail) = self.data.split_at_mut(sentinel + 1);
+        head[sentinel].copy_rebased(&mut tail[0], element);
+    }
+
+    /// Appends copies of all `elements` to the end of this vector.
+    ///
+    /// For structs with ranges, `elements` is usually a view without its
+    /// sentinel, e.g. a resource of an archive or the result of [`as_view`],
+    /// and their ranges are rebased like by [`push`].
+    ///
+    /// [`as_view`]: #method.as_view
+    /// [`push`]: #method.push
+    pub fn extend_from_slice(&mut self, elements: &[T]) {
+        self.data.reserve(elements.len());
+        for element in elements {
+            self.push(element);
+        }
+    }
+
+    /// Shortens this vector to `len` elements.
+    ///
+    /// Has no effect if the vector is not longer than `len`. For structs with
+    /// ranges, the last remaining struct still starts where the range of the
+    /// one before ends, so it stays a valid sentinel.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.data.truncate(len + 1);
+            self.data[len] = unsafe { T::create_unchecked() };
+        }
+    }
+}
+
+/// Editing operations which move structs, and hence are only available for
+/// structs without ranges.
+///
+/// Sorting and swapping is provided by the slice the vector dereferences to,
+/// e.g. `sort_by_key` and `swap`.
+impl<T> Vector<T>
+where
+    T: Struct + NoOverlap,
+{
+    /// Retains only the elements for which `f` returns `true`, preserving
+    /// their order.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.edit(|data| data.retain(f));
+    }
+
+    /// Removes all but the first of consecutive elements for which
+    /// `same_bucket` returns `true`.
+    ///
+    /// Like for `Vec::dedup_by`, the later element is passed first.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.edit(|data| data.dedup_by(|a, b| same_bucket(a, b)));
+    }
+
+    /// Removes all but the first of consecutive elements with the same key.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Applies `f` to the elements without the internal element following
+    /// them.
+    fn edit<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let next = self.data.pop().expect("missing internal element");
+        let result = f(&mut self.data);
+        self.data.push(next);
+        result
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T>
+where
+    T: Struct + NoOverlap,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Self::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<'a, T> FromIterator<&'a T> for Vector<T>
+where
+    T: Struct,
+{
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        let mut vector = Self::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T> Extend<T> for Vector<T>
+where
+    T: Struct + NoOverlap,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(&element);
+        }
+    }
+}
+
+impl<'a, T> Extend<&'a T> for Vector<T>
+where
+    T: Struct,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
 }
 
 impl<T> std::ops::Deref for Vector<T>
@@ -197,7 +357,11 @@ where
 ///
 /// External vector does not provide access to elements previously added to it.
 /// Only the last element added to the vector using the result of the method
-/// [`grow`] can be accessed and written.
+/// [`grow`] can be written. Elements which were not flushed yet can be read
+/// with [`get`].
+///
+/// Besides [`grow`], elements can be copied into the vector with [`push`] and
+/// [`extend`], or written directly to storage with [`write_slice`].
 ///
 /// An external vector *must* be closed, after the last element was written to
 /// it. After closing, it can not be used anymore.
@@ -245,13 +409,23 @@ where
 /// ```
 ///
 /// [`grow`]: #method.grow
+/// [`get`]: #method.get
+/// [`push`]: #method.push
+/// [`extend`]: #method.extend
+/// [`write_slice`]: #method.write_slice
 pub struct ExternalVector<'a, T>
 where
     T: Struct,
 {
     data: Vector<T>,
     len: usize,
+    size_flushed: usize,
     resource_handle: ResourceHandle<'a>,
+    strict: bool,
+    last_written: LastWritten<T>,
+    sentinel: Option<Vector<T>>,
+    buffering: WriteBuffering,
+    reservation: Reservation,
 }
 
 impl<'a, T> ExternalVector<'a, T>
@@ -259,15 +433,80 @@ where
     T: Struct,
 {
     /// Creates an empty `ExternalVector<T>` in the given resource storage.
+    ///
+    /// The vector uses the default buffering at the time it is created (cf.
+    /// [`WriteBuffering`]).
+    ///
+    /// [`WriteBuffering`]: struct.WriteBuffering.html
     pub fn new(resource_handle: ResourceHandle<'a>) -> Self {
+        let buffering = WriteBuffering::default();
         Self {
             data: Vector::new(),
             len: 0,
+            size_flushed: 0,
             resource_handle,
+            strict: false,
+            last_written: LastWritten::new(None),
+            sentinel: None,
+            reservation: Reservation::new(buffering.budget().cloned()),
+            buffering,
         }
     }
 
+    /// Creates an `ExternalVector<T>` appending to a reopened resource which
+    /// already contains `len` elements, the last of which is `last`.
+    ///
+    /// The removed `sentinel` of the resource is written back when closing
+    /// the vector, unless elements were appended.
+    pub(crate) fn new_appending(
+        resource_handle: ResourceHandle<'a>,
+        len: usize,
+        last: Option<&T>,
+        sentinel: Option<&T>,
+    ) -> Self {
+        let mut vector = Self::new(resource_handle);
+        vector.len = len;
+        vector.size_flushed = len * T::SIZE_IN_BYTES;
+        vector.last_written = LastWritten::new(last);
+        vector.sentinel = sentinel.map(|sentinel| {
+            let mut copy = Vector::with_len(1);
+            copy_struct(sentinel, &mut copy.data[0]);
+            copy
+        });
+        vector
+    }
+
+    /// Sets how much data this vector buffers before flushing it to storage.
+    ///
+    /// Memory reserved from a previously set budget is returned to it.
+    pub fn set_buffering(&mut self, buffering: WriteBuffering) {
+        self.reservation = Reservation::new(buffering.budget().cloned());
+        self.buffering = buffering;
+    }
+
+    /// Returns the buffering configuration of this vector.
+    pub fn buffering(&self) -> &WriteBuffering {
+        &self.buffering
+    }
+
+    /// Enables or disables the strict mode of this vector.
+    ///
+    /// In strict mode, the ranges of the elements are checked before they are
+    /// written to storage (cf. [`check_ranges`]). Flushing or closing the
+    /// vector fails on an invalid range, and the resource is not finalized.
+    ///
+    /// [`check_ranges`]: fn.check_ranges.html
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strict
+    }
+
     /// Number of elements that where added to this vector.
+    ///
+    /// When appending to an existing resource, its elements are included.
     pub fn len(&self) -> usize {
         self.len
     }
@@ -285,19 +524,154 @@ where
     ///
     /// [`flush`]: #method.flush
     pub fn grow(&mut self) -> io::Result<&mut T> {
-        if self.data.as_view().as_bytes().len() > 1024 * 1024 * 32 {
+        let size = self.data.len() * T::SIZE_IN_BYTES + T::SIZE_IN_BYTES;
+        if size > self.buffering.buffer_size() || !self.reservation.reserve(size) {
             self.flush()?;
+            // if the budget is exhausted by other writers, buffer the element anyway
+            self.reservation.reserve(T::SIZE_IN_BYTES);
         }
         self.len += 1;
+        self.sentinel = None;
         Ok(self.data.grow())
     }
 
+    /// Appends a copy of `element` to the end of this vector.
+    ///
+    /// Calling this method may flush data to storage (cf. [`flush`]), which
+    /// may fail due to different IO reasons.
+    ///
+    /// [`flush`]: #method.flush
+    pub fn push(&mut self, element: &T) -> io::Result<()> {
+        let target = self.grow()?;
+        copy_struct(element, target);
+        Ok(())
+    }
+
+    /// Appends copies of all elements of `iter` to the end of this vector.
+    ///
+    /// Cheaper than calling [`push`] for each element, since the buffer is
+    /// filled without checking whether it needs to be flushed after each of
+    /// them.
+    ///
+    /// [`push`]: #method.push
+    pub fn extend<I>(&mut self, iter: I) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<T>,
+    {
+        let mut iter = iter.into_iter();
+        while let Some(element) = iter.next() {
+            self.push(element.borrow())?;
+            let room = self.room();
+            self.data.data.reserve(room.min(iter.size_hint().0));
+            for element in iter.by_ref().take(room) {
+                copy_struct(element.borrow(), self.data.grow());
+                self.len += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends `elements` to the end of this vector by writing them directly
+    /// to storage.
+    ///
+    /// The buffered elements are flushed before, so this is meant for large
+    /// slices, which would only be copied into the buffer otherwise.
+    pub fn write_slice(&mut self, elements: &[T]) -> io::Result<()> {
+        if elements.is_empty() {
+            return Ok(());
+        }
+        self.flush()?;
+        self.last_written
+            .check_and_update(self.strict, self.resource_handle.name(), self.len, elements)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        // Safety: structs are plain byte arrays of size `T::SIZE_IN_BYTES`.
+        // The slice is not interpreted as `&[T]` by `SliceExt::as_bytes`, which
+        // would include the sentinel following it.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * T::SIZE_IN_BYTES,
+            )
+        };
+        self.resource_handle.borrow_mut().write(bytes)?;
+        self.size_flushed += bytes.len();
+        self.len += elements.len();
+        self.sentinel = None;
+        Ok(())
+    }
+
+    /// Returns the element at `index`, if it was not flushed to storage yet.
+    ///
+    /// Elements which are already flushed can not be accessed anymore.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let first_buffered = self.len - self.data.len();
+        index
+            .checked_sub(first_buffered)
+            .and_then(|index| self.data.get(index))
+    }
+
+    /// Number of bytes flushed to storage so far.
+    ///
+    /// When appending to an existing resource, its data is included.
+    ///
+    /// Useful for reporting progress, since elements are flushed in chunks.
+    pub fn bytes_written(&self) -> usize {
+        self.size_flushed
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        self.resource_handle.name()
+    }
+
+    /// Number of elements which can be added without flushing or reserving
+    /// memory from the budget.
+    fn room(&self) -> usize {
+        let buffered = self.data.len() * T::SIZE_IN_BYTES;
+        let mut limit = self.buffering.buffer_size();
+        if let Some(reserved) = self.reservation.reserved() {
+            limit = limit.min(reserved);
+        }
+        limit.saturating_sub(buffered) / T::SIZE_IN_BYTES
+    }
+
     /// Flushes the not yet flushed content in this vector to storage.
-    fn flush(&mut self) -> io::Result<()> {
-        self.resource_handle
-            .borrow_mut()
-            .write(self.data.as_view().as_bytes())?;
+    ///
+    /// Flushing happens automatically when growing the vector, depending on
+    /// its buffering (cf. [`set_buffering`]). Calling it explicitly allows to
+    /// release buffered memory early.
+    ///
+    /// [`set_buffering`]: #method.set_buffering
+    pub fn flush(&mut self) -> io::Result<()> {
+        if self.data.is_empty() {
+            return Ok(());
+        }
+        self.check_buffer()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.write_buffer()
+    }
+
+    /// Checks the ranges of the buffered elements in strict mode.
+    fn check_buffer(&mut self) -> Result<(), ResourceStorageError> {
+        let index = self.len - self.data.len();
+        self.last_written.check_and_update(
+            self.strict,
+            self.resource_handle.name(),
+            index,
+            &self.data,
+        )
+    }
+
+    fn write_buffer(&mut self) -> io::Result<()> {
+        let bytes = self.data.as_view().as_bytes();
+        self.resource_handle.borrow_mut().write(bytes)?;
+        self.size_flushed += bytes.len();
         self.data.clear();
+        if self.reservation.is_budgeted() {
+            // memory is only accounted for while it is in use
+            self.data.data.shrink_to_fit();
+        }
+        self.reservation.release();
         Ok(())
     }
 
@@ -306,8 +680,13 @@ where
     ///
     /// An external vector *must* be closed
     pub fn close(mut self) -> Result<&'a [T], ResourceStorageError> {
+        if let Some(sentinel) = self.sentinel.take() {
+            // nothing was appended to the reopened resource
+            copy_struct(&sentinel.data[0], self.data.grow());
+        }
         if self.data.len() > 0 || self.len == 0 {
-            self.flush().map_err(|e| {
+            self.check_buffer()?;
+            self.write_buffer().map_err(|e| {
                 ResourceStorageError::from_io_error(e, self.resource_handle.name().into())
             })?;
         }
@@ -317,6 +696,110 @@ where
     }
 }
 
+/// Copy of the last element an [`ExternalVector`] wrote to storage.
+///
+/// The ranges of an element can only be checked together with the next
+/// element, which might only be written by a later flush.
+///
+/// [`ExternalVector`]: struct.ExternalVector.html
+struct LastWritten<T>
+where
+    T: Struct,
+{
+    element: Vector<T>,
+    present: bool,
+}
+
+impl<T> LastWritten<T>
+where
+    T: Struct,
+{
+    fn new(element: Option<&T>) -> Self {
+        let mut last = Self {
+            element: Vector::with_len(1),
+            present: element.is_some(),
+        };
+        if let Some(element) = element {
+            copy_struct(element, &mut last.element.data[0]);
+        }
+        last
+    }
+
+    /// Checks the ranges of `elements`, which are written next starting at
+    /// `index`, if `strict` is set, and keeps a copy of the last of them.
+    fn check_and_update(
+        &mut self,
+        strict: bool,
+        resource_name: &str,
+        index: usize,
+        elements: &[T],
+    ) -> Result<(), ResourceStorageError> {
+        let last = match elements.last() {
+            Some(last) if T::IS_OVERLAPPING_WITH_NEXT => last,
+            _ => return Ok(()),
+        };
+        if strict {
+            let invalid_range = |index| ResourceStorageError::InvalidRange {
+                resource_name: resource_name.into(),
+                index,
+            };
+            if self.present {
+                copy_struct(&elements[0], &mut self.element.data[1]);
+                if !self.element.data[0].has_valid_ranges() {
+                    return Err(invalid_range(index - 1));
+                }
+            }
+            // the ranges of the last element are checked with the next one
+            let complete = &elements[..elements.len() - 1];
+            if let Some(position) = complete.iter().position(|x| !x.has_valid_ranges()) {
+                return Err(invalid_range(index + position));
+            }
+        }
+        copy_struct(last, &mut self.element.data[0]);
+        self.present = true;
+        Ok(())
+    }
+}
+
+/// Copies the data of `source` into `target`.
+pub(crate) fn copy_struct<T: Struct>(source: &T, target: &mut T) {
+    // Safety: structs are plain byte arrays of size `T::SIZE_IN_BYTES`, and
+    // a shared and a mutable reference can not overlap.
+    unsafe {
+        ptr::copy_nonoverlapping(
+            source as *const T as *const u8,
+            target as *mut T as *mut u8,
+            T::SIZE_IN_BYTES,
+        )
+    }
+}
+
+/// Checks that no range of the structs in `data` ends before it starts.
+///
+/// A decreasing range is usually the result of a value truncated to the
+/// width of its field, e.g. an offset into another resource exceeding
+/// `2^width`.
+///
+/// For structs with ranges, `data` must be followed by a sentinel, as it is
+/// the case for slices of closed resources and [`Vector::as_view`].
+///
+/// [`Vector::as_view`]: struct.Vector.html#method.as_view
+pub fn check_ranges<T: Struct>(
+    resource_name: &str,
+    data: &[T],
+) -> Result<(), ResourceStorageError> {
+    if !T::IS_OVERLAPPING_WITH_NEXT {
+        return Ok(());
+    }
+    match data.iter().position(|x| !x.has_valid_ranges()) {
+        Some(index) => Err(ResourceStorageError::InvalidRange {
+            resource_name: resource_name.into(),
+            index,
+        }),
+        None => Ok(()),
+    }
+}
+
 impl<T> fmt::Debug for ExternalVector<'_, T>
 where
     T: Struct,
@@ -332,7 +815,12 @@ mod tests {
     // Note: ExternalVector is tested in the corresponding example.
 
     use super::*;
+    use crate::buffering::MINIMUM_BUFFER_SIZE;
     use crate::test::{A, R};
+    use crate::{
+        append_external_vector, create_external_vector, MemoryBudget, MemoryResourceStorage,
+        ResourceStorage, WriteBuffering,
+    };
 
     #[test]
     fn test_vector_new() {
@@ -426,6 +914,308 @@ mod tests {
         assert_eq!(v.len(), 3);
     }
 
+    #[test]
+    fn test_vector_editing() {
+        let mut source: Vector<A> = Vector::new();
+        for &(x, y) in &[(3, 0), (1, 1), (3, 2), (2, 3), (1, 4)] {
+            let a = source.grow();
+            a.set_x(x);
+            a.set_y(y);
+        }
+        let xy = |v: &Vector<A>| v.iter().map(|a| (a.x(), a.y())).collect::<Vec<_>>();
+
+        let mut v: Vector<A> = source.iter().collect();
+        assert_eq!(xy(&v), xy(&source));
+        v.sort_by_key(A::x);
+        assert_eq!(xy(&v), [(1, 1), (1, 4), (2, 3), (3, 0), (3, 2)]);
+        v.dedup_by_key(A::x);
+        assert_eq!(xy(&v), [(1, 1), (2, 3), (3, 0)]);
+        v.swap(0, 2);
+        v.push(&source[3]);
+        assert_eq!(xy(&v), [(3, 0), (2, 3), (1, 1), (2, 3)]);
+        v.retain(|a| a.x() != 2);
+        assert_eq!(xy(&v), [(3, 0), (1, 1)]);
+        v.extend_from_slice(&source[..2]);
+        v.extend(source.iter().skip(4));
+        assert_eq!(xy(&v), [(3, 0), (1, 1), (3, 0), (1, 1), (1, 4)]);
+        v.truncate(2);
+        assert_eq!(xy(&v), [(3, 0), (1, 1)]);
+        assert_eq!(v.as_view().len(), 2);
+        v.truncate(3);
+        assert_eq!(v.len(), 2);
+    }
+
+    #[test]
+    fn test_vector_editing_ranges() {
+        let mut source: Vector<R> = Vector::new();
+        for &(first_x, y) in &[(10, 0), (12, 1), (15, 2), (19, 3)] {
+            let r = source.grow();
+            r.set_first_x(first_x);
+            r.set_y(y);
+        }
+        let ranges = |v: &Vector<R>| {
+            let view = v.as_view();
+            view.iter()
+                .map(|r| (r.x().start, r.x().end, r.y()))
+                .collect::<Vec<_>>()
+        };
+
+        // collecting a view recreates its sentinel from the lengths of the ranges
+        let mut v: Vector<R> = source.as_view().iter().collect();
+        assert_eq!(ranges(&v), ranges(&source));
+        assert_eq!(v[3].first_x(), 19);
+
+        v.truncate(3);
+        assert_eq!(ranges(&v), [(10, 12, 0), (12, 15, 1)]);
+        v.extend_from_slice(&source.as_view()[2..]);
+        v.push(&source[0]);
+        assert_eq!(
+            ranges(&v),
+            [(10, 12, 0), (12, 15, 1), (15, 19, 2), (19, 21, 0)]
+        );
+        assert!(check_ranges("v", v.as_view()).is_ok());
+
+        let mut v: Vector<R> = Vector::new();
+        v.push(&source[1]);
+        assert_eq!(ranges(&v), [(12, 15, 1)]);
+    }
+
+    #[test]
+    fn test_check_ranges() {
+        let mut v: Vector<R> = Vector::new();
+        for &first_x in &[1, 5, 3] {
+            v.grow().set_first_x(first_x);
+        }
+        assert!(check_ranges("v", &v.as_view()[..1]).is_ok());
+        match check_ranges("v", v.as_view()) {
+            Err(ResourceStorageError::InvalidRange {
+                resource_name,
+                index,
+            }) => assert_eq!((resource_name.as_str(), index), ("v", 1)),
+            x => panic!("unexpected result: {:?}", x),
+        }
+        assert!(check_ranges::<A>("v", Vector::with_len(3).as_view()).is_ok());
+    }
+
+    #[test]
+    fn test_external_vector_strict() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        for &strict in &[false, true] {
+            let name = format!("v_{}", strict);
+            let mut v = create_external_vector::<R>(&*storage, &name, "Some schema")
+                .expect("failed to create ExternalVector");
+            v.set_strict(strict);
+            v.grow().expect("grow failed").set_first_x(2);
+            v.grow().expect("grow failed").set_first_x(1);
+            assert_eq!(v.close().is_err(), strict);
+            // a failed resource is not finalized
+            assert_eq!(storage.read(&name, "Some schema").is_ok(), !strict);
+        }
+    }
+
+    #[test]
+    fn test_external_vector_strict_flush() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        v.set_strict(true);
+        v.set_buffering(WriteBuffering::new(2 * R::SIZE_IN_BYTES));
+        for &first_x in &[0, 1, 2] {
+            v.grow().expect("grow failed").set_first_x(first_x);
+        }
+        v.flush().expect("flush failed");
+        // the range of the last flushed element ends before it starts
+        v.grow().expect("grow failed").set_first_x(1);
+        let error = v.flush().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        drop(v);
+        assert!(storage.read("v", "Some schema").is_err());
+    }
+
+    #[test]
+    fn test_external_vector_strict_append() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        for &first_x in &[1, 5, 7] {
+            v.grow().expect("grow failed").set_first_x(first_x);
+        }
+        v.close().expect("close failed");
+
+        let mut v =
+            append_external_vector::<R>(&*storage, "v", "Some schema").expect("failed to append");
+        v.set_strict(true);
+        // the range of the last stored element ends before it starts
+        v.grow().expect("grow failed").set_first_x(3);
+        v.grow().expect("grow failed").set_first_x(9);
+        match v.close() {
+            Err(ResourceStorageError::InvalidRange { index, .. }) => assert_eq!(index, 1),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let data = storage.read("v", "Some schema").expect("failed to read");
+        let view = <&[R]>::from_bytes(data).expect("failed to read");
+        assert_eq!(view.iter().map(|r| r.x()).collect::<Vec<_>>(), [1..5, 5..7]);
+    }
+
+    #[test]
+    fn test_external_vector_buffering() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let budget = MemoryBudget::new(1024 * 1024);
+        let buffering = WriteBuffering::new(2 * R::SIZE_IN_BYTES).with_budget(budget.clone());
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        v.set_buffering(buffering);
+        for first_x in 0..10 {
+            v.grow().expect("grow failed").set_first_x(first_x);
+            if first_x == 4 {
+                v.flush().expect("flush failed");
+                assert_eq!(budget.used(), 0);
+                v.flush().expect("flush failed");
+            }
+        }
+        // small buffers are not taken from the budget
+        assert_eq!(budget.used(), 0);
+        let view = v.close().expect("close failed");
+        assert_eq!(view.len(), 9);
+        for (i, r) in view.iter().enumerate() {
+            assert_eq!(r.x(), i as u32..i as u32 + 1);
+        }
+    }
+
+    #[test]
+    fn test_external_vector_exhausted_budget() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let budget = MemoryBudget::new(0);
+        let mut v = create_external_vector::<A>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        v.set_buffering(WriteBuffering::default().with_budget(budget.clone()));
+        for x in 0..5 {
+            v.grow().expect("grow failed").set_x(x);
+        }
+        assert_eq!(budget.used(), 0);
+        // elements are still buffered up to the minimum buffer size
+        assert_eq!(v.bytes_written(), 0);
+        let view = v.close().expect("close failed");
+        assert_eq!(
+            view.iter().map(|a| a.x()).collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_external_vector_minimum_buffer() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let budget = MemoryBudget::new(1024 * 1024);
+        let mut v = create_external_vector::<A>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        v.set_buffering(WriteBuffering::default().with_budget(budget.clone()));
+        let minimum = MINIMUM_BUFFER_SIZE / A::SIZE_IN_BYTES;
+        v.extend(std::iter::repeat_n(A::new(), minimum))
+            .expect("extend failed");
+        assert_eq!(budget.used(), 0);
+        v.grow().expect("grow failed");
+        assert!(budget.used() > 0);
+        v.flush().expect("flush failed");
+        assert_eq!(budget.used(), 0);
+        assert_eq!(v.bytes_written(), (minimum + 1) * A::SIZE_IN_BYTES);
+        v.close().expect("close failed");
+    }
+
+    #[test]
+    fn test_external_vector_bulk() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let mut source: Vector<R> = Vector::new();
+        for first_x in 0..20 {
+            source.grow().set_first_x(first_x);
+        }
+
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        v.set_buffering(WriteBuffering::new(4 * R::SIZE_IN_BYTES));
+        v.push(&source[0]).expect("push failed");
+        assert_eq!(v.get(0).map(|r| r.first_x()), Some(0));
+        v.extend(&source[1..6]).expect("extend failed");
+        assert_eq!(v.len(), 6);
+        assert!(v.get(0).is_none());
+        assert_eq!(v.get(5).map(|r| r.first_x()), Some(5));
+        assert!(v.get(6).is_none());
+        assert_eq!(v.bytes_written(), 4 * R::SIZE_IN_BYTES);
+
+        v.write_slice(&source[6..15]).expect("write_slice failed");
+        assert_eq!(v.len(), 15);
+        assert!(v.get(14).is_none());
+        assert_eq!(v.bytes_written(), 15 * R::SIZE_IN_BYTES);
+        v.extend(source[15..20].iter()).expect("extend failed");
+
+        let view = v.close().expect("close failed");
+        assert_eq!(view.len(), 19);
+        for (i, r) in view.iter().enumerate() {
+            assert_eq!(r.x(), i as u32..i as u32 + 1);
+        }
+    }
+
+    #[test]
+    fn test_external_vector_append() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        for first_x in 0..5 {
+            v.grow().expect("grow failed").set_first_x(first_x);
+        }
+        assert_eq!(v.close().expect("close failed").len(), 4);
+
+        assert!(matches!(
+            append_external_vector::<R>(&*storage, "v", "Other schema"),
+            Err(ResourceStorageError::WrongSignature { .. })
+        ));
+        assert!(matches!(
+            append_external_vector::<R>(&*storage, "missing", "Some schema"),
+            Err(ResourceStorageError::Missing)
+        ));
+
+        let mut v = append_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to append to ExternalVector");
+        // the sentinel is removed
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.bytes_written(), 4 * R::SIZE_IN_BYTES);
+        for first_x in 4..10 {
+            v.grow().expect("grow failed").set_first_x(first_x);
+        }
+        let view = v.close().expect("close failed");
+        assert_eq!(view.len(), 9);
+        for (i, r) in view.iter().enumerate() {
+            assert_eq!(r.x(), i as u32..i as u32 + 1);
+        }
+
+        let data = storage
+            .read("v", "Some schema")
+            .expect("failed to read resource");
+        assert_eq!(data.len(), 10 * R::SIZE_IN_BYTES);
+    }
+
+    #[test]
+    fn test_external_vector_append_nothing() {
+        let storage = MemoryResourceStorage::new("/root/extvec");
+        let mut v = create_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to create ExternalVector");
+        for first_x in [1, 5, 7] {
+            v.grow().expect("grow failed").set_first_x(first_x);
+        }
+        v.close().expect("close failed");
+
+        // the stored sentinel is written back
+        let v = append_external_vector::<R>(&*storage, "v", "Some schema")
+            .expect("failed to append to ExternalVector");
+        let view = v.close().expect("close failed");
+        assert_eq!(view.iter().map(|r| r.x()).collect::<Vec<_>>(), [1..5, 5..7]);
+
+        let data = storage.read("v", "Some schema").expect("failed to read");
+        assert_eq!(data.len(), 3 * R::SIZE_IN_BYTES);
+        let view = <&[R]>::from_bytes(data).expect("failed to read");
+        assert_eq!(view.iter().map(|r| r.x()).collect::<Vec<_>>(), [1..5, 5..7]);
+    }
+
     #[test]
     fn test_ability_to_get_mut_and_const_for_non_overlap() {
         let mut v: Vector<A> = Vector::with_len(10);
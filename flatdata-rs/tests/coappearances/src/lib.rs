@@ -27,7 +27,7 @@ fn read_and_validate_coappearances(
         "Leo Tolstoy (Лев Николаевич Толстой)"
     );
 
-    let num_chapters = edges.iter().map(|e| e.count() as usize).sum();
+    let num_chapters: usize = edges.iter().map(|e| e.count() as usize).sum();
     assert_eq!(g.chapters().len(), num_chapters);
 
     assert_eq!(
@@ -120,6 +120,32 @@ fn read_and_validate_coappearances(
         }
         _ => assert!(false),
     };
+
+    use coappearances::VerticesDataBucket;
+    let nicknames: Vec<_> = vertices_data
+        .iter()
+        .enumerate()
+        .flat_map(|(index, item)| {
+            item.nicknames()
+                .map(move |nickname| (index, nickname.ref_()))
+        })
+        .take(2)
+        .collect();
+    assert_eq!(nicknames.len(), 2);
+    assert_eq!(nicknames[0].0, 7);
+    assert_eq!(g.strings().substring(nicknames[0].1 as usize)?, "Mitya");
+    assert_eq!(nicknames[1].0, 19);
+    assert_eq!(g.strings().substring(nicknames[1].1 as usize)?, "Dolly");
+
+    let relation = vertices_data
+        .get(1)
+        .and_then(|item| item.first_of::<coappearances::UnaryRelation>())
+        .expect("missing relation");
+    assert_eq!(
+        g.strings().substring(relation.kind_ref() as usize)?,
+        "housekeeper"
+    );
+    assert!(vertices_data.get(vertices_data.len()).is_none());
     Ok(())
 }
 
@@ -139,6 +165,147 @@ fn read_and_validate_coappearances_from_tar_archive_storage() -> Result<(), std:
     read_and_validate_coappearances(storage)
 }
 
+#[test]
+#[cfg(feature = "serde")]
+fn serialize_coappearances_to_json() {
+    let storage =
+        flatdata::FileResourceStorage::new(path::PathBuf::from("assets/karenina.archive"));
+    let g = coappearances::Graph::open(storage).expect("invalid archive");
+    let json = serde_json::to_value(&g).expect("failed to serialize");
+
+    assert_eq!(json["meta"]["title_ref"], g.meta().title_ref());
+    assert_eq!(json["meta"]["author_ref"], g.meta().author_ref());
+    assert_eq!(
+        json["vertices"].as_array().map(|v| v.len()),
+        Some(g.vertices().len())
+    );
+    assert_eq!(json["edges"][0]["count"], g.edges()[0].count());
+    assert_eq!(
+        json["strings"].as_array().map(|v| v.len()),
+        Some(g.strings().as_bytes().len())
+    );
+    assert_eq!(
+        json["vertices_data"].as_array().map(|v| v.len()),
+        Some(g.vertices_data().len())
+    );
+    match g.vertices_data().at(0).next().unwrap() {
+        coappearances::VerticesDataRef::UnaryRelation(data) => {
+            let item = &json["vertices_data"][0][0]["UnaryRelation"];
+            assert_eq!(item["kind_ref"], data.kind_ref());
+            assert_eq!(item["to_ref"], data.to_ref());
+        }
+        _ => panic!("unexpected variant"),
+    };
+    let statistics = g.statistics().expect("missing statistics");
+    assert_eq!(
+        json["statistics"]["invariants"]["max_degree"],
+        statistics.invariants().max_degree()
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn write_coappearances_from_json() -> Result<(), std::str::Utf8Error> {
+    let storage =
+        flatdata::FileResourceStorage::new(path::PathBuf::from("assets/karenina.archive"));
+    let g = coappearances::Graph::open(storage).expect("invalid archive");
+    let json = serde_json::to_string(&g).expect("failed to serialize");
+
+    let storage = flatdata::MemoryResourceStorage::new("/write_coappearances_from_json");
+    let builder =
+        coappearances::GraphBuilder::new(storage.clone()).expect("failed to create builder");
+    builder
+        .deserialize(&mut serde_json::Deserializer::from_str(&json))
+        .expect("failed to deserialize");
+
+    let copy = coappearances::Graph::open(storage.clone()).expect("invalid archive");
+    assert_eq!(
+        serde_json::to_string(&copy).expect("failed to serialize"),
+        json
+    );
+    read_and_validate_coappearances(storage)
+}
+
+#[test]
+fn read_coappearances_dynamically() {
+    use flatdata::{DynArchive, DynResource, Value};
+
+    let storage =
+        flatdata::FileResourceStorage::new(path::PathBuf::from("assets/karenina.archive"));
+    let g = coappearances::Graph::open(storage.clone()).expect("invalid archive");
+    let archive = DynArchive::open(storage, "Graph").expect("invalid archive");
+    assert_eq!(archive.name(), ".coappearances.Graph");
+
+    let resource = |name| archive.resource(name).expect("invalid resource");
+    match resource("meta") {
+        Some(DynResource::Instance(meta)) => assert_eq!(
+            meta.get("author_ref"),
+            Some(Value::UInt(g.meta().author_ref().into()))
+        ),
+        x => panic!("unexpected resource {:?}", x),
+    }
+    match resource("edges") {
+        Some(DynResource::Vector(edges)) => {
+            assert_eq!(edges.len(), g.edges().len());
+            for (edge, expected) in edges.iter().zip(g.edges()) {
+                assert_eq!(
+                    edge.get("a_ref"),
+                    Some(Value::UInt(expected.a_ref().into()))
+                );
+                let range = expected.chapters_range();
+                assert_eq!(
+                    edge.range("chapters_range"),
+                    Some(Value::UInt(range.start.into())..Value::UInt(range.end.into()))
+                );
+            }
+        }
+        x => panic!("unexpected resource {:?}", x),
+    }
+    match resource("vertices_data") {
+        Some(DynResource::MultiVector(data)) => {
+            assert_eq!(data.len(), g.vertices_data().len());
+            for (item, expected) in data.iter().zip(g.vertices_data().iter()) {
+                let names: Vec<_> = item.map(|x| x.definition().name.clone()).collect();
+                let expected: Vec<_> = expected
+                    .map(|x| match x {
+                        coappearances::VerticesDataRef::Nickname(_) => ".coappearances.Nickname",
+                        coappearances::VerticesDataRef::Description(_) => {
+                            ".coappearances.Description"
+                        }
+                        coappearances::VerticesDataRef::UnaryRelation(_) => {
+                            ".coappearances.UnaryRelation"
+                        }
+                        coappearances::VerticesDataRef::BinaryRelation(_) => {
+                            ".coappearances.BinaryRelation"
+                        }
+                    })
+                    .collect();
+                assert_eq!(names, expected);
+            }
+        }
+        x => panic!("unexpected resource {:?}", x),
+    }
+    match resource("strings") {
+        Some(DynResource::RawData(strings)) => {
+            assert_eq!(strings.as_bytes(), g.strings().as_bytes())
+        }
+        x => panic!("unexpected resource {:?}", x),
+    }
+    let statistics = match resource("statistics") {
+        Some(DynResource::Archive(statistics)) => statistics,
+        x => panic!("unexpected resource {:?}", x),
+    };
+    match statistics.resource("invariants").expect("invalid resource") {
+        Some(DynResource::Instance(invariants)) => assert_eq!(
+            invariants.get("max_degree"),
+            Some(Value::UInt(
+                g.statistics().unwrap().invariants().max_degree().into()
+            ))
+        ),
+        x => panic!("unexpected resource {:?}", x),
+    }
+}
+
 fn check_files(name_a: &path::Path, name_b: &path::Path) {
     let mut fa = fs::File::open(name_a).unwrap();
     let mut buf_a = Vec::new();
@@ -281,6 +448,40 @@ fn read_non_existent_statistics_subarchive() {
     assert!(g.statistics().is_none());
 }
 
+#[test]
+fn modify_coappearances_in_place() {
+    let (archive_path, _) = copy_coappearances_archive(
+        "assets/karenina.archive",
+        "modify_coappearances_in_place/karenina.archive",
+    );
+
+    {
+        let storage = flatdata::FileResourceStorageMut::new(archive_path.clone());
+        let mut g = coappearances::GraphMut::open(storage).expect("invalid archive");
+        g.vertices().swap(0, 1);
+        for chapter in g.chapters() {
+            chapter.set_minor(chapter.minor() / 2);
+        }
+        assert!(g.statistics().is_none());
+        g.flush().expect("flush failed");
+    }
+
+    let storage = flatdata::FileResourceStorage::new("assets/karenina.archive");
+    let orig = coappearances::Graph::open(storage).expect("invalid archive");
+    let storage = flatdata::FileResourceStorage::new(archive_path);
+    let copy = coappearances::Graph::open(storage).expect("invalid archive");
+
+    assert_eq!(orig.vertices()[0], copy.vertices()[1]);
+    assert_eq!(orig.vertices()[1], copy.vertices()[0]);
+    assert_eq!(orig.vertices()[2..], copy.vertices()[2..]);
+    assert_eq!(orig.chapters().len(), copy.chapters().len());
+    for (orig, copy) in orig.chapters().iter().zip(copy.chapters()) {
+        assert_eq!(orig.major(), copy.major());
+        assert_eq!(orig.minor() / 2, copy.minor());
+    }
+    assert_eq!(orig.edges(), copy.edges());
+}
+
 #[test]
 fn read_write_statistics_subarchive() {
     let (archive_path, gb) = copy_coappearances_archive(
@@ -317,7 +518,7 @@ fn read_write_statistics_subarchive() {
     // compare
     let storage = flatdata::FileResourceStorage::new("assets/karenina.archive");
     let orig = coappearances::Graph::open(storage).expect("invalid archive");
-    let storage = flatdata::FileResourceStorage::new(archive_path);
+    let storage = flatdata::FileResourceStorage::new(archive_path.clone());
     let copy = coappearances::Graph::open(storage).expect("invalid archive");
 
     let orig_stats = orig.statistics().expect("orig statistics failed");
@@ -334,6 +535,19 @@ fn read_write_statistics_subarchive() {
             copy_stats.vertex_degrees()[i]
         );
     }
+
+    // the subarchive is modified together with its parent
+    {
+        let storage = flatdata::FileResourceStorageMut::new(archive_path.clone());
+        let mut g = coappearances::GraphMut::open(storage).expect("invalid archive");
+        let stats = g.statistics().expect("statistics missing");
+        stats.vertex_degrees()[0].set_value(5);
+        g.flush().expect("flush failed");
+    }
+    let storage = flatdata::FileResourceStorage::new(archive_path);
+    let copy = coappearances::Graph::open(storage).expect("invalid archive");
+    let copy_stats = copy.statistics().expect("copy statistics failed");
+    assert_eq!(copy_stats.vertex_degrees()[0].value(), 5);
 }
 
 #[test]
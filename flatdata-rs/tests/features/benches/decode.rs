@@ -0,0 +1,65 @@
+//! Compares decoding a field with per-element getters vs. bulk decoding.
+//!
+//! Run with `cargo bench -p flatdata_tests_features`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+#[allow(dead_code)]
+mod integers {
+    include!(concat!(env!("OUT_DIR"), "/structs/integers.rs"));
+}
+
+#[allow(dead_code)]
+mod unaligned {
+    include!(concat!(env!("OUT_DIR"), "/structs/unaligned.rs"));
+}
+
+const NUM_ELEMENTS: usize = 1 << 16;
+
+macro_rules! bench_field {
+    ($c:expr, $ns:ident::$T:ident) => {{
+        type T = $ns::n::$T;
+        let mut vec = flatdata::Vector::<T>::with_len(NUM_ELEMENTS);
+        for (index, item) in vec.iter_mut().enumerate() {
+            item.set_f(index as _);
+        }
+        let data: &[T] = &vec;
+
+        let mut group = $c.benchmark_group(concat!(stringify!($ns), "/", stringify!($T)));
+        group.throughput(Throughput::Elements(NUM_ELEMENTS as u64));
+        group.bench_function("getter", |b| {
+            let mut out = vec![Default::default(); data.len()];
+            b.iter(|| {
+                for (item, value) in black_box(data).iter().zip(out.iter_mut()) {
+                    *value = item.f();
+                }
+                black_box(&out);
+            })
+        });
+        group.bench_function("bulk", |b| {
+            let mut out = vec![Default::default(); data.len()];
+            b.iter(|| {
+                T::decode_f_into(black_box(data), &mut out);
+                black_box(&out);
+            })
+        });
+        group.finish();
+    }};
+}
+
+fn integers(c: &mut Criterion) {
+    bench_field!(c, integers::U8);
+    bench_field!(c, integers::I16);
+    bench_field!(c, integers::U32);
+    bench_field!(c, integers::I64);
+}
+
+fn unaligned(c: &mut Criterion) {
+    bench_field!(c, unaligned::U8);
+    bench_field!(c, unaligned::I16);
+    bench_field!(c, unaligned::U32);
+    bench_field!(c, unaligned::I64);
+}
+
+criterion_group!(benches, integers, unaligned);
+criterion_main!(benches);
@@ -30,3 +30,33 @@ fn test() {
         );
     }
 }
+
+#[test]
+fn strict_mode() {
+    let mut data = flatdata::Vector::<n::S>::new();
+    data.grow().set_first_y(1 << 13);
+    // as if `1 << 14` was truncated in a release build
+    data.grow().set_first_y(0);
+
+    let storage = flatdata::MemoryResourceStorage::new("/my_test");
+    let builder = n::ABuilder::new(storage).expect("Failed to create builder");
+    builder
+        .set_data(data.as_view())
+        .expect("Failed to set data");
+
+    let storage = flatdata::MemoryResourceStorage::new("/my_test");
+    let mut builder = n::ABuilder::new(storage).expect("Failed to create builder");
+    builder.set_strict(true);
+    let error = builder.set_data(data.as_view()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    let mut data = builder.start_data().expect("Failed to start data");
+    data.grow().unwrap().set_first_y(1 << 13);
+    let error = data.grow().unwrap().try_set_first_y(1 << 14).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "value 16384 of S::first_y does not fit into 14 bits"
+    );
+    data.grow().unwrap().set_first_y(1);
+    assert!(data.close().is_err());
+}
@@ -29,3 +29,24 @@ fn test() {
         }
     }
 }
+
+#[test]
+fn test_streaming() {
+    use std::io::Write;
+
+    let storage = flatdata::MemoryResourceStorage::new("/my_test");
+
+    let builder = n::ABuilder::new(storage.clone()).expect("Failed to create builder");
+    let mut data = builder.start_data().expect("Failed to start data");
+    assert_eq!(data.offset(), 0);
+    data.write_all(b"My ").expect("Failed to write data");
+    assert_eq!(data.offset(), 3);
+    data.write_all(b"Data").expect("Failed to write data");
+    assert_eq!(data.offset(), 7);
+    let view = data.close().expect("Failed to close data");
+    assert_eq!(view.as_bytes(), b"My Data");
+
+    let archive = n::A::open(storage).expect("Failed to open archive");
+    assert_eq!(archive.data().as_bytes(), b"My Data");
+    assert!(archive.optional_data().is_none());
+}
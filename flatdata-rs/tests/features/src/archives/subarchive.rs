@@ -51,3 +51,33 @@ fn test() {
         }
     }
 }
+
+#[test]
+fn test_resources() {
+    let resources: Vec<_> = n::A::RESOURCES
+        .iter()
+        .map(|r| (r.name, r.kind, r.element_types, r.is_optional))
+        .collect();
+    assert_eq!(
+        resources,
+        [
+            (
+                "data",
+                flatdata::ResourceKind::Archive,
+                &[".n.X"][..],
+                false
+            ),
+            (
+                "optional_data",
+                flatdata::ResourceKind::Archive,
+                &[".n.X"][..],
+                true
+            ),
+        ]
+    );
+
+    let payload = &n::X::RESOURCES[0];
+    assert_eq!(payload.kind, flatdata::ResourceKind::RawData);
+    assert!(payload.element_types.is_empty());
+    assert_eq!(payload.schema, n::schema::x::resources::PAYLOAD);
+}
@@ -24,3 +24,50 @@ fn values() {
     data.invset_alid_max_int(127);
     assert_eq!(data.invalid_max_int(), None);
 }
+
+#[cfg(feature = "arrow")]
+#[test]
+fn arrow_nulls() {
+    use arrow_array::{cast::AsArray, types::Int8Type, Array};
+
+    let mut data = flatdata::Vector::<n::Bar>::new();
+    for &x in &[0, 10, -128, 127] {
+        let bar = data.grow();
+        bar.set_invalid_zero(x);
+        bar.invset_alid_min_int(x);
+        bar.invset_alid_max_int(x);
+    }
+    let batch = flatdata::arrow::to_record_batch(data.as_view()).expect("conversion failed");
+    assert!(batch.schema().field(0).is_nullable());
+    let column = |index: usize| -> Vec<_> {
+        batch
+            .column(index)
+            .as_primitive::<Int8Type>()
+            .iter()
+            .collect()
+    };
+    assert_eq!(column(0), vec![None, Some(10), Some(-128), Some(127)]);
+    assert_eq!(column(1), vec![Some(0), Some(10), None, Some(127)]);
+    assert_eq!(column(2), vec![Some(0), Some(10), Some(-128), None]);
+    assert_eq!(batch.column(0).null_count(), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn import_missing_values() {
+    let storage = flatdata::MemoryResourceStorage::new("/root/import");
+    let mut data = flatdata::create_external_vector::<n::Bar>(&*storage, "bars", "")
+        .expect("failed to create vector");
+    let input = "invalid_zero,invalid_min_int,invalid_max_int\n,10,\n-128,,5\n";
+    let count = flatdata::import_csv(input.as_bytes(), &mut data).expect("failed to import");
+    assert_eq!(count, 2);
+    let data = data.close().expect("failed to close");
+    let values: Vec<_> = data
+        .iter()
+        .map(|x| (x.invalid_zero(), x.invalid_min_int(), x.invalid_max_int()))
+        .collect();
+    assert_eq!(
+        values,
+        vec![(None, Some(10), None), (Some(-128), None, Some(5))]
+    );
+}
@@ -85,3 +85,120 @@ fn test_max_values() {
     value.set_f(std::i64::MAX);
     assert_eq!(value.f(), std::i64::MAX);
 }
+
+#[test]
+fn test_reflect() {
+    use flatdata::StructReflect;
+
+    let info = n::I16::field_info("f").expect("missing field");
+    assert_eq!(
+        (info.primitive_type, info.width, info.is_signed),
+        ("i16", 16, true)
+    );
+    let info = n::U64::field_info("f").expect("missing field");
+    assert_eq!(
+        (info.primitive_type, info.width, info.is_signed),
+        ("u64", 64, false)
+    );
+
+    let mut value = n::I16::new();
+    value.set_f(-2);
+    assert_eq!(value.get_field_u64("f").map(|x| x as i64), Some(-2));
+
+    let mut value = n::U64::new();
+    value.set_f(u64::MAX);
+    assert_eq!(value.get_field_u64("f"), Some(u64::MAX));
+}
+
+#[test]
+fn test_decode() {
+    macro_rules! check {
+        ($T:ident, $values:expr) => {{
+            let values: Vec<_> = $values;
+            let mut vec = flatdata::Vector::<n::$T>::with_len(values.len());
+            for (item, &value) in vec.iter_mut().zip(&values) {
+                item.set_f(value);
+            }
+            assert_eq!(n::$T::decode_f(&vec), values);
+
+            let mut out = vec![0; values.len() + 1];
+            n::$T::decode_f_into(&vec, &mut out);
+            assert_eq!(&out[..values.len()], &values[..]);
+        }};
+    }
+
+    check!(U8, vec![0, 1, 0x7f, u8::MAX]);
+    check!(I8, vec![i8::MIN, -1, 0, i8::MAX]);
+    check!(U16, vec![0, 1, 0x1234, u16::MAX]);
+    check!(I16, vec![i16::MIN, -1, 0, i16::MAX]);
+    check!(U32, vec![0, 1, 0x1234_5678, u32::MAX]);
+    check!(I32, vec![i32::MIN, -1, 0, i32::MAX]);
+    check!(U64, vec![0, 1, 0x1234_5678_9abc_def0, u64::MAX]);
+    check!(I64, vec![i64::MIN, -1, 0, i64::MAX]);
+}
+
+#[test]
+fn test_is_sorted() {
+    use flatdata::SortedSliceExt;
+
+    macro_rules! check {
+        ($T:ident, $values:expr) => {{
+            let mut values: Vec<_> = $values;
+            let mut vec = flatdata::Vector::<n::$T>::with_len(values.len());
+            for (item, &value) in vec.iter_mut().zip(&values) {
+                item.set_f(value);
+            }
+            assert!(n::$T::is_sorted_by_f(&vec));
+            assert!(n::$T::is_sorted_by_f(&vec[..0]));
+            assert_eq!(
+                vec.as_view().find_by_key(&values[2], n::$T::f).map(n::$T::f),
+                Some(values[2])
+            );
+
+            values.reverse();
+            for (item, &value) in vec.iter_mut().zip(&values) {
+                item.set_f(value);
+            }
+            assert!(!n::$T::is_sorted_by_f(&vec));
+        }};
+    }
+
+    check!(U8, vec![0, 1, 0x7f, u8::MAX]);
+    check!(I8, vec![i8::MIN, -1, 0, i8::MAX]);
+    check!(U16, vec![0, 1, 0x1234, u16::MAX]);
+    check!(I16, vec![i16::MIN, -1, 0, i16::MAX]);
+    check!(U32, vec![0, 1, 0x1234_5678, u32::MAX]);
+    check!(I32, vec![i32::MIN, -1, 0, i32::MAX]);
+    check!(U64, vec![0, 1, 0x1234_5678_9abc_def0, u64::MAX]);
+    check!(I64, vec![i64::MIN, -1, 0, i64::MAX]);
+}
+
+#[test]
+fn test_aligned_fields_match_macros() {
+    use flatdata::{flatdata_read_bytes, flatdata_write_bytes};
+
+    macro_rules! check {
+        ($T:ident, $type:tt, $values:expr) => {{
+            for &x in $values.iter() {
+                let mut value = n::$T::new();
+                value.set_f(x);
+                let mut expected = [0u8; std::mem::size_of::<$type>()];
+                flatdata_write_bytes!($type; x, expected, 0, std::mem::size_of::<$type>() * 8);
+                assert_eq!(value.as_bytes(), &expected);
+
+                let bytes = x.to_le_bytes();
+                let read = flatdata_read_bytes!($type, bytes.as_ptr(), 0, bytes.len() * 8);
+                assert_eq!(n::$T::from_bytes(&bytes).f(), read);
+            }
+        }};
+    }
+
+    check!(U8, u8, [0, 1, 0x7f, u8::MAX]);
+    check!(I8, i8, [i8::MIN, -1, 0, i8::MAX]);
+    check!(U16, u16, [0, 1, 0x1234, u16::MAX]);
+    check!(I16, i16, [i16::MIN, -1, 0, i16::MAX]);
+    check!(U32, u32, [0, 1, 0x1234_5678, u32::MAX]);
+    check!(I32, i32, [i32::MIN, -1, 0, i32::MAX]);
+    check!(U64, u64, [0, 1, 0x1234_5678_9abc_def0, u64::MAX]);
+    check!(I64, i64, [i64::MIN, -1, 0, i64::MAX]);
+}
@@ -85,3 +85,89 @@ fn test_max_values() {
     value.set_f((1 << (64 - 3 - 1)) - 1);
     assert_eq!(value.f(), (1 << (64 - 3 - 1)) - 1);
 }
+
+#[test]
+fn test_values() {
+    use std::convert::TryFrom;
+
+    let value = n::I16Value {
+        padding: 7,
+        f: -(1 << 12),
+    };
+    let packed = n::I16::try_from(value).expect("value out of range");
+    assert_eq!(packed.padding(), 7);
+    assert_eq!(packed.f(), -(1 << 12));
+    assert_eq!(n::I16Value::from(&packed), value);
+
+    let error = n::I16::try_from(n::I16Value {
+        padding: 0,
+        f: 1 << 12,
+    })
+    .unwrap_err();
+    assert_eq!(error.field_name, "f");
+    assert_eq!(error.value, 1 << 12);
+    assert_eq!(error.num_bits, 13);
+    assert!(n::I16::try_from(n::I16Value {
+        padding: 0,
+        f: -(1 << 12) - 1
+    })
+    .is_err());
+    assert!(n::I16::try_from(n::I16Value { padding: 8, f: 0 }).is_err());
+
+    let value = n::U64Value {
+        padding: 0,
+        f: (1 << 61) - 1,
+    };
+    assert_eq!(n::U64::try_from(value).map(|x| x.f()), Ok((1 << 61) - 1));
+    assert!(n::U64::try_from(n::U64Value {
+        padding: 0,
+        f: 1 << 61
+    })
+    .is_err());
+}
+
+#[test]
+fn test_decode() {
+    macro_rules! check {
+        ($T:ident, $values:expr) => {{
+            let values: Vec<_> = $values;
+            let mut vec = flatdata::Vector::<n::$T>::with_len(values.len());
+            for (item, &value) in vec.iter_mut().zip(&values) {
+                item.set_padding(0b101);
+                item.set_f(value);
+            }
+            assert_eq!(n::$T::decode_f(&vec), values);
+            assert_eq!(n::$T::decode_padding(&vec), vec![0b101; values.len()]);
+
+            let mut out = vec![0; values.len() + 1];
+            n::$T::decode_f_into(&vec, &mut out);
+            assert_eq!(&out[..values.len()], &values[..]);
+        }};
+    }
+
+    check!(U8, vec![0, 1, (1 << (8 - 3)) - 1]);
+    check!(I8, vec![-(1 << (8 - 3 - 1)), -1, 0, (1 << (8 - 3 - 1)) - 1]);
+    check!(U16, vec![0, 1, (1 << (16 - 3)) - 1]);
+    check!(
+        I16,
+        vec![-(1 << (16 - 3 - 1)), -1, 0, (1 << (16 - 3 - 1)) - 1]
+    );
+    check!(U32, vec![0, 1, (1 << (32 - 3)) - 1]);
+    check!(
+        I32,
+        vec![-(1 << (32 - 3 - 1)), -1, 0, (1 << (32 - 3 - 1)) - 1]
+    );
+    check!(U64, vec![0, 1, (1 << (64 - 3)) - 1]);
+    check!(
+        I64,
+        vec![-(1 << (64 - 3 - 1)), -1, 0, (1 << (64 - 3 - 1)) - 1]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_decode_short_buffer() {
+    let vec = flatdata::Vector::<n::U32>::with_len(3);
+    let mut out = [0; 2];
+    n::U32::decode_f_into(&vec, &mut out);
+}